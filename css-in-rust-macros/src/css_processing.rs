@@ -127,6 +127,30 @@ pub fn optimize_css_with_lightningcss(css: &str) -> Result<String, String> {
     result
 }
 
+/// Optimize a complete, unscoped stylesheet (used by css_global!)
+///
+/// Unlike [`optimize_css_with_lightningcss`], the input is never wrapped in a
+/// temporary class, so selectors such as `:root` or `@keyframes` stay global.
+pub fn optimize_global_css(css: &str) -> Result<String, String> {
+    if css.trim().is_empty() {
+        return Ok(String::new());
+    }
+
+    let stylesheet = LightningStyleSheet::parse(css, ParserOptions::default())
+        .map_err(|e| format!("Invalid global CSS: {}", e))?;
+
+    let printer_options = PrinterOptions {
+        minify: true,
+        targets: Targets::from(Browsers::default()),
+        ..Default::default()
+    };
+
+    match stylesheet.to_css(printer_options) {
+        Ok(result) => Ok(result.code),
+        Err(_) => validate_css_simple(css),
+    }
+}
+
 /// Deduplicate CSS rules
 #[allow(dead_code)]
 pub fn deduplicate_css_rules(rules: &[String]) -> Vec<String> {
@@ -143,6 +167,157 @@ pub fn deduplicate_css_rules(rules: &[String]) -> Vec<String> {
     deduplicated
 }
 
+/// Collapse exact-duplicate declarations within each rule
+///
+/// Only the last occurrence of an identical `property: value` pair is kept, so
+/// the cascade is unchanged; different values for the same property are all
+/// kept. Nested blocks are handled separately. The input is returned untouched
+/// when there is nothing to remove, so class names of existing CSS are stable.
+pub fn dedup_declarations(css: &str) -> String {
+    let bytes = css.as_bytes();
+    // Declarations of each open block: (normalized key, start, end)
+    let mut blocks: Vec<Vec<(String, usize, usize)>> = vec![Vec::new()];
+    let mut removals: Vec<(usize, usize)> = Vec::new();
+    let mut segment_start = 0;
+    let mut parens = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+
+    let record = |blocks: &mut Vec<Vec<(String, usize, usize)>>, start: usize, end: usize| {
+        if let Some(key) = declaration_key(&css[start..end]) {
+            if let Some(block) = blocks.last_mut() {
+                block.push((key, start, end));
+            }
+        }
+    };
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if let Some(q) = quote {
+            if byte == b'\\' {
+                i += 1;
+            } else if byte == q {
+                quote = None;
+            }
+        } else if byte == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            i = css[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |end| i + 2 + end + 1);
+        } else {
+            match byte {
+                b'"' | b'\'' => quote = Some(byte),
+                b'(' => parens += 1,
+                b')' => parens = parens.saturating_sub(1),
+                b';' if parens == 0 => {
+                    record(&mut blocks, segment_start, i + 1);
+                    segment_start = i + 1;
+                }
+                b'{' if parens == 0 => {
+                    blocks.push(Vec::new());
+                    segment_start = i + 1;
+                }
+                b'}' if parens == 0 => {
+                    record(&mut blocks, segment_start, i);
+                    if blocks.len() > 1 {
+                        let block = blocks.pop().unwrap_or_default();
+                        removals.extend(earlier_duplicates(&block));
+                    }
+                    segment_start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    record(&mut blocks, segment_start, bytes.len());
+    for block in &blocks {
+        removals.extend(earlier_duplicates(block));
+    }
+
+    if removals.is_empty() {
+        return css.to_string();
+    }
+
+    removals.sort_unstable();
+    let mut result = String::with_capacity(css.len());
+    let mut cursor = 0;
+    for (start, end) in removals {
+        // Drop the declaration together with the whitespace that follows it
+        let start = start + (css[start..end].len() - css[start..end].trim_start().len());
+        let end = end + (css[end..].len() - css[end..].trim_start().len());
+        result.push_str(&css[cursor..start]);
+        cursor = end;
+    }
+    result.push_str(&css[cursor..]);
+    result
+}
+
+/// Normalized `property:value` key of a declaration, `None` for non-declarations
+fn declaration_key(declaration: &str) -> Option<String> {
+    let declaration = declaration.trim().trim_end_matches(';');
+    let (property, value) = declaration.split_once(':')?;
+    let property = property.trim();
+    if property.is_empty() || property.contains(char::is_whitespace) {
+        return None;
+    }
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some(format!("{}:{}", property.to_ascii_lowercase(), value))
+}
+
+/// Ranges of declarations that are repeated identically later in the same block
+fn earlier_duplicates(block: &[(String, usize, usize)]) -> Vec<(usize, usize)> {
+    block
+        .iter()
+        .enumerate()
+        .filter(|(index, (key, _, _))| block[index + 1..].iter().any(|(later, _, _)| later == key))
+        .map(|(_, (_, start, end))| (*start, *end))
+        .collect()
+}
+
+/// CSS variables referenced through `var(--name)`, in first-use order
+pub fn referenced_variables(css: &str) -> Vec<String> {
+    let mut variables: Vec<String> = Vec::new();
+
+    for (index, _) in css.match_indices("var(") {
+        let rest = &css[index + "var(".len()..];
+        let end = rest.find([',', ')']).unwrap_or(rest.len());
+        let name = rest[..end].trim();
+        if name.starts_with("--") && !variables.iter().any(|v| v == name) {
+            variables.push(name.to_string());
+        }
+    }
+
+    variables
+}
+
+/// Number of declarations across all rules, including nested blocks
+pub fn count_declarations(css: &str) -> usize {
+    let mut count = 0;
+    let mut segment_start = 0;
+    let mut parens = 0usize;
+
+    for (index, ch) in css.char_indices() {
+        match ch {
+            '(' => parens += 1,
+            ')' => parens = parens.saturating_sub(1),
+            ';' | '}' if parens == 0 => {
+                if declaration_key(&css[segment_start..index]).is_some() {
+                    count += 1;
+                }
+                segment_start = index + 1;
+            }
+            '{' if parens == 0 => segment_start = index + 1,
+            _ => {}
+        }
+    }
+
+    if declaration_key(&css[segment_start..]).is_some() {
+        count += 1;
+    }
+    count
+}
+
 /// Compress CSS by removing unnecessary whitespace
 #[allow(dead_code)]
 pub fn compress_css(css: &str) -> String {
@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
@@ -70,6 +70,26 @@ struct StyledComponentWithPropsInput {
     tag_name: Ident,
     props_type: syn::Type,
     css: LitStr,
+    variant_bindings: Vec<VariantBinding>,
+}
+
+/// 属性到变体的绑定，例如 `size = props.size`
+struct VariantBinding {
+    variant_type: Ident,
+    value: Expr,
+}
+
+impl Parse for VariantBinding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let variant_type: Ident = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let value: Expr = input.parse()?;
+
+        Ok(VariantBinding {
+            variant_type,
+            value,
+        })
+    }
 }
 
 impl Parse for StyledComponentWithPropsInput {
@@ -85,11 +105,31 @@ impl Parse for StyledComponentWithPropsInput {
 
         let css: LitStr = input.parse()?;
 
+        // 可选的变体绑定：variant(size = props.size, color = props.color)
+        let mut variant_bindings = Vec::new();
+        if input.parse::<Option<Comma>>()?.is_some() && !input.is_empty() {
+            let keyword: Ident = input.parse()?;
+            if keyword != "variant" {
+                return Err(syn::Error::new(
+                    keyword.span(),
+                    "expected `variant(name = expr, ...)`",
+                ));
+            }
+            let content;
+            syn::parenthesized!(content in input);
+            variant_bindings = content
+                .parse_terminated(VariantBinding::parse, Comma)?
+                .into_iter()
+                .collect();
+            input.parse::<Option<Comma>>()?;
+        }
+
         Ok(StyledComponentWithPropsInput {
             component_name,
             tag_name,
             props_type,
             css,
+            variant_bindings,
         })
     }
 }
@@ -99,7 +139,15 @@ impl Parse for StyledComponentWithPropsInput {
 /// 使用方式：
 /// ```ignore
 /// styled_component_with_props!(MyButton, button, ButtonProps, "background-color: blue; color: white;");
+///
+/// // 将属性绑定到以组件名注册的 VariantConfig 变体
+/// styled_component_with_props!(MyButton, button, ButtonProps, "color: white;", variant(size = props.size));
 /// ```
+///
+/// 生成的组件依赖 `css_in_rust::theme::dioxus`（`use_style`、`styled::StyledProps`），
+/// 该模块目前不在 css-in-rust 中，需要由使用方提供。
+/// 变体绑定会额外生成独立的辅助函数 `__css_in_rust_<组件名>_variant_class(props, base_class)`，
+/// 只依赖 `css_in_rust::variants::bind_prop_variants`，可在组件之外单独调用。
 pub fn styled_component_with_props_impl(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as StyledComponentWithPropsInput);
 
@@ -108,7 +156,37 @@ pub fn styled_component_with_props_impl(input: TokenStream) -> TokenStream {
     let props_type = &input.props_type;
     let css = &input.css;
 
+    let (variant_helper, variant_binding) = if input.variant_bindings.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        let helper_name = format_ident!("__css_in_rust_{}_variant_class", component_name);
+        let variant_types = input
+            .variant_bindings
+            .iter()
+            .map(|binding| binding.variant_type.to_string());
+        let values = input.variant_bindings.iter().map(|binding| &binding.value);
+        let helper = quote! {
+            /// 按属性绑定解析变体，返回合并后的类名
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            pub fn #helper_name(props: &#props_type, base_class: &str) -> String {
+                css_in_rust::variants::bind_prop_variants(
+                    base_class,
+                    stringify!(#component_name),
+                    &[#((#variant_types, (#values).to_string())),*],
+                )
+            }
+        };
+        let binding = quote! {
+            // 应用属性绑定的变体
+            class_name = #helper_name(&cx.props.props, &class_name);
+        };
+        (helper, binding)
+    };
+
     let output = quote! {
+        #variant_helper
+
         #[component]
         pub fn #component_name(cx: Scope<css_in_rust::theme::dioxus::styled::StyledProps<#props_type>>) -> dioxus::prelude::Element {
             use css_in_rust::theme::dioxus::use_style;
@@ -124,6 +202,8 @@ pub fn styled_component_with_props_impl(input: TokenStream) -> TokenStream {
                 }
             }
 
+            #variant_binding
+
             // 获取原始属性
             let mut props = cx.props.props.clone();
 
@@ -148,13 +228,28 @@ pub fn styled_component_with_props_impl(input: TokenStream) -> TokenStream {
 
 /// 主题化样式输入
 struct ThemedStyleInput {
+    /// 是否要求所有 `var()` 都提供回退值
+    require_fallbacks: bool,
     css_expr: Expr,
 }
 
 impl Parse for ThemedStyleInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let require_fallbacks = input.peek(Ident) && input.peek2(Comma) && {
+            let fork = input.fork();
+            let flag: Ident = fork.parse()?;
+            flag == "require_fallbacks"
+        };
+        if require_fallbacks {
+            input.parse::<Ident>()?;
+            input.parse::<Comma>()?;
+        }
+
         let css_expr = input.parse()?;
-        Ok(ThemedStyleInput { css_expr })
+        Ok(ThemedStyleInput {
+            require_fallbacks,
+            css_expr,
+        })
     }
 }
 
@@ -163,11 +258,20 @@ impl Parse for ThemedStyleInput {
 /// 使用方式：
 /// ```ignore
 /// themed_style!(|theme| format!("color: {};", theme.colors.primary))
+///
+/// // 严格模式：表达式中的字符串字面量里每个 var() 都必须提供回退值
+/// themed_style!(require_fallbacks, "color: var(--primary, #1890ff);")
 /// ```
 pub fn themed_style_impl(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ThemedStyleInput);
     let css_expr = &input.css_expr;
 
+    if input.require_fallbacks {
+        if let Err(err) = check_var_fallbacks(quote!(#css_expr)) {
+            return err.to_compile_error().into();
+        }
+    }
+
     let output = quote! {
         {
             use css_in_rust::theme::dioxus::use_themed_style;
@@ -177,3 +281,66 @@ pub fn themed_style_impl(input: TokenStream) -> TokenStream {
 
     output.into()
 }
+
+/// 检查表达式中所有字符串字面量里的 `var()` 是否都提供了回退值
+fn check_var_fallbacks(tokens: proc_macro2::TokenStream) -> syn::Result<()> {
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Group(group) => check_var_fallbacks(group.stream())?,
+            proc_macro2::TokenTree::Literal(literal) => {
+                let Ok(lit) = syn::parse2::<LitStr>(literal.into_token_stream()) else {
+                    continue;
+                };
+                if let Some(var) = find_var_without_fallback(&lit.value()) {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "`{}` has no fallback value; use `var(--name, fallback)` when `require_fallbacks` is enabled",
+                            var
+                        ),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// 查找第一个没有回退值的 `var()` 调用
+fn find_var_without_fallback(css: &str) -> Option<String> {
+    let mut search_from = 0;
+
+    while let Some(offset) = css[search_from..].find("var(") {
+        let start = search_from + offset;
+        let args_start = start + "var(".len();
+
+        let mut depth = 1;
+        let mut has_fallback = false;
+        let mut end = css.len();
+        for (index, c) in css[args_start..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = args_start + index + 1;
+                        break;
+                    }
+                }
+                ',' if depth == 1 => has_fallback = true,
+                _ => {}
+            }
+        }
+
+        if !has_fallback {
+            return Some(css[start..end].to_string());
+        }
+
+        // 回退值中可能嵌套其他 var()，继续在参数内部查找
+        search_from = args_start;
+    }
+
+    None
+}
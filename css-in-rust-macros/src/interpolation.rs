@@ -0,0 +1,165 @@
+//! Runtime value interpolation for css! string literals
+//!
+//! Rules:
+//! - A placeholder is `{name}` where `name` is a Rust identifier in scope at the call site.
+//!   Values are formatted with `Display`.
+//! - Placeholders are only allowed in the value of a top-level declaration
+//!   (`width: {w}px;`). Property names, selectors and nested blocks must be static.
+//! - Declarations without placeholders stay in the static, compile-time scoped class;
+//!   declarations with placeholders are rendered at runtime into a separate class.
+
+/// A css! literal split into its static and interpolated declarations
+#[derive(Debug, PartialEq)]
+pub struct InterpolatedCss {
+    /// CSS without any placeholder, processed at compile time
+    pub static_css: String,
+    /// `format!` template for the interpolated declarations
+    pub dynamic_template: String,
+    /// Identifiers referenced by the template, in order
+    pub args: Vec<String>,
+}
+
+/// Split a css! literal into static and interpolated parts
+///
+/// Returns `Ok(None)` when the literal contains no placeholder.
+pub fn split_interpolations(css: &str) -> Result<Option<InterpolatedCss>, String> {
+    let segments = split_top_level(css);
+    if !segments
+        .iter()
+        .any(|segment| !find_placeholders(segment).is_empty())
+    {
+        return Ok(None);
+    }
+
+    let mut static_parts = Vec::new();
+    let mut dynamic_parts = Vec::new();
+    let mut args = Vec::new();
+
+    for segment in segments {
+        let placeholders = find_placeholders(segment);
+        let is_block = segment.contains('{') && has_block(segment, &placeholders);
+
+        if placeholders.is_empty() {
+            if is_block {
+                static_parts.push(segment.to_string());
+            } else {
+                static_parts.push(format!("{};", segment));
+            }
+            continue;
+        }
+
+        if is_block {
+            return Err(format!(
+                "css! interpolation is only supported in top-level declaration values, found one in `{}`",
+                segment
+            ));
+        }
+
+        let colon = segment.find(':').unwrap_or(segment.len());
+        let mut template = String::new();
+        let mut last = 0;
+        for (start, end, name) in placeholders {
+            if start < colon {
+                return Err(format!(
+                    "css! interpolation is not allowed in property names: `{}`",
+                    segment
+                ));
+            }
+            template.push_str(&segment[last..start]);
+            template.push_str("{}");
+            args.push(name.to_string());
+            last = end;
+        }
+        template.push_str(&segment[last..]);
+        dynamic_parts.push(format!("{};", template));
+    }
+
+    Ok(Some(InterpolatedCss {
+        static_css: static_parts.join(" "),
+        dynamic_template: dynamic_parts.join(" "),
+        args,
+    }))
+}
+
+/// Split CSS on top-level `;` and after top-level blocks, trimming empty segments
+fn split_top_level(css: &str) -> Vec<&str> {
+    let placeholders = find_placeholders(css);
+    let mut segments = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (index, c) in css.char_indices() {
+        if placeholders
+            .iter()
+            .any(|(open, close, _)| index >= *open && index < *close)
+        {
+            continue;
+        }
+
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    segments.push(&css[start..=index]);
+                    start = index + 1;
+                }
+            }
+            ';' if depth == 0 => {
+                segments.push(&css[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    segments.push(&css[start..]);
+
+    segments
+        .into_iter()
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Whether the segment contains a real `{ ... }` block besides placeholders
+fn has_block(segment: &str, placeholders: &[(usize, usize, &str)]) -> bool {
+    segment
+        .char_indices()
+        .filter(|(_, c)| *c == '{')
+        .any(|(index, _)| !placeholders.iter().any(|(open, _, _)| *open == index))
+}
+
+/// Find `{ident}` placeholders, returning (start, end, name) byte ranges
+fn find_placeholders(css: &str) -> Vec<(usize, usize, &str)> {
+    let mut placeholders = Vec::new();
+
+    for (open, _) in css.match_indices('{') {
+        let rest = &css[open + 1..];
+        let Some(close) = rest.find('}') else {
+            continue;
+        };
+
+        let name = &rest[..close];
+        if is_identifier(name) {
+            placeholders.push((open, open + close + 2, name));
+        }
+    }
+
+    placeholders
+}
+
+/// Whether the string is a plain Rust identifier
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -10,13 +10,16 @@ use proc_macro2::TokenStream as TokenStream2;
 mod cache_management;
 mod css_processing;
 mod hash_utils;
+mod interpolation;
 mod macro_definitions;
+mod manifest;
 mod theme_variants;
 mod utility_conversion;
 mod wasm_types;
 
 use macro_definitions::{
-    css_class_impl_internal, css_if_impl_internal, css_impl_internal, css_multi_if_impl_internal,
+    css_class_impl_internal, css_global_impl_internal, css_if_impl_internal, css_impl_internal,
+    css_multi_if_impl_internal, css_with_meta_impl_internal,
 };
 
 mod css_macro;
@@ -39,11 +42,89 @@ mod dioxus_macros;
 /// // Variant syntax
 /// let class_name = css!("hover:bg-primary-500 sm:text-lg dark:text-white");
 /// ```
+///
+/// In item position an optional name exposes the generated class as a `const`,
+/// so build tools can cross-reference declared and referenced classes. A companion
+/// `*_STYLE` static injects the style on first use, like `css!`; outside the
+/// browser it is registered with `css_in_rust::runtime`, so the calling crate must
+/// depend on `css-in-rust`:
+///
+/// ```rust,ignore
+/// use css_in_rust::css;
+///
+/// css!(name = "primary-btn", "color: white; background: blue;");
+///
+/// assert!(PRIMARY_BTN.starts_with("css-"));
+/// let class_name: &str = *PRIMARY_BTN_STYLE; // injects the style
+/// assert_eq!(class_name, PRIMARY_BTN);
+/// ```
+///
+/// Rust values can be interpolated into top-level declaration values with
+/// `{ident}`. Static declarations keep their compile-time class; interpolated
+/// ones are rendered into an extra `css-dyn-*` class at runtime. The dynamic class
+/// is named and registered through `css_in_rust::runtime`, so the calling crate
+/// must depend on `css-in-rust`:
+///
+/// ```rust,ignore
+/// use css_in_rust::css;
+///
+/// let width = 120;
+/// let class_name = css!("color: red; width: {width}px;");
+///
+/// assert!(class_name.split(' ').nth(1).unwrap().starts_with("css-dyn-"));
+/// ```
 #[proc_macro]
 pub fn css(input: TokenStream) -> TokenStream {
     css_macro::css_impl(input)
 }
 
+/// Global CSS macro for styles that must not be scoped to a generated class
+///
+/// Use it for `@keyframes`, `:root` variables or resets. The CSS is optimized at
+/// compile time, injected once at runtime, and the macro evaluates to the CSS.
+///
+/// # Examples
+///
+/// ```rust
+/// use css_in_rust_macros::css_global;
+///
+/// let css = css_global!(":root { --gap: 4px; } @keyframes spin { to { transform: rotate(360deg); } }");
+/// assert!(css.starts_with(":root"));
+/// ```
+#[proc_macro]
+pub fn css_global(input: TokenStream) -> TokenStream {
+    let input2 = TokenStream2::from(input);
+    match css_global_impl_internal(input2) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// CSS macro that also returns metadata about the style
+///
+/// Expands to a `css_in_rust::runtime::StyleMeta` holding the class name (the
+/// same one `css!` generates), the content hash, the `var(--x)` references and
+/// the declaration count, for tools that track theme-variable dependencies.
+/// Requires the `css-in-rust` crate; interpolated values are not supported.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use css_in_rust::css_with_meta;
+///
+/// let meta = css_with_meta!("color: var(--primary); padding: 4px;");
+/// assert_eq!(meta.variables, ["--primary"]);
+/// assert_eq!(meta.declaration_count, 2);
+/// ```
+#[proc_macro]
+pub fn css_with_meta(input: TokenStream) -> TokenStream {
+    let input2 = TokenStream2::from(input);
+    match css_with_meta_impl_internal(input2) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
 /// Conditional CSS macro that only applies styles when condition is true
 ///
 /// # Examples
@@ -1,33 +1,46 @@
 use crate::cache_management::{cache_css, get_cached_css};
 use crate::css_processing::{
-    optimize_css_with_lightningcss, parse_css_syntax, process_media_queries,
-    process_pseudo_selectors,
+    count_declarations, dedup_declarations, optimize_css_with_lightningcss, optimize_global_css,
+    parse_css_syntax, process_media_queries, process_pseudo_selectors, referenced_variables,
 };
 use crate::hash_utils::calculate_css_hash;
+use crate::interpolation::{split_interpolations, InterpolatedCss};
+use crate::manifest::record_class;
 use crate::theme_variants::process_css_with_variants_and_themes;
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{Error, LitStr, Result};
 
 /// Internal implementation of the css! macro
 pub fn css_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
-    let css_content = if let Ok(lit_str) = syn::parse2::<LitStr>(input.clone()) {
-        lit_str.value()
-    } else {
-        parse_css_syntax(input)?
-    };
+    // Named form: css!(name = "btn", "...") exposes the class as a const item
+    if let Some((name, css_input)) = split_named_css_input(input.clone())? {
+        return css_named_const(&name, css_input);
+    }
+
+    // Interpolated form: css!("width: {w}px;") renders placeholders at runtime
+    if let Ok(lit_str) = syn::parse2::<LitStr>(input.clone()) {
+        let interpolated =
+            split_interpolations(&lit_str.value()).map_err(|e| Error::new(lit_str.span(), e))?;
+        if let Some(interpolated) = interpolated {
+            return css_interpolated(interpolated);
+        }
+    }
+
+    let css_content = css_content_from_input(input)?;
 
     // Calculate hash for caching
     let css_hash = calculate_css_hash(&css_content);
 
+    // Generate CSS ID; recorded before the cache check so every call site is listed
+    let css_id = format!("css-{}", &css_hash[..8]);
+    record_class(&css_id);
+
     // Check cache first
     if let Some(cached_class) = get_cached_css(&css_hash) {
         return Ok(quote! { #cached_class });
     }
 
-    // Generate CSS ID
-    let css_id = format!("css-{}", &css_hash[..8]);
-
     // Process CSS with caching
     let result = process_css_with_cache(&css_content, &css_id)?;
 
@@ -37,6 +50,311 @@ pub fn css_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
     Ok(result)
 }
 
+/// Internal implementation of the css_with_meta! macro
+///
+/// Expands to a `css_in_rust::runtime::StyleMeta` whose class name matches what
+/// `css!` generates for the same CSS.
+pub fn css_with_meta_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
+    if split_named_css_input(input.clone())?.is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            "css_with_meta! does not support the `name = ...` form",
+        ));
+    }
+
+    let css_content = css_content_from_input(input.clone())?;
+    if split_interpolations(&css_content)
+        .map_err(|e| Error::new(Span::call_site(), e))?
+        .is_some()
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "css_with_meta! must be static; interpolated values are not allowed",
+        ));
+    }
+
+    let class_name = css_impl_internal(input)?;
+    let hash = calculate_css_hash(&css_content);
+    let variables = referenced_variables(&css_content);
+    let declaration_count = count_declarations(&css_content);
+
+    Ok(quote! {
+        ::css_in_rust::runtime::StyleMeta {
+            class_name: ::std::string::ToString::to_string(&#class_name),
+            hash: #hash,
+            variables: &[#(#variables),*],
+            declaration_count: #declaration_count,
+        }
+    })
+}
+
+/// Extract the CSS content from a css! input (string literal or CSS syntax)
+///
+/// Identical declarations repeated within a rule are collapsed before hashing.
+fn css_content_from_input(input: TokenStream2) -> syn::Result<String> {
+    let css = if let Ok(lit_str) = syn::parse2::<LitStr>(input.clone()) {
+        lit_str.value()
+    } else {
+        parse_css_syntax(input)?
+    };
+    Ok(dedup_declarations(&css))
+}
+
+/// Split a `name = "...", <css>` input into the name and the remaining CSS input
+///
+/// Returns `None` when the input does not start with `name =`.
+fn split_named_css_input(input: TokenStream2) -> syn::Result<Option<(LitStr, TokenStream2)>> {
+    use proc_macro2::TokenTree;
+
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let is_named = matches!(
+        (tokens.first(), tokens.get(1)),
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(eq)))
+            if ident == "name" && eq.as_char() == '='
+    );
+    if !is_named {
+        return Ok(None);
+    }
+
+    let name = match tokens.get(2) {
+        Some(token) => syn::parse2::<LitStr>(token.clone().into())?,
+        None => {
+            return Err(Error::new(
+                Span::call_site(),
+                "css! name must be a string literal, e.g. css!(name = \"btn\", \"...\")",
+            ))
+        }
+    };
+
+    match tokens.get(3) {
+        Some(TokenTree::Punct(comma)) if comma.as_char() == ',' => {}
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "expected `,` followed by CSS after the css! name",
+            ))
+        }
+    }
+
+    Ok(Some((name, tokens[4..].iter().cloned().collect())))
+}
+
+/// Generate a named const holding the class name of the given CSS
+///
+/// The const name is the upper-cased `name` (`"primary-btn"` becomes `PRIMARY_BTN`),
+/// and its value equals the class name `css!` generates for the same CSS, so build
+/// tools can cross-reference declared and referenced classes. Being a plain
+/// `&str`, it works in const contexts and `match` patterns.
+///
+/// Reading a const cannot inject anything, so a companion `PRIMARY_BTN_STYLE`
+/// static runs the same expansion as `css!` on first deref and yields the class
+/// name: the style is injected into the document on web targets, and on other
+/// targets the declarations are registered with `css_in_rust::runtime::inject_style`.
+fn css_named_const(name: &LitStr, css_input: TokenStream2) -> syn::Result<TokenStream2> {
+    let const_name = name.value().replace('-', "_").to_uppercase();
+    let const_ident = syn::parse_str::<syn::Ident>(&const_name).map_err(|_| {
+        Error::new(
+            name.span(),
+            format!("`{}` is not a valid css! name", name.value()),
+        )
+    })?;
+
+    let css_content = css_content_from_input(css_input)?;
+    if split_interpolations(&css_content)
+        .map_err(|e| Error::new(name.span(), e))?
+        .is_some()
+    {
+        return Err(Error::new(
+            name.span(),
+            "named css! must be static; interpolated values are not allowed",
+        ));
+    }
+
+    let css_hash = calculate_css_hash(&css_content);
+    let css_id = format!("css-{}", &css_hash[..8]);
+    record_class(&css_id);
+    let style_ident = Ident::new(&format!("{}_STYLE", const_name), const_ident.span());
+    let doc = format!("Class name generated by `css!` for `{}`", name.value());
+    let style_doc = format!(
+        "Injects the `css!` style for `{}` on first use and yields [`{}`]",
+        name.value(),
+        const_name
+    );
+
+    let processed_css = process_css_with_variants_and_themes(&css_content)?;
+    let declarations = optimize_css_with_lightningcss(&processed_css.css)
+        .unwrap_or_else(|_| processed_css.css.clone());
+    let class_name = process_css_with_cache(&css_content, &css_id)?;
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        pub const #const_ident: &str = #css_id;
+
+        #[doc = #style_doc]
+        #[allow(dead_code)]
+        pub static #style_ident: ::std::sync::LazyLock<&'static str> =
+            ::std::sync::LazyLock::new(|| {
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ::css_in_rust::runtime::inject_style(#declarations, #css_id);
+                }
+
+                let _ = #class_name;
+                #const_ident
+            });
+    })
+}
+
+/// Generate the class list for an interpolated css! literal
+///
+/// Static declarations go through the regular compile-time pipeline. The
+/// interpolated declarations are formatted at runtime, hashed into a
+/// `css-dyn-*` class with `css_in_rust::runtime::generate_class_name` (so server
+/// and client agree on the name) and injected once per distinct value. Outside
+/// the browser they are registered with `css_in_rust::runtime` for SSR.
+///
+/// Locals in the expansion carry a `__css_in_rust_` prefix so they cannot
+/// shadow interpolated identifiers.
+fn css_interpolated(interpolated: InterpolatedCss) -> syn::Result<TokenStream2> {
+    let static_hash = calculate_css_hash(&interpolated.static_css);
+    let static_id = format!("css-{}", &static_hash[..8]);
+    record_class(&static_id);
+    let static_class = process_css_with_cache(&interpolated.static_css, &static_id)?;
+    cache_css(static_hash, static_id);
+
+    let template = interpolated.dynamic_template;
+    let args = interpolated
+        .args
+        .iter()
+        .map(|name| Ident::new(name, Span::call_site()));
+    let injection = web_style_injection(
+        quote! { &__css_in_rust_dynamic_class },
+        quote! {
+            &format!(
+                ".{} {{ {} }}",
+                __css_in_rust_dynamic_class, __css_in_rust_dynamic_css
+            )
+        },
+    );
+
+    Ok(quote! {
+        {
+            let __css_in_rust_static_class = (#static_class).to_string();
+            let __css_in_rust_dynamic_css = format!(#template, #(#args),*);
+            let __css_in_rust_dynamic_class = format!(
+                "css-dyn-{}",
+                &::css_in_rust::runtime::generate_class_name(&__css_in_rust_dynamic_css)
+                    ["css-".len()..]
+            );
+
+            #injection
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                ::css_in_rust::runtime::inject_style(
+                    &__css_in_rust_dynamic_css,
+                    &__css_in_rust_dynamic_class,
+                );
+            }
+
+            format!("{} {}", __css_in_rust_static_class, __css_in_rust_dynamic_class)
+        }
+    })
+}
+
+/// Generate web-only code that injects `css` into a `<style id=style_id>` once
+fn web_style_injection(style_id: TokenStream2, css: TokenStream2) -> TokenStream2 {
+    quote! {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::prelude::*;
+
+            #[wasm_bindgen]
+            extern "C" {
+                type Document;
+                type Element;
+                type Node;
+
+                #[wasm_bindgen(method, getter, js_name = head)]
+                fn head(this: &Document) -> Element;
+
+                #[wasm_bindgen(method, js_name = createElement)]
+                fn create_element(this: &Document, tag_name: &str) -> Element;
+
+                #[wasm_bindgen(method, js_name = getElementById)]
+                fn get_element_by_id(this: &Document, id: &str) -> Option<Element>;
+
+                #[wasm_bindgen(method, js_name = setAttribute)]
+                fn set_attribute(this: &Element, name: &str, value: &str);
+
+                #[wasm_bindgen(method, setter, js_name = innerHTML)]
+                fn set_inner_html(this: &Element, html: &str);
+
+                #[wasm_bindgen(method, js_name = appendChild)]
+                fn append_child(this: &Element, child: &Node);
+
+                #[wasm_bindgen(js_name = document)]
+                static DOCUMENT: Document;
+            }
+
+            impl From<Element> for Node {
+                fn from(element: Element) -> Node {
+                    element.unchecked_into()
+                }
+            }
+
+            if DOCUMENT.get_element_by_id(#style_id).is_none() {
+                let style_element = DOCUMENT.create_element("style");
+                style_element.set_attribute("id", #style_id);
+                style_element.set_inner_html(#css);
+                DOCUMENT.head().append_child(&style_element.into());
+            }
+        }
+    }
+}
+
+/// Internal implementation of the css_global! macro
+///
+/// The CSS is optimized as a complete stylesheet and injected without being
+/// scoped to a generated class. The macro evaluates to the optimized CSS.
+/// Outside the browser it is registered on every evaluation with
+/// `css_in_rust::runtime::inject_global_style`, so it reaches the SSR output
+/// even after the runtime's styles have been cleared.
+pub fn css_global_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let lit_str = syn::parse2::<LitStr>(input).map_err(|e| {
+        Error::new(
+            e.span(),
+            "css_global! expects a string literal, e.g. css_global!(\":root { --gap: 4px; }\")",
+        )
+    })?;
+
+    let optimized_css =
+        optimize_global_css(&lit_str.value()).map_err(|e| Error::new(lit_str.span(), e))?;
+
+    let css_hash = calculate_css_hash(&optimized_css);
+    let style_id = format!("css-global-{}", &css_hash[..8]);
+    let injection = web_style_injection(quote! { #style_id }, quote! { #optimized_css });
+
+    Ok(quote! {
+        {
+            static CSS_GLOBAL_INJECTED: ::std::sync::OnceLock<::std::string::String> = ::std::sync::OnceLock::new();
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                ::css_in_rust::runtime::inject_global_style(#optimized_css, #style_id);
+            }
+
+            CSS_GLOBAL_INJECTED.get_or_init(|| {
+                // Inject CSS into document head (web target only)
+                #injection
+
+                #optimized_css.to_string()
+            }).clone()
+        }
+    })
+}
+
 /// Internal implementation of the css_if! macro
 pub fn css_if_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
     // Parse the input to extract condition and CSS
@@ -69,6 +387,10 @@ pub fn css_if_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
     // Calculate hash for caching
     let css_hash = calculate_css_hash(css_content);
 
+    // Generate CSS ID; recorded before the cache check so every call site is listed
+    let css_id = format!("css-{}", &css_hash[..8]);
+    record_class(&css_id);
+
     // Check cache first
     if let Some(cached_class) = get_cached_css(&css_hash) {
         return Ok(quote! {
@@ -82,9 +404,6 @@ pub fn css_if_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2> {
         });
     }
 
-    // Generate CSS ID
-    let css_id = format!("css-{}", &css_hash[..8]);
-
     // Process CSS with caching
     let css_processing_result = process_css_with_cache(css_content, &css_id)?;
 
@@ -114,6 +433,7 @@ pub fn css_class_impl_internal(input: TokenStream2) -> syn::Result<TokenStream2>
     // Generate a hash-based class name to ensure uniqueness
     let css_hash = calculate_css_hash(&class_name);
     let unique_class = format!("{}-{}", class_name, &css_hash[..8]);
+    record_class(&unique_class);
 
     let tokens = quote! {
         #unique_class
@@ -160,6 +480,7 @@ pub fn css_multi_if_impl_internal(input: TokenStream2) -> Result<TokenStream2> {
     // Generate a unique identifier for this CSS block
     let css_hash = calculate_css_hash(css_content);
     let css_id = format!("css-multi-{}", &css_hash[..8]);
+    record_class(&css_id);
     let css_id_literal = css_id.clone();
 
     // Process media queries and pseudo selectors
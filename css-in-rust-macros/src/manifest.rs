@@ -0,0 +1,101 @@
+//! Build-time class manifest
+//!
+//! When the `CSS_IN_RUST_MANIFEST` environment variable names a file, every
+//! `css!`, `css_with_meta!`, `css_if!`, `css_multi_if!` and `css_class!`
+//! expansion appends one JSON line describing the generated class and where it
+//! was used. Purge tooling can then keep exactly the classes that are
+//! referenced by the compiled code.
+//!
+//! Classes that only exist at runtime are not listed: the `css-dyn-*` classes
+//! of interpolated `css!` calls and the classes produced by the Dioxus macros
+//! through `use_style`. Purge tooling must keep those by prefix.
+//!
+//! Entries are written only while a macro expands, and rustc does not track
+//! `CSS_IN_RUST_MANIFEST`, so crates that an incremental build does not
+//! recompile contribute nothing. Generate the manifest from a clean build
+//! (e.g. `cargo clean` first) before using it to purge styles.
+//!
+//! Proc-macros run once per invocation and possibly from several rustc
+//! processes at once, so each write takes an exclusive file lock.
+
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+/// Environment variable holding the manifest path
+pub const MANIFEST_ENV: &str = "CSS_IN_RUST_MANIFEST";
+
+/// Records a generated class in the manifest, if one is configured
+///
+/// Errors are ignored: the manifest is a build aid and must never fail the
+/// compilation. Entries already present (e.g. from an incremental rebuild) are
+/// not written twice.
+pub fn record_class(class_name: &str) {
+    let Some(path) = std::env::var_os(MANIFEST_ENV).filter(|path| !path.is_empty()) else {
+        return;
+    };
+    let (file, line, column) = call_site();
+    let entry = manifest_entry(class_name, &file, line, column);
+    let _ = append_unique_line(std::path::Path::new(&path), &entry);
+}
+
+/// Returns the source location of the current macro invocation
+fn call_site() -> (String, usize, usize) {
+    if !proc_macro::is_available() {
+        return ("<unknown>".to_string(), 0, 0);
+    }
+    let span = proc_macro::Span::call_site();
+    (span.file(), span.line(), span.column())
+}
+
+/// Formats one manifest line as a JSON object
+fn manifest_entry(class_name: &str, file: &str, line: usize, column: usize) -> String {
+    format!(
+        r#"{{"class":"{}","file":"{}","line":{},"column":{}}}"#,
+        escape_json(class_name),
+        escape_json(file),
+        line,
+        column
+    )
+}
+
+/// Escapes a string for use inside a JSON string literal
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends `line` to the file under an exclusive lock unless it is already present
+fn append_unique_line(path: &std::path::Path, line: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)?;
+    file.lock()?;
+
+    let mut existing = String::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_string(&mut existing)?;
+    if existing.lines().any(|existing| existing == line) {
+        return Ok(());
+    }
+
+    let mut output = String::new();
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        output.push('\n');
+    }
+    output.push_str(line);
+    output.push('\n');
+    file.write_all(output.as_bytes())
+}
@@ -13,6 +13,14 @@ use css_in_rust_macros::{css, css_class, css_if, css_multi_if};
 mod css_macro_tests {
     use super::*;
 
+    /// 测试不含插值的 CSS 只生成一个静态类名
+    #[test]
+    fn test_interpolation_static_only() {
+        let class_name = css!("color: red;");
+        assert!(class_name.starts_with("css-"));
+        assert!(!class_name.contains(' '));
+    }
+
     /// 测试基础 CSS 样式
     #[test]
     fn test_basic_css() {
@@ -135,6 +143,34 @@ mod css_macro_tests {
         // 不同的 CSS 内容应该生成不同的类名
         assert_ne!(class_name1, class_name2);
     }
+
+    /// 测试规则内完全相同的声明在编译期去重
+    #[test]
+    fn test_duplicate_declarations_collapse() {
+        assert_eq!(css!("color: red; color: red;"), css!("color: red;"));
+        assert_eq!(
+            css!("color: red; margin: 0; color:  red;"),
+            css!("margin: 0; color:  red;")
+        );
+        assert_eq!(
+            css!("&:hover { color: red; color: red; }"),
+            css!("&:hover { color: red; }")
+        );
+    }
+
+    /// 测试同一属性的不同值全部保留，最后一个值仍然生效
+    #[test]
+    fn test_different_values_keep_last() {
+        assert_eq!(
+            css!("color: red; color: blue; color: red;"),
+            css!("color: blue; color: red;")
+        );
+        assert_ne!(css!("color: red; color: blue;"), css!("color: blue;"));
+        assert_ne!(
+            css!("color: red; color: blue;"),
+            css!("color: blue; color: red;")
+        );
+    }
 }
 
 #[cfg(test)]
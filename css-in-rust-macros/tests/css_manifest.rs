@@ -0,0 +1,76 @@
+//! css! 类名清单测试
+//!
+//! 验证设置 `CSS_IN_RUST_MANIFEST` 后编译期会记录每个生成类名的宏调用
+use lightningcss as _;
+use proc_macro2 as _;
+use quote as _;
+use sha2 as _;
+use syn as _;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_manifest_records_every_class_macro_call() {
+    let dir = std::env::temp_dir().join(format!("css_in_rust_manifest_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let manifest = dir.join("classes.jsonl");
+    let _ = std::fs::remove_file(&manifest);
+
+    // 每次生成不同的 CSS，保证 cargo 不会跳过宏展开
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let fixture = dir.join("manifest_fixture.rs");
+    std::fs::write(
+        &fixture,
+        format!(
+            "use css_in_rust_macros::{{css, css_class, css_if, css_multi_if}};\n\
+             fn main() {{\n\
+             \x20   let a = css!(\"color: red; --nonce: {nonce};\");\n\
+             \x20   let b = css!(\"color: blue; --nonce: {nonce};\");\n\
+             \x20   assert_ne!(a, b);\n\
+             \x20   let _ = css_if!(true, \"color: green; --nonce: {nonce};\");\n\
+             \x20   let _ = css_multi_if!(true, \"color: gray; --nonce: {nonce};\");\n\
+             \x20   let _ = css_class!(\"nonce-{nonce}\");\n\
+             }}\n"
+        ),
+    )
+    .unwrap();
+
+    std::env::set_var("CSS_IN_RUST_MANIFEST", &manifest);
+    let t = trybuild::TestCases::new();
+    t.pass(&fixture);
+    drop(t);
+    std::env::remove_var("CSS_IN_RUST_MANIFEST");
+
+    let content = std::fs::read_to_string(&manifest).unwrap();
+    let entries: Vec<&str> = content
+        .lines()
+        .filter(|line| line.contains("manifest_fixture.rs"))
+        .collect();
+    assert_eq!(entries.len(), 5, "manifest: {content}");
+    assert!(entries[0].starts_with(r#"{"class":"css-"#));
+    assert!(entries.iter().any(|entry| entry.contains(r#""line":3,"#)));
+    assert!(entries.iter().any(|entry| entry.contains(r#""line":4,"#)));
+    for line in 6..=8 {
+        let line = format!(r#""line":{line},"#);
+        assert!(
+            entries.iter().any(|entry| entry.contains(&line)),
+            "{content}"
+        );
+    }
+    assert!(entries
+        .iter()
+        .any(|entry| entry.contains(r#""class":"css-multi-"#)));
+    assert!(entries
+        .iter()
+        .any(|entry| entry.contains(&format!(r#""class":"nonce-{nonce}-"#))));
+    assert_ne!(
+        entries[0].split('"').nth(3),
+        entries[1].split('"').nth(3),
+        "the two css! calls must produce distinct classes"
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
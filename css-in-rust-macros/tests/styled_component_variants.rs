@@ -0,0 +1,15 @@
+//! styled_component_with_props! 变体绑定展开测试
+//!
+//! 验证 `variant(...)` 的解析以及生成的变体辅助函数
+use lightningcss as _;
+use proc_macro2 as _;
+use quote as _;
+use sha2 as _;
+use syn as _;
+
+#[test]
+fn test_styled_component_variant_bindings() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/styled_component_variants.rs");
+    t.compile_fail("tests/ui/styled_component_bad_variant.rs");
+}
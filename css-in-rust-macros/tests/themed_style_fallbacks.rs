@@ -0,0 +1,15 @@
+//! themed_style! 回退值检查测试
+//!
+//! 验证 `require_fallbacks` 模式下缺少回退值的 var() 会产生编译错误
+use lightningcss as _;
+use proc_macro2 as _;
+use quote as _;
+use sha2 as _;
+use syn as _;
+
+#[test]
+fn test_themed_style_require_fallbacks() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/themed_style_with_fallback.rs");
+    t.compile_fail("tests/ui/themed_style_missing_fallback.rs");
+}
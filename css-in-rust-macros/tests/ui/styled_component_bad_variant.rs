@@ -0,0 +1,15 @@
+use css_in_rust_macros::styled_component_with_props;
+
+pub struct ButtonProps {
+    size: &'static str,
+}
+
+styled_component_with_props!(
+    VariantButton,
+    button,
+    ButtonProps,
+    "color: white;",
+    variants(size = props.size)
+);
+
+fn main() {}
@@ -0,0 +1,43 @@
+use css_in_rust_macros::styled_component_with_props;
+
+// 生成的组件本体依赖 Dioxus 运行时；非测试构建中 `#[test]` 会移除被标注的项，
+// 这里借此跳过组件本体，只验证独立生成的变体辅助函数
+use core::prelude::v1::test as component;
+
+// 模拟 css_in_rust 运行时，记录传入的绑定
+mod css_in_rust {
+    pub mod variants {
+        pub fn bind_prop_variants(
+            base_class: &str,
+            component_name: &str,
+            bindings: &[(&str, String)],
+        ) -> String {
+            let bindings: Vec<String> = bindings
+                .iter()
+                .map(|(variant_type, value)| format!("{}={}", variant_type, value))
+                .collect();
+            format!("{} {}[{}]", base_class, component_name, bindings.join(","))
+        }
+    }
+}
+
+pub struct ButtonProps {
+    size: &'static str,
+    tone: u8,
+}
+
+styled_component_with_props!(
+    VariantButton,
+    button,
+    ButtonProps,
+    "color: white;",
+    variant(size = props.size, color = format!("tone{}", props.tone))
+);
+
+fn main() {
+    let props = ButtonProps { size: "lg", tone: 2 };
+    assert_eq!(
+        __css_in_rust_VariantButton_variant_class(&props, "base"),
+        "base VariantButton[size=lg,color=tone2]"
+    );
+}
@@ -0,0 +1,17 @@
+use css_in_rust_macros::themed_style;
+
+// 模拟 css_in_rust 运行时，仅用于验证宏展开
+mod css_in_rust {
+    pub mod theme {
+        pub mod dioxus {
+            pub fn use_themed_style(_cx: (), css: &str) -> String {
+                css.to_string()
+            }
+        }
+    }
+}
+
+fn main() {
+    let cx = ();
+    let _style = themed_style!(require_fallbacks, "color: var(--x);");
+}
@@ -0,0 +1,22 @@
+use css_in_rust_macros::themed_style;
+
+// 模拟 css_in_rust 运行时，仅用于验证宏展开
+mod css_in_rust {
+    pub mod theme {
+        pub mod dioxus {
+            pub fn use_themed_style(_cx: (), css: &str) -> String {
+                css.to_string()
+            }
+        }
+    }
+}
+
+fn main() {
+    let cx = ();
+    let style = themed_style!(require_fallbacks, "color: var(--x, red);");
+    assert_eq!(style, "color: var(--x, red);");
+
+    // 非严格模式不检查回退值
+    let style = themed_style!("color: var(--x);");
+    assert_eq!(style, "color: var(--x);");
+}
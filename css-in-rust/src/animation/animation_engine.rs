@@ -211,7 +211,7 @@ impl AnimationEngine {
     /// }
     /// ```
     pub fn register_keyframes(&self, keyframes: Keyframes) -> Result<(), String> {
-        keyframes.validate()?;
+        keyframes.validate().map_err(|e| e.to_string())?;
 
         if let Ok(mut registry) = self.keyframes_registry.lock() {
             registry.insert(keyframes.name.clone(), keyframes);
@@ -3,6 +3,18 @@
 //! 提供各种动画缓动函数，包括标准缓动和自定义贝塞尔曲线。
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// CSS 缓动关键字
+const EASING_KEYWORDS: [&str; 7] = [
+    "ease",
+    "ease-in",
+    "ease-out",
+    "ease-in-out",
+    "linear",
+    "step-start",
+    "step-end",
+];
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EasingFunction {
@@ -10,8 +22,75 @@ pub enum EasingFunction {
     Css(String),
     /// 自定义贝塞尔曲线缓动函数
     CubicBezier(f32, f32, f32, f32),
+    /// 阶跃缓动函数 `steps(n, position)`
+    Steps(u32, StepPosition),
+}
+
+/// `steps()` 的跳跃位置
+///
+/// 保留原始关键字，`end` 和 `jump-end` 虽然等价，但各自按原样输出。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StepPosition {
+    JumpStart,
+    JumpEnd,
+    JumpNone,
+    JumpBoth,
+    Start,
+    End,
+}
+
+impl StepPosition {
+    /// 获取对应的 CSS 关键字
+    pub fn as_css(&self) -> &'static str {
+        match self {
+            StepPosition::JumpStart => "jump-start",
+            StepPosition::JumpEnd => "jump-end",
+            StepPosition::JumpNone => "jump-none",
+            StepPosition::JumpBoth => "jump-both",
+            StepPosition::Start => "start",
+            StepPosition::End => "end",
+        }
+    }
+
+    /// 从 CSS 关键字解析
+    fn from_css(keyword: &str) -> Option<Self> {
+        match keyword {
+            "jump-start" => Some(StepPosition::JumpStart),
+            "jump-end" => Some(StepPosition::JumpEnd),
+            "jump-none" => Some(StepPosition::JumpNone),
+            "jump-both" => Some(StepPosition::JumpBoth),
+            "start" => Some(StepPosition::Start),
+            "end" => Some(StepPosition::End),
+            _ => None,
+        }
+    }
+}
+
+/// 缓动函数解析错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum EasingError {
+    /// 未知的缓动关键字或函数
+    UnknownEasing(String),
+    /// `cubic-bezier()` 参数无效
+    InvalidCubicBezier(String),
+    /// `steps()` 参数无效
+    InvalidSteps(String),
 }
 
+impl fmt::Display for EasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EasingError::UnknownEasing(value) => write!(f, "Unknown easing function: {}", value),
+            EasingError::InvalidCubicBezier(value) => {
+                write!(f, "Invalid cubic-bezier() arguments: {}", value)
+            }
+            EasingError::InvalidSteps(value) => write!(f, "Invalid steps() arguments: {}", value),
+        }
+    }
+}
+
+impl std::error::Error for EasingError {}
+
 impl EasingFunction {
     /// 转换为 CSS 字符串
     ///
@@ -40,6 +119,87 @@ impl EasingFunction {
             EasingFunction::CubicBezier(x1, y1, x2, y2) => {
                 format!("cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2)
             }
+            EasingFunction::Steps(count, position) => {
+                format!("steps({}, {})", count, position.as_css())
+            }
+        }
+    }
+
+    /// 从 CSS 字符串解析缓动函数
+    ///
+    /// 支持缓动关键字（`ease`、`linear`、`step-end` 等）、`cubic-bezier(x1, y1, x2, y2)`
+    /// 和 `steps(n[, position])`。`cubic-bezier()` 的 x 坐标必须在 `[0, 1]` 内，
+    /// `steps()` 省略位置时按 `end` 处理。解析结果经 [`EasingFunction::to_css`] 输出后可再次解析为相同的值。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::animation::{EasingFunction, StepPosition};
+    ///
+    /// let steps = EasingFunction::from_css("steps(4, jump-end)").unwrap();
+    /// assert_eq!(steps, EasingFunction::Steps(4, StepPosition::JumpEnd));
+    /// assert_eq!(steps.to_css(), "steps(4, jump-end)");
+    ///
+    /// let bezier = EasingFunction::from_css("cubic-bezier(0.25,0.1,0.25,1)").unwrap();
+    /// assert_eq!(bezier.to_css(), "cubic-bezier(0.25, 0.1, 0.25, 1)");
+    /// ```
+    ///
+    /// # 错误
+    ///
+    /// 未知关键字或函数参数无效时返回 [`EasingError`]。
+    pub fn from_css(value: &str) -> Result<EasingFunction, EasingError> {
+        let value = value.trim();
+        let lower = value.to_ascii_lowercase();
+
+        if EASING_KEYWORDS.contains(&lower.as_str()) {
+            return Ok(EasingFunction::Css(lower));
+        }
+
+        let Some((name, rest)) = lower.split_once('(') else {
+            return Err(EasingError::UnknownEasing(value.to_string()));
+        };
+        let Some(args) = rest.strip_suffix(')') else {
+            return Err(EasingError::UnknownEasing(value.to_string()));
+        };
+        let args: Vec<&str> = args.split(',').map(str::trim).collect();
+
+        match name.trim() {
+            "cubic-bezier" => {
+                let points: Vec<f32> = args
+                    .iter()
+                    .map(|arg| arg.parse::<f32>())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| EasingError::InvalidCubicBezier(value.to_string()))?;
+                let [x1, y1, x2, y2] = points[..] else {
+                    return Err(EasingError::InvalidCubicBezier(value.to_string()));
+                };
+                if !(0.0..=1.0).contains(&x1) || !(0.0..=1.0).contains(&x2) {
+                    return Err(EasingError::InvalidCubicBezier(value.to_string()));
+                }
+                Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
+            }
+            "steps" => {
+                let invalid = || EasingError::InvalidSteps(value.to_string());
+                let (count, position) = match args[..] {
+                    [count] => (count, StepPosition::End),
+                    [count, position] => {
+                        (count, StepPosition::from_css(position).ok_or_else(invalid)?)
+                    }
+                    _ => return Err(invalid()),
+                };
+                let count: u32 = count.parse().map_err(|_| invalid())?;
+                // jump-none 至少需要两步，其余位置至少一步
+                let min_count = if position == StepPosition::JumpNone {
+                    2
+                } else {
+                    1
+                };
+                if count < min_count {
+                    return Err(invalid());
+                }
+                Ok(EasingFunction::Steps(count, position))
+            }
+            _ => Err(EasingError::UnknownEasing(value.to_string())),
         }
     }
 
@@ -213,6 +373,54 @@ mod tests {
         assert_eq!(cubic_easing.suggested_duration_ms(), 200);
     }
 
+    #[test]
+    fn test_easing_css_round_trip() {
+        let steps = EasingFunction::from_css("steps(4, jump-end)").unwrap();
+        assert_eq!(steps, EasingFunction::Steps(4, StepPosition::JumpEnd));
+        assert_eq!(steps.to_css(), "steps(4, jump-end)");
+
+        let legacy = EasingFunction::from_css("steps(4, end)").unwrap();
+        assert_eq!(legacy.to_css(), "steps(4, end)");
+        assert_eq!(
+            EasingFunction::from_css("steps(3)").unwrap(),
+            EasingFunction::Steps(3, StepPosition::End)
+        );
+
+        let bezier = EasingFunction::from_css("cubic-bezier(0.25,0.1,0.25,1)").unwrap();
+        assert_eq!(bezier, EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0));
+        assert_eq!(bezier.to_css(), "cubic-bezier(0.25, 0.1, 0.25, 1)");
+        assert_eq!(EasingFunction::from_css(&bezier.to_css()).unwrap(), bezier);
+
+        assert_eq!(
+            EasingFunction::from_css("Ease-In-Out").unwrap().to_css(),
+            "ease-in-out"
+        );
+    }
+
+    #[test]
+    fn test_easing_from_css_errors() {
+        assert!(matches!(
+            EasingFunction::from_css("wobble"),
+            Err(EasingError::UnknownEasing(_))
+        ));
+        assert!(matches!(
+            EasingFunction::from_css("cubic-bezier(1.5, 0, 0.5, 1)"),
+            Err(EasingError::InvalidCubicBezier(_))
+        ));
+        assert!(matches!(
+            EasingFunction::from_css("cubic-bezier(0.1, 0.2)"),
+            Err(EasingError::InvalidCubicBezier(_))
+        ));
+        assert!(matches!(
+            EasingFunction::from_css("steps(1, jump-none)"),
+            Err(EasingError::InvalidSteps(_))
+        ));
+        assert!(matches!(
+            EasingFunction::from_css("steps(0)"),
+            Err(EasingError::InvalidSteps(_))
+        ));
+    }
+
     #[test]
     fn test_easing_factory() {
         let standard = EasingFactory::standard();
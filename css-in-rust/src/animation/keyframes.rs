@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::fmt;
 
 /// 关键帧定义
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -12,8 +13,51 @@ pub struct Keyframes {
     pub name: String,
     /// 关键帧步骤（百分比 -> CSS 属性）
     pub steps: BTreeMap<u8, KeyframeStep>,
+    /// 步骤的声明顺序（百分比），用于校验声明是否单调递增
+    #[serde(default)]
+    pub declared_order: Vec<u8>,
 }
 
+/// 关键帧校验错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyframeError {
+    /// 关键帧名称为空
+    EmptyName,
+    /// 没有任何步骤
+    NoSteps,
+    /// 百分比超出 0-100 范围
+    OutOfRange(u8),
+    /// 步骤没有按百分比递增的顺序声明
+    Unordered {
+        /// 前一个声明的百分比
+        previous: u8,
+        /// 当前声明的百分比
+        current: u8,
+    },
+    /// 既没有 0% 也没有 100% 步骤
+    MissingEndpoints,
+}
+
+impl fmt::Display for KeyframeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyframeError::EmptyName => write!(f, "关键帧名称不能为空"),
+            KeyframeError::NoSteps => write!(f, "关键帧必须包含至少一个步骤"),
+            KeyframeError::OutOfRange(percentage) => {
+                write!(f, "关键帧百分比 {}% 超出 0-100 范围", percentage)
+            }
+            KeyframeError::Unordered { previous, current } => write!(
+                f,
+                "关键帧步骤必须按百分比递增声明：{}% 出现在 {}% 之后",
+                current, previous
+            ),
+            KeyframeError::MissingEndpoints => write!(f, "关键帧应该包含 0% 或 100% 的步骤"),
+        }
+    }
+}
+
+impl std::error::Error for KeyframeError {}
+
 /// 关键帧步骤
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct KeyframeStep {
@@ -50,6 +94,7 @@ impl Keyframes {
         Self {
             name: name.into(),
             steps: BTreeMap::new(),
+            declared_order: Vec::new(),
         }
     }
 
@@ -90,6 +135,8 @@ impl Keyframes {
     ///         .add_step(100, end_step);
     /// ```
     pub fn add_step(&mut self, percentage: u8, step: KeyframeStep) -> &mut Self {
+        // 超出范围的百分比不会生成步骤，但会保留在声明顺序中供 validate 报告
+        self.declared_order.push(percentage);
         if percentage <= 100 {
             self.steps.insert(percentage, step);
         }
@@ -221,11 +268,11 @@ impl Keyframes {
     /// 验证关键帧是否有效
     ///
     /// 检查关键帧定义是否符合基本要求，如名称不为空、至少有一个步骤、
-    /// 包含0%或100%的关键步骤等。
+    /// 百分比在 0-100 范围内且按递增顺序声明、包含0%或100%的关键步骤等。
     ///
     /// # 返回值
     ///
-    /// 如果关键帧有效，则返回`Ok(())`；否则返回包含错误信息的`Err(String)`。
+    /// 如果关键帧有效，则返回`Ok(())`；否则返回对应的`KeyframeError`。
     ///
     /// # 示例
     ///
@@ -251,22 +298,53 @@ impl Keyframes {
     ///     Err(e) => println!("关键帧无效: {}", e),
     /// }
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), KeyframeError> {
         if self.name.is_empty() {
-            return Err("关键帧名称不能为空".to_string());
+            return Err(KeyframeError::EmptyName);
+        }
+
+        // 检查百分比范围（包括直接构造的步骤）
+        if let Some(&percentage) = self
+            .declared_order
+            .iter()
+            .chain(self.steps.keys())
+            .find(|&&percentage| percentage > 100)
+        {
+            return Err(KeyframeError::OutOfRange(percentage));
         }
 
         if self.steps.is_empty() {
-            return Err("关键帧必须包含至少一个步骤".to_string());
+            return Err(KeyframeError::NoSteps);
+        }
+
+        // 检查声明顺序是否严格递增
+        for pair in self.declared_order.windows(2) {
+            if pair[1] <= pair[0] {
+                return Err(KeyframeError::Unordered {
+                    previous: pair[0],
+                    current: pair[1],
+                });
+            }
         }
 
         // 检查是否有 0% 或 100% 的关键帧
-        if !self.steps.contains_key(&0) && !self.steps.contains_key(&100) {
-            return Err("关键帧应该包含 0% 或 100% 的步骤".to_string());
+        if self.missing_endpoints().len() == 2 {
+            return Err(KeyframeError::MissingEndpoints);
         }
 
         Ok(())
     }
+
+    /// 获取缺失的端点步骤
+    ///
+    /// 返回缺少的 `0` 和/或 `100`。只缺少其中一个端点时 `validate` 仍然通过，
+    /// 调用方可以据此给出警告。
+    pub fn missing_endpoints(&self) -> Vec<u8> {
+        [0, 100]
+            .into_iter()
+            .filter(|percentage| !self.steps.contains_key(percentage))
+            .collect()
+    }
 }
 
 impl KeyframeStep {
@@ -526,9 +604,46 @@ mod tests {
         assert!(keyframes.validate().is_err());
 
         keyframes.add_simple_step(50, BTreeMap::new());
-        assert!(keyframes.validate().is_err());
+        assert_eq!(keyframes.validate(), Err(KeyframeError::MissingEndpoints));
 
+        let mut keyframes = Keyframes::new("test");
         keyframes.add_simple_step(0, BTreeMap::new());
+        keyframes.add_simple_step(50, BTreeMap::new());
         assert!(keyframes.validate().is_ok());
+        assert_eq!(keyframes.missing_endpoints(), vec![100]);
+    }
+
+    #[test]
+    fn test_out_of_range_percentage() {
+        let mut keyframes = Keyframes::new("test");
+        keyframes.add_simple_step(0, BTreeMap::new());
+        keyframes.add_simple_step(150, BTreeMap::new());
+        assert_eq!(keyframes.validate(), Err(KeyframeError::OutOfRange(150)));
+
+        // 直接构造的步骤同样会被检查
+        let mut keyframes = Keyframes::new("test");
+        keyframes.steps.insert(0, KeyframeStep::new());
+        keyframes.steps.insert(120, KeyframeStep::new());
+        assert_eq!(keyframes.validate(), Err(KeyframeError::OutOfRange(120)));
+    }
+
+    #[test]
+    fn test_unordered_sequence() {
+        let keyframes = KeyframesBuilder::new("test")
+            .step(0)
+            .property("opacity", "0")
+            .step(100)
+            .property("opacity", "1")
+            .step(50)
+            .property("opacity", "0.5")
+            .build();
+
+        assert_eq!(
+            keyframes.validate(),
+            Err(KeyframeError::Unordered {
+                previous: 100,
+                current: 50
+            })
+        );
     }
 }
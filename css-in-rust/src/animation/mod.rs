@@ -7,12 +7,14 @@ pub mod animation_engine;
 pub mod animation_presets;
 pub mod easing;
 pub mod keyframes;
+pub mod play_state;
 
 // 重新导出主要类型
 pub use animation_engine::*;
 pub use animation_presets::*;
 pub use easing::*;
 pub use keyframes::*;
+pub use play_state::*;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -73,6 +75,53 @@ pub enum AnimationPlayState {
     Paused,
 }
 
+impl AnimationPlayState {
+    /// 获取对应的 CSS `animation-play-state` 值
+    pub fn as_css(&self) -> &'static str {
+        match self {
+            AnimationPlayState::Running => "running",
+            AnimationPlayState::Paused => "paused",
+        }
+    }
+}
+
+/// 解析 CSS 时间值
+///
+/// 支持 `ms` 和 `s` 单位（秒可以是小数，如 `0.3s`），单位不区分大小写。
+/// 无单位、负数或非数字的值返回错误。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::animation::parse_css_time;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_css_time("300ms"), Ok(Duration::from_millis(300)));
+/// assert_eq!(parse_css_time("0.3s"), Ok(Duration::from_millis(300)));
+/// assert!(parse_css_time("300").is_err());
+/// ```
+pub fn parse_css_time(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    let (number, scale) = if let Some(number) = lower.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = lower.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        return Err(format!("Missing time unit (ms or s): '{}'", value));
+    };
+
+    let amount: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid time value: '{}'", value))?;
+    if !amount.is_finite() || amount < 0.0 {
+        return Err(format!("Time value must be non-negative: '{}'", value));
+    }
+
+    Duration::try_from_secs_f64(amount * scale)
+        .map_err(|_| format!("Time value out of range: '{}'", value))
+}
+
 /// 动画管理器
 ///
 /// 用于管理和组织应用程序中的动画，提供统一的接口访问自定义动画和预设动画。
@@ -180,6 +229,39 @@ impl AnimationManager {
         self.animations.get(name)
     }
 
+    /// 注销动画
+    ///
+    /// 从管理器中移除已注册的动画配置，预设动画不受影响。
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 要注销的动画名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回被移除的动画配置；动画未注册时返回`None`。
+    pub fn unregister_animation(&mut self, name: &str) -> Option<AnimationConfig> {
+        self.animations.remove(name)
+    }
+
+    /// 列出已注册的动画名称
+    ///
+    /// 按名称排序，不包含预设动画。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::animation::AnimationManager;
+    ///
+    /// let manager = AnimationManager::new();
+    /// assert!(manager.list_animations().is_empty());
+    /// ```
+    pub fn list_animations(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.animations.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
     /// 生成动画 CSS
     ///
     /// 根据动画名称生成对应的CSS代码。
@@ -212,6 +294,101 @@ impl AnimationManager {
             .map(|config| self.engine.generate_css(config))
     }
 
+    /// 组合顺序播放的动画
+    ///
+    /// 依次播放多个动画：每个动画的延迟在自身延迟基础上，累加前面所有动画的延迟和播放时长。
+    /// 动画名称优先匹配已注册的动画，其次匹配预设动画。
+    ///
+    /// # 参数
+    ///
+    /// * `names` - 按播放顺序排列的动画名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回多值 `animation` 属性声明；名称列表为空或存在未知动画时返回`None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::animation::AnimationManager;
+    ///
+    /// let manager = AnimationManager::new();
+    ///
+    /// // 先淡入，再上滑
+    /// let css = manager.compose_sequence(&["fade-in", "slide-up"]).unwrap();
+    /// assert!(css.starts_with("animation: fade-in "));
+    /// ```
+    pub fn compose_sequence(&self, names: &[&str]) -> Option<String> {
+        let configs = self.resolve_animations(names)?;
+
+        let mut offset = Duration::ZERO;
+        let values: Vec<String> = configs
+            .iter()
+            .map(|config| {
+                let delay = offset + config.delay;
+                // 无限循环的动画按单次播放时长计算后续动画的偏移
+                let iterations = match config.iteration_count {
+                    AnimationIterationCount::Count(n) => n.max(1),
+                    AnimationIterationCount::Infinite => 1,
+                };
+                offset = delay + config.duration * iterations;
+                animation_shorthand(config, delay)
+            })
+            .collect();
+
+        Some(format!("animation: {};", values.join(", ")))
+    }
+
+    /// 组合同时播放的动画
+    ///
+    /// 将多个动画合并为一个以逗号分隔的 `animation` 属性，各动画保留自身的延迟。
+    ///
+    /// # 参数
+    ///
+    /// * `names` - 要同时播放的动画名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回多值 `animation` 属性声明；名称列表为空或存在未知动画时返回`None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::animation::AnimationManager;
+    ///
+    /// let manager = AnimationManager::new();
+    ///
+    /// // 同时淡入和缩放
+    /// let css = manager.compose_parallel(&["fade-in", "zoom-in"]).unwrap();
+    /// assert!(css.contains(", zoom-in "));
+    /// ```
+    pub fn compose_parallel(&self, names: &[&str]) -> Option<String> {
+        let configs = self.resolve_animations(names)?;
+
+        let values: Vec<String> = configs
+            .iter()
+            .map(|config| animation_shorthand(config, config.delay))
+            .collect();
+
+        Some(format!("animation: {};", values.join(", ")))
+    }
+
+    /// 按名称查找动画配置，已注册的动画优先于预设动画
+    fn resolve_animations(&self, names: &[&str]) -> Option<Vec<AnimationConfig>> {
+        if names.is_empty() {
+            return None;
+        }
+
+        names
+            .iter()
+            .map(|name| {
+                self.get_animation(name)
+                    .cloned()
+                    .or_else(|| self.get_preset(name))
+            })
+            .collect()
+    }
+
     /// 获取预设动画
     ///
     /// 获取预定义的动画预设配置。
@@ -251,3 +428,124 @@ impl Default for AnimationManager {
         Self::new()
     }
 }
+
+/// 生成单个动画的 `animation` 简写值
+fn animation_shorthand(config: &AnimationConfig, delay: Duration) -> String {
+    let iteration_count = match &config.iteration_count {
+        AnimationIterationCount::Count(n) => n.to_string(),
+        AnimationIterationCount::Infinite => "infinite".to_string(),
+    };
+    let direction = match config.direction {
+        AnimationDirection::Normal => "normal",
+        AnimationDirection::Reverse => "reverse",
+        AnimationDirection::Alternate => "alternate",
+        AnimationDirection::AlternateReverse => "alternate-reverse",
+    };
+    let fill_mode = match config.fill_mode {
+        AnimationFillMode::None => "none",
+        AnimationFillMode::Forwards => "forwards",
+        AnimationFillMode::Backwards => "backwards",
+        AnimationFillMode::Both => "both",
+    };
+    let play_state = config.play_state.as_css();
+
+    format!(
+        "{} {}ms {} {}ms {} {} {} {}",
+        config.name,
+        config.duration.as_millis(),
+        config.easing.to_css(),
+        delay.as_millis(),
+        iteration_count,
+        direction,
+        fill_mode,
+        play_state
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animation(name: &str, duration_ms: u64, delay_ms: u64) -> AnimationConfig {
+        AnimationConfig {
+            name: name.to_string(),
+            duration: Duration::from_millis(duration_ms),
+            easing: EasingFunction::Css("linear".to_string()),
+            delay: Duration::from_millis(delay_ms),
+            iteration_count: AnimationIterationCount::Count(1),
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::Both,
+            play_state: AnimationPlayState::Running,
+        }
+    }
+
+    #[test]
+    fn test_compose_sequence_offsets_delays() {
+        let mut manager = AnimationManager::new();
+        manager.register_animation(animation("fade", 300, 0));
+        manager.register_animation(animation("slide", 200, 50));
+
+        let css = manager.compose_sequence(&["fade", "slide"]).unwrap();
+        assert_eq!(
+            css,
+            "animation: fade 300ms linear 0ms 1 normal both running, \
+             slide 200ms linear 350ms 1 normal both running;"
+        );
+    }
+
+    #[test]
+    fn test_compose_parallel_joins_animations() {
+        let mut manager = AnimationManager::new();
+        manager.register_animation(animation("fade", 300, 0));
+        manager.register_animation(animation("scale", 200, 50));
+
+        let css = manager.compose_parallel(&["fade", "scale"]).unwrap();
+        assert_eq!(
+            css,
+            "animation: fade 300ms linear 0ms 1 normal both running, \
+             scale 200ms linear 50ms 1 normal both running;"
+        );
+    }
+
+    #[test]
+    fn test_unregister_and_list_animations() {
+        let mut manager = AnimationManager::new();
+        manager.register_animation(animation("slide", 200, 0));
+        manager.register_animation(animation("fade", 300, 0));
+        assert_eq!(manager.list_animations(), vec!["fade", "slide"]);
+
+        let removed = manager.unregister_animation("fade").unwrap();
+        assert_eq!(removed.duration, Duration::from_millis(300));
+        assert_eq!(manager.list_animations(), vec!["slide"]);
+        assert!(manager.get_animation("fade").is_none());
+        assert!(manager.unregister_animation("fade").is_none());
+    }
+
+    #[test]
+    fn test_parse_css_time() {
+        assert_eq!(parse_css_time("300ms"), Ok(Duration::from_millis(300)));
+        assert_eq!(parse_css_time("0.3s"), Ok(Duration::from_millis(300)));
+        assert_eq!(parse_css_time(" 1.5S "), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_css_time("0ms"), Ok(Duration::ZERO));
+
+        assert!(parse_css_time("300").is_err());
+        assert!(parse_css_time("-1s").is_err());
+        assert!(parse_css_time("ms").is_err());
+        assert!(parse_css_time("fast").is_err());
+    }
+
+    #[test]
+    fn test_parse_css_time_out_of_range() {
+        assert!(parse_css_time("1e30s").is_err());
+        assert!(parse_css_time("1e300ms").is_err());
+        assert!(parse_css_time("NaNs").is_err());
+        assert!(parse_css_time("infs").is_err());
+    }
+
+    #[test]
+    fn test_compose_unknown_animation() {
+        let manager = AnimationManager::new();
+        assert!(manager.compose_sequence(&["fade-in", "missing"]).is_none());
+        assert!(manager.compose_parallel(&[]).is_none());
+    }
+}
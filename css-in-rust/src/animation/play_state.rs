@@ -0,0 +1,140 @@
+//! # 动画播放状态模块
+//!
+//! 提供在运行时暂停或恢复动画的辅助函数，可以直接修改 DOM 元素的内联样式，
+//! 也可以通过样式注入器为类名生成播放状态修饰类。
+
+use super::AnimationPlayState;
+use crate::runtime::{InjectionError, StyleInjector};
+
+/// 可设置内联样式的目标
+#[cfg(any(target_arch = "wasm32", test))]
+trait InlineStyleTarget {
+    /// 设置内联样式属性
+    fn set_style_property(&self, property: &str, value: &str) -> Result<(), String>;
+}
+
+#[cfg(target_arch = "wasm32")]
+impl InlineStyleTarget for web_sys::Element {
+    fn set_style_property(&self, property: &str, value: &str) -> Result<(), String> {
+        use wasm_bindgen::JsCast;
+
+        self.dyn_ref::<web_sys::HtmlElement>()
+            .ok_or_else(|| "元素不支持内联样式".to_string())?
+            .style()
+            .set_property(property, value)
+            .map_err(|e| format!("设置内联样式失败: {:?}", e))
+    }
+}
+
+/// 将播放状态写入目标的 `animation-play-state` 内联样式
+#[cfg(any(target_arch = "wasm32", test))]
+fn apply_play_state<T: InlineStyleTarget + ?Sized>(
+    target: &T,
+    state: &AnimationPlayState,
+) -> Result<(), String> {
+    target.set_style_property("animation-play-state", state.as_css())
+}
+
+/// 设置元素的动画播放状态
+///
+/// 直接修改元素的 `animation-play-state` 内联样式，适合在滚动等事件中暂停或恢复动画。
+///
+/// # 参数
+///
+/// * `element` - 目标 DOM 元素，必须是 `HtmlElement`
+/// * `state` - 新的播放状态
+#[cfg(target_arch = "wasm32")]
+pub fn set_play_state(element: &web_sys::Element, state: AnimationPlayState) -> Result<(), String> {
+    apply_play_state(element, &state)
+}
+
+/// 获取类名对应的播放状态修饰类名
+pub fn play_state_class_name(class_name: &str) -> String {
+    format!("{}-play-state", class_name)
+}
+
+/// 通过样式注入器设置类名的动画播放状态
+///
+/// 以修饰类名注入 `animation-play-state` 声明（生成 `.{class_name}-play-state { ... }` 规则）并返回修饰类名，
+/// 将其追加到元素的类列表即可切换动画状态。再次调用会覆盖之前注入的状态。
+///
+/// # 参数
+///
+/// * `injector` - 样式注入器
+/// * `class_name` - 动画所在的类名
+/// * `state` - 新的播放状态
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::animation::{set_class_play_state, AnimationPlayState};
+/// use css_in_rust::runtime::StyleInjector;
+///
+/// let injector = StyleInjector::new_ssr();
+/// let modifier = set_class_play_state(&injector, "spinner", AnimationPlayState::Paused).unwrap();
+/// assert_eq!(modifier, "spinner-play-state");
+/// ```
+pub fn set_class_play_state(
+    injector: &StyleInjector,
+    class_name: &str,
+    state: AnimationPlayState,
+) -> Result<String, InjectionError> {
+    let modifier = play_state_class_name(class_name);
+    // 注入器会用类名包裹声明，这里只传入声明
+    let css = format!("animation-play-state: {};", state.as_css());
+    injector.inject_style(&css, &modifier)?;
+    Ok(modifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MockElement {
+        style: RefCell<HashMap<String, String>>,
+    }
+
+    impl InlineStyleTarget for MockElement {
+        fn set_style_property(&self, property: &str, value: &str) -> Result<(), String> {
+            self.style
+                .borrow_mut()
+                .insert(property.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_play_state_updates_inline_style() {
+        let element = MockElement::default();
+
+        apply_play_state(&element, &AnimationPlayState::Paused).unwrap();
+        assert_eq!(
+            element.style.borrow().get("animation-play-state"),
+            Some(&"paused".to_string())
+        );
+
+        apply_play_state(&element, &AnimationPlayState::Running).unwrap();
+        assert_eq!(
+            element.style.borrow().get("animation-play-state"),
+            Some(&"running".to_string())
+        );
+    }
+
+    #[test]
+    fn test_class_play_state_injects_modifier_rule() {
+        let injector = StyleInjector::new_ssr();
+        let modifier =
+            set_class_play_state(&injector, "play-state-spinner", AnimationPlayState::Paused)
+                .unwrap();
+        assert_eq!(modifier, "play-state-spinner-play-state");
+
+        let html = injector.generate_style_html().unwrap();
+        assert!(
+            html.contains("\n.play-state-spinner-play-state { animation-play-state: paused; }\n")
+        );
+        assert!(!html.contains(".play-state-spinner-play-state { .play-state-spinner-play-state"));
+    }
+}
@@ -35,6 +35,9 @@ pub struct CssMacroCall {
     pub css_content: String,
     /// Generated CSS ID
     pub css_id: String,
+    /// Const name given via `css!(name = "...", ...)`
+    #[serde(default)]
+    pub name: Option<String>,
     /// Extracted selectors
     pub selectors: CssSelectors,
 }
@@ -258,7 +261,7 @@ impl StaticAnalyzer {
                     let css_end = start + paren_start + paren_end;
 
                     if css_start < css_end && css_end <= line.len() {
-                        let css_content = line[css_start..css_end].trim();
+                        let (name, css_content) = split_macro_name(line[css_start..css_end].trim());
 
                         // Remove quotes
                         let css_content =
@@ -276,6 +279,7 @@ impl StaticAnalyzer {
                             line_number: line_num,
                             css_content: css_content.to_string(),
                             css_id,
+                            name,
                             selectors,
                         });
                     }
@@ -403,6 +407,29 @@ impl StaticAnalyzer {
     }
 }
 
+/// Split the optional `name = "...",` prefix from css! macro arguments
+fn split_macro_name(arguments: &str) -> (Option<String>, &str) {
+    let Some(rest) = arguments.strip_prefix("name") else {
+        return (None, arguments);
+    };
+    let Some(rest) = rest.trim_start().strip_prefix('=') else {
+        return (None, arguments);
+    };
+    let Some(rest) = rest.trim_start().strip_prefix('"') else {
+        return (None, arguments);
+    };
+    let Some(name_end) = rest.find('"') else {
+        return (None, arguments);
+    };
+
+    let name = rest[..name_end].to_string();
+    let css = rest[name_end + 1..]
+        .trim_start()
+        .trim_start_matches(',')
+        .trim();
+    (Some(name), css)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -430,6 +457,19 @@ mod tests {
         assert!(css_call.selectors.classes.contains(&"btn".to_string()));
     }
 
+    #[test]
+    fn test_named_css_macro_extraction() {
+        let analyzer = StaticAnalyzer::new(PathBuf::from("."));
+        let line = r#"css!(name = "primary-btn", "color: red;");"#;
+
+        let css_call = analyzer
+            .extract_css_macro_call(&PathBuf::from("test.rs"), 1, line)
+            .unwrap();
+
+        assert_eq!(css_call.name.as_deref(), Some("primary-btn"));
+        assert_eq!(css_call.css_content, "color: red;");
+    }
+
     #[test]
     fn test_selector_extraction() {
         let analyzer = StaticAnalyzer::new(PathBuf::from("."));
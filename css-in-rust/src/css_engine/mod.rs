@@ -6,9 +6,13 @@
 
 pub mod optimizer;
 pub mod parser;
+pub mod selector;
+pub mod value;
 
 pub use optimizer::{CssOptimizer, OptimizationError, OptimizerConfig};
 pub use parser::{CssParser, ParseError, ParserConfig};
+pub use selector::specificity;
+pub use value::{parse_value, CssValueToken, LengthUnit};
 
 /// Result type for CSS operations
 ///
@@ -21,7 +25,11 @@ pub use parser::{CssParser, ParseError, ParserConfig};
 ///
 /// fn process_css(input: &str) -> Result<String> {
 ///     if input.is_empty() {
-///         return Err(CssError::ParseError("Empty CSS input".to_string()));
+///         return Err(CssError::ParseError {
+///             message: "Empty CSS input".to_string(),
+///             line: 1,
+///             column: 1,
+///         });
 ///     }
 ///     Ok(input.to_string())
 /// }
@@ -46,7 +54,11 @@ pub type Result<T> = std::result::Result<T, CssError>;
 ///
 ///     // 检查CSS内容有效性
 ///     if css.contains("invalid") {
-///         return Err(CssError::ParseError("CSS内容无效".to_string()));
+///         return Err(CssError::ParseError {
+///             message: "CSS内容无效".to_string(),
+///             line: 0,
+///             column: 0,
+///         });
 ///     }
 ///
 ///     // 处理主题变量
@@ -61,7 +73,16 @@ pub type Result<T> = std::result::Result<T, CssError>;
 #[derive(Debug)]
 pub enum CssError {
     /// CSS解析过程中发生的错误
-    ParseError(String),
+    ///
+    /// `line` 和 `column` 从 1 开始；位置未知时均为 0
+    ParseError {
+        /// 错误信息
+        message: String,
+        /// 出错的行号
+        line: usize,
+        /// 出错的列号
+        column: usize,
+    },
     /// 样式注入过程中发生的错误
     InjectionError(String),
     /// 主题处理相关的错误
@@ -81,11 +102,17 @@ impl std::fmt::Display for CssError {
     /// use css_in_rust::css_engine::CssError;
     ///
     /// // 创建一个解析错误
-    /// let error = CssError::ParseError("选择器无效".to_string());
+    /// let error = CssError::ParseError {
+    ///     message: "选择器无效".to_string(),
+    ///     line: 3,
+    ///     column: 5,
+    /// };
     ///
     /// // 打印错误信息
-    /// println!("错误: {}", error);
-    /// // 输出: "错误: CSS parsing failed: 选择器无效"
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "CSS parsing failed at line 3, column 5: 选择器无效"
+    /// );
     ///
     /// // 创建一个主题错误
     /// let theme_error = CssError::ThemeError("找不到主题变量".to_string());
@@ -94,7 +121,18 @@ impl std::fmt::Display for CssError {
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CssError::ParseError(msg) => write!(f, "CSS parsing failed: {}", msg),
+            CssError::ParseError {
+                message, line: 0, ..
+            } => write!(f, "CSS parsing failed: {}", message),
+            CssError::ParseError {
+                message,
+                line,
+                column,
+            } => write!(
+                f,
+                "CSS parsing failed at line {}, column {}: {}",
+                line, column, message
+            ),
             CssError::InjectionError(msg) => write!(f, "Style injection failed: {}", msg),
             CssError::ThemeError(msg) => write!(f, "Theme error: {}", msg),
             CssError::IoError(err) => write!(f, "IO error: {}", err),
@@ -125,7 +163,11 @@ impl std::error::Error for CssError {
     /// }
     ///
     /// // 对于非IO错误，source返回None
-    /// let parse_error = CssError::ParseError("语法错误".to_string());
+    /// let parse_error = CssError::ParseError {
+    ///     message: "语法错误".to_string(),
+    ///     line: 1,
+    ///     column: 1,
+    /// };
     /// assert!(parse_error.source().is_none());
     /// ```
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -174,3 +216,35 @@ impl From<std::io::Error> for CssError {
         CssError::IoError(err)
     }
 }
+
+impl From<ParseError> for CssError {
+    /// 将解析器错误转换为`CssError`，保留错误位置
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::css_engine::{CssError, ParseError};
+    ///
+    /// let parse_error = ParseError::SyntaxError {
+    ///     message: "Unclosed block, expected `}`".to_string(),
+    ///     line: 2,
+    ///     column: 7,
+    /// };
+    ///
+    /// let css_error: CssError = parse_error.into();
+    /// assert!(matches!(css_error, CssError::ParseError { line: 2, column: 7, .. }));
+    /// ```
+    fn from(err: ParseError) -> Self {
+        let (line, column) = err.location().unwrap_or((0, 0));
+        let message = match err {
+            ParseError::SyntaxError { message, .. } => message,
+            other => other.to_string(),
+        };
+
+        CssError::ParseError {
+            message,
+            line,
+            column,
+        }
+    }
+}
@@ -94,6 +94,44 @@ pub enum ParseError {
     InvalidInput(String),
     /// Error during CSS processing
     ProcessingError(String),
+    /// Syntax error at a known position (1-based line and column)
+    SyntaxError {
+        /// Description of the problem
+        message: String,
+        /// Line of the offending token, starting from 1
+        line: usize,
+        /// Column of the offending token, starting from 1
+        column: usize,
+    },
+}
+
+impl ParseError {
+    /// Returns the 1-based `(line, column)` of the error, if known
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::css_engine::parser::ParseError;
+    ///
+    /// let error = ParseError::SyntaxError {
+    ///     message: "Unclosed block".to_string(),
+    ///     line: 2,
+    ///     column: 5,
+    /// };
+    /// assert_eq!(error.location(), Some((2, 5)));
+    /// assert_eq!(ParseError::InvalidInput("bad".to_string()).location(), None);
+    /// ```
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            #[cfg(feature = "optimizer")]
+            ParseError::LightningCssError(err) => err
+                .loc
+                .as_ref()
+                .map(|loc| (loc.line as usize + 1, loc.column as usize)),
+            ParseError::SyntaxError { line, column, .. } => Some((*line, *column)),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ParseError {
@@ -113,6 +151,15 @@ impl std::fmt::Display for ParseError {
             ParseError::LightningCssError(err) => write!(f, "LightningCSS error: {:?}", err),
             ParseError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             ParseError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
+            ParseError::SyntaxError {
+                message,
+                line,
+                column,
+            } => write!(
+                f,
+                "Syntax error at line {}, column {}: {}",
+                line, column, message
+            ),
         }
     }
 }
@@ -337,6 +384,9 @@ impl CssParser {
     ///     Err(err) => match err {
     ///         ParseError::InvalidInput(msg) => println!("无效输入: {}", msg),
     ///         ParseError::ProcessingError(msg) => println!("处理错误: {}", msg),
+    ///         ParseError::SyntaxError { message, line, column } => {
+    ///             println!("语法错误 ({}:{}): {}", line, column, message)
+    ///         }
     ///         #[cfg(feature = "optimizer")]
     ///         ParseError::LightningCssError(err) => println!("LightningCSS错误: {:?}", err),
     ///     }
@@ -426,9 +476,7 @@ impl CssParser {
         }
 
         // Basic validation
-        if let Err(msg) = self.validate_basic_css_syntax(css) {
-            return Err(ParseError::InvalidInput(msg));
-        }
+        self.validate_basic_css_syntax(css)?;
 
         // Simple metadata extraction
         let metadata = StyleSheetMetadata {
@@ -473,6 +521,44 @@ impl CssParser {
         })
     }
 
+    /// Parse CSS, recovering from errors and collecting all of them
+    ///
+    /// Unlike [`CssParser::parse`], which stops at the first error, this scans
+    /// the whole input and recovers at rule boundaries: a top-level rule that
+    /// contains an error is dropped and scanning continues with the next one.
+    /// Every error is reported as a [`ParseError::SyntaxError`] with its line
+    /// and column, which makes it suitable for linters and editor diagnostics.
+    ///
+    /// The returned stylesheet is built from the rules without errors; it is
+    /// `None` only if those remaining rules still fail to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::css_engine::parser::CssParser;
+    ///
+    /// let parser = CssParser::new();
+    /// let css = ".a { color red; }\n.b { color: blue; }\n.c { margin: 0; } }";
+    ///
+    /// let (stylesheet, errors) = parser.parse_all(css);
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].location(), Some((1, 6)));
+    /// assert_eq!(errors[1].location(), Some((3, 19)));
+    /// assert!(stylesheet.unwrap().source.contains(".b { color: blue; }"));
+    /// ```
+    pub fn parse_all(&self, css: &str) -> (Option<StyleSheet>, Vec<ParseError>) {
+        let (recovered, mut errors) = recover_rules(css);
+
+        match self.parse(&recovered) {
+            Ok(stylesheet) => (Some(stylesheet), errors),
+            Err(err) => {
+                errors.push(err);
+                (None, errors)
+            }
+        }
+    }
+
     /// Basic CSS syntax validation for fallback implementation
     ///
     /// Performs simple syntax validation when the optimizer feature is not enabled.
@@ -483,7 +569,8 @@ impl CssParser {
     ///
     /// # Returns
     ///
-    /// A `Result` containing either `()` if valid or an error message string
+    /// A `Result` containing either `()` if valid or a `ParseError::SyntaxError`
+    /// pointing at the offending brace or quote
     ///
     /// # Examples
     ///
@@ -502,13 +589,29 @@ impl CssParser {
     /// # }
     /// ```
     #[cfg(not(feature = "optimizer"))]
-    fn validate_basic_css_syntax(&self, css: &str) -> Result<(), String> {
-        let mut brace_count = 0;
-        let mut in_string = false;
+    fn validate_basic_css_syntax(&self, css: &str) -> Result<(), ParseError> {
+        let syntax_error =
+            |message: &str, (line, column): (usize, usize)| ParseError::SyntaxError {
+                message: message.to_string(),
+                line,
+                column,
+            };
+
+        // 记录每个未闭合的 `{` 和当前字符串起始位置，用于报告错误位置
+        let mut open_braces = Vec::new();
+        let mut string_start = None;
         let mut escape_next = false;
         let mut string_char = '\0';
+        let (mut line, mut column) = (1, 0);
 
         for ch in css.chars() {
+            if ch == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+
             if escape_next {
                 escape_next = false;
                 continue;
@@ -519,35 +622,34 @@ impl CssParser {
                 continue;
             }
 
-            if in_string {
+            if string_start.is_some() {
                 if ch == string_char {
-                    in_string = false;
+                    string_start = None;
                 }
                 continue;
             }
 
             match ch {
                 '"' | '\'' => {
-                    in_string = true;
+                    string_start = Some((line, column));
                     string_char = ch;
                 }
-                '{' => brace_count += 1,
+                '{' => open_braces.push((line, column)),
                 '}' => {
-                    brace_count -= 1;
-                    if brace_count < 0 {
-                        return Err("Unexpected closing brace".to_string());
-                    }
+                    let Some(_) = open_braces.pop() else {
+                        return Err(syntax_error("Unexpected closing brace", (line, column)));
+                    };
                 }
                 _ => {}
             }
         }
 
-        if brace_count != 0 {
-            return Err("Mismatched braces".to_string());
+        if let Some(position) = string_start {
+            return Err(syntax_error("Unterminated string", position));
         }
 
-        if in_string {
-            return Err("Unterminated string".to_string());
+        if let Some(&position) = open_braces.last() {
+            return Err(syntax_error("Unclosed block, expected `}`", position));
         }
 
         Ok(())
@@ -595,6 +697,156 @@ impl CssParser {
     }
 }
 
+/// Scans CSS for syntax errors, keeping only the top-level rules without errors
+///
+/// Returns the CSS made of the error-free rules and every error found.
+fn recover_rules(css: &str) -> (String, Vec<ParseError>) {
+    let mut errors = Vec::new();
+    let mut recovered = String::with_capacity(css.len());
+    let syntax_error = |message: &str, (line, column): (usize, usize)| ParseError::SyntaxError {
+        message: message.to_string(),
+        line,
+        column,
+    };
+
+    let mut open_braces: Vec<(usize, usize)> = Vec::new();
+    let mut rule_start = 0;
+    let mut rule_has_error = false;
+    // 当前声明的内容及起始位置
+    let mut declaration = String::new();
+    let mut declaration_start = (1, 1);
+    let mut string_char: Option<(char, (usize, usize))> = None;
+    let mut escape_next = false;
+    // 括号（如 `url(data:...;base64,...)`）内的 `;` 不结束声明
+    let mut paren_depth = 0usize;
+    let (mut line, mut column) = (1, 0);
+    let mut chars = css.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        if ch == '\n' {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+
+        if escape_next {
+            escape_next = false;
+            declaration.push(ch);
+            continue;
+        }
+
+        if let Some((quote, start)) = string_char {
+            match ch {
+                '\\' => escape_next = true,
+                '\n' => {
+                    // 未闭合的字符串在行尾结束
+                    errors.push(syntax_error("Unterminated string", start));
+                    rule_has_error = true;
+                    string_char = None;
+                }
+                _ if ch == quote => string_char = None,
+                _ => {}
+            }
+            declaration.push(ch);
+            continue;
+        }
+
+        // 跳过注释
+        if ch == '/' && chars.peek().map(|(_, next)| *next) == Some('*') {
+            let mut previous = '\0';
+            for (_, comment_char) in chars.by_ref() {
+                if comment_char == '\n' {
+                    line += 1;
+                    column = 0;
+                } else {
+                    column += 1;
+                }
+                if previous == '*' && comment_char == '/' {
+                    break;
+                }
+                previous = comment_char;
+            }
+            continue;
+        }
+
+        match ch {
+            '\\' => {
+                escape_next = true;
+                declaration.push(ch);
+            }
+            '"' | '\'' => {
+                string_char = Some((ch, (line, column)));
+                declaration.push(ch);
+            }
+            '(' => {
+                paren_depth += 1;
+                declaration.push(ch);
+            }
+            ')' => {
+                paren_depth = paren_depth.saturating_sub(1);
+                declaration.push(ch);
+            }
+            ';' if paren_depth > 0 => declaration.push(ch),
+            '{' => {
+                open_braces.push((line, column));
+                declaration.clear();
+                paren_depth = 0;
+            }
+            ';' | '}' => {
+                paren_depth = 0;
+                let text = declaration.trim();
+                if !open_braces.is_empty() && !text.is_empty() && !text.contains(':') {
+                    errors.push(syntax_error(
+                        "Expected `:` in declaration",
+                        declaration_start,
+                    ));
+                    rule_has_error = true;
+                }
+                declaration.clear();
+
+                if ch == '}' {
+                    if open_braces.pop().is_none() {
+                        errors.push(syntax_error("Unexpected closing brace", (line, column)));
+                        rule_start = index + 1;
+                        continue;
+                    }
+
+                    if open_braces.is_empty() {
+                        if !rule_has_error {
+                            recovered.push_str(&css[rule_start..=index]);
+                        }
+                        rule_start = index + 1;
+                        rule_has_error = false;
+                    }
+                } else if open_braces.is_empty() {
+                    // 顶层语句，如 `@import`
+                    recovered.push_str(&css[rule_start..=index]);
+                    rule_start = index + 1;
+                }
+            }
+            _ => {
+                if declaration.trim().is_empty() && !ch.is_whitespace() {
+                    declaration.clear();
+                    declaration_start = (line, column);
+                }
+                declaration.push(ch);
+            }
+        }
+    }
+
+    if let Some((_, start)) = string_char {
+        errors.push(syntax_error("Unterminated string", start));
+    }
+    if let Some(&start) = open_braces.last() {
+        errors.push(syntax_error("Unclosed block, expected `}`", start));
+    } else if !rule_has_error {
+        recovered.push_str(&css[rule_start..]);
+    }
+
+    (recovered, errors)
+}
+
 impl Default for CssParser {
     /// Creates a new CSS parser with default configuration
     ///
@@ -639,4 +891,57 @@ mod tests {
         let result = parser.parse(css);
         assert!(result.is_err());
     }
+
+    #[cfg(not(feature = "optimizer"))]
+    #[test]
+    fn test_parse_error_location() {
+        let parser = CssParser::new();
+        let css = ".button { color: red; }\n.card {\n  padding: 4px;\n";
+
+        let err = parser.parse(css).unwrap_err();
+        assert_eq!(err.location(), Some((2, 7)));
+        assert!(err.to_string().contains("line 2, column 7"));
+
+        let css_err = crate::css_engine::CssError::from(err);
+        assert!(matches!(
+            css_err,
+            crate::css_engine::CssError::ParseError {
+                line: 2,
+                column: 7,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_all_collects_multiple_errors() {
+        let parser = CssParser::new();
+        let css = ".a { color: red; }\n.b {\n  color blue;\n}\n/* ok */\n.c { margin: 0; }\n.d { padding: 4px; } }\n";
+
+        let (stylesheet, errors) = parser.parse_all(css);
+
+        let locations: Vec<_> = errors.iter().map(ParseError::location).collect();
+        assert_eq!(locations, vec![Some((3, 3)), Some((7, 22))]);
+
+        let stylesheet = stylesheet.unwrap();
+        assert!(stylesheet.source.contains(".a { color: red; }"));
+        assert!(stylesheet.source.contains(".c { margin: 0; }"));
+        assert!(stylesheet.source.contains(".d { padding: 4px; }"));
+        assert!(!stylesheet.source.contains(".b"));
+    }
+
+    #[test]
+    fn test_parse_all_accepts_data_uri_declarations() {
+        let parser = CssParser::new();
+        let css = ".icon { background: url(data:image/png;base64,iVBORw0KGgo=) no-repeat; color: red; }\n.b { mask: url(\"data:image/svg+xml;utf8,<svg/>\"); }\n";
+
+        let (stylesheet, errors) = parser.parse_all(css);
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        let stylesheet = stylesheet.unwrap();
+        assert!(stylesheet
+            .source
+            .contains("url(data:image/png;base64,iVBORw0KGgo=) no-repeat"));
+        assert!(stylesheet.source.contains(".b { mask:"));
+    }
 }
@@ -0,0 +1,237 @@
+//! CSS selector utilities
+//!
+//! This module provides helpers for inspecting CSS selectors, such as computing
+//! their specificity according to the Selectors Level 4 specification. It is
+//! intended for debugging cascade issues, e.g. finding out why a rule loses to
+//! another one.
+
+/// Calculates the specificity of a CSS selector
+///
+/// Returns the `(id, class, element)` triple where:
+/// - `id` counts ID selectors
+/// - `class` counts class selectors, attribute selectors and pseudo-classes
+/// - `element` counts type selectors and pseudo-elements
+///
+/// `:is()`, `:not()` and `:has()` take the specificity of their most specific
+/// argument, `:where()` always contributes zero, and `:nth-child(... of S)`
+/// counts as a pseudo-class plus the most specific selector in `S`. For a
+/// selector list (`a, .b`) the highest specificity in the list is returned.
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::css_engine::selector::specificity;
+///
+/// assert_eq!(specificity("#id .c a"), (1, 1, 1));
+/// assert_eq!(specificity(":where(.x) a"), (0, 0, 1));
+/// assert_eq!(specificity("button:not(.primary):hover"), (0, 2, 1));
+/// ```
+pub fn specificity(selector: &str) -> (u32, u32, u32) {
+    split_top_level(selector, ',')
+        .iter()
+        .map(|complex| complex_specificity(complex))
+        .max()
+        .unwrap_or((0, 0, 0))
+}
+
+/// Legacy pseudo-elements that may be written with a single colon
+const LEGACY_PSEUDO_ELEMENTS: &[&str] = &["before", "after", "first-line", "first-letter"];
+
+/// Calculates the specificity of a single complex selector (no top-level commas)
+fn complex_specificity(selector: &str) -> (u32, u32, u32) {
+    let chars: Vec<char> = selector.chars().collect();
+    let (mut ids, mut classes, mut elements) = (0u32, 0u32, 0u32);
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '#' => {
+                ids += 1;
+                i = skip_identifier(&chars, i + 1);
+            }
+            '.' => {
+                classes += 1;
+                i = skip_identifier(&chars, i + 1);
+            }
+            '[' => {
+                classes += 1;
+                i = skip_block(&chars, i, '[', ']');
+            }
+            ':' if chars.get(i + 1) == Some(&':') => {
+                elements += 1;
+                i = skip_identifier(&chars, i + 2);
+                if chars.get(i) == Some(&'(') {
+                    i = skip_block(&chars, i, '(', ')');
+                }
+            }
+            ':' => {
+                let name_end = skip_identifier(&chars, i + 1);
+                let name: String = chars[i + 1..name_end].iter().collect::<String>();
+                let name = name.to_ascii_lowercase();
+                i = name_end;
+
+                let arguments = if chars.get(i) == Some(&'(') {
+                    let end = skip_block(&chars, i, '(', ')');
+                    let inner_end = if chars[end - 1] == ')' { end - 1 } else { end };
+                    let inner: String = chars[i + 1..inner_end].iter().collect();
+                    i = end;
+                    Some(inner)
+                } else {
+                    None
+                };
+
+                let (a, b, c) = pseudo_class_specificity(&name, arguments.as_deref());
+                ids += a;
+                classes += b;
+                elements += c;
+            }
+            c if is_identifier_start(c) => {
+                elements += 1;
+                i = skip_identifier(&chars, i);
+            }
+            _ => {
+                // 组合符、通配符 `*` 和空白不影响特异性
+                i += 1;
+            }
+        }
+    }
+
+    (ids, classes, elements)
+}
+
+/// Calculates the specificity contributed by a pseudo-class
+fn pseudo_class_specificity(name: &str, arguments: Option<&str>) -> (u32, u32, u32) {
+    if LEGACY_PSEUDO_ELEMENTS.contains(&name) {
+        return (0, 0, 1);
+    }
+
+    match (name, arguments) {
+        ("where", _) => (0, 0, 0),
+        ("is" | "not" | "has" | "matches" | "-webkit-any" | "-moz-any", Some(args)) => {
+            specificity(args)
+        }
+        ("nth-child" | "nth-last-child", Some(args)) => {
+            let parts = split_top_level(args, ' ');
+            let of_selector = parts
+                .iter()
+                .position(|part| part.eq_ignore_ascii_case("of"))
+                .map(|index| parts[index + 1..].join(" "));
+
+            let (a, b, c) = of_selector.map(|s| specificity(&s)).unwrap_or((0, 0, 0));
+            (a, b + 1, c)
+        }
+        _ => (0, 1, 0),
+    }
+}
+
+/// Splits a selector on a delimiter, ignoring delimiters nested in brackets or quotes
+fn split_top_level(input: &str, delimiter: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+
+    for c in input.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth -= 1,
+            (None, _) if c == delimiter && depth == 0 => {
+                if !current.trim().is_empty() {
+                    parts.push(current.trim().to_string());
+                }
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    parts
+}
+
+/// Returns whether a character can start an identifier (type selector)
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '-' || c == '\\' || !c.is_ascii()
+}
+
+/// Skips an identifier starting at `start`, returning the index after it
+fn skip_identifier(chars: &[char], start: usize) -> usize {
+    let mut i = start;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            // 转义字符连同下一个字符一起跳过
+            i += 2;
+        } else if c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    i.min(chars.len())
+}
+
+/// Skips a bracketed block starting at `start`, returning the index after the closing bracket
+fn skip_block(chars: &[char], start: usize, open: char, close: char) -> usize {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut i = start;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == open => depth += 1,
+            None if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            None => {}
+        }
+        i += 1;
+    }
+
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compound_selector_specificity() {
+        assert_eq!(specificity("#id .c a"), (1, 1, 1));
+        assert_eq!(specificity("input[type=\"text\"]:focus"), (0, 2, 1));
+        assert_eq!(specificity("ul > li + li ~ *"), (0, 0, 3));
+        assert_eq!(specificity("li::before"), (0, 0, 2));
+        assert_eq!(specificity("p:first-line"), (0, 0, 2));
+    }
+
+    #[test]
+    fn test_functional_pseudo_class_specificity() {
+        assert_eq!(specificity(":where(.x) a"), (0, 0, 1));
+        assert_eq!(specificity(":where(#a, .b)"), (0, 0, 0));
+        assert_eq!(specificity(":not(#a) .b"), (1, 1, 0));
+        assert_eq!(specificity("a:is(.x, #y)"), (1, 0, 1));
+        assert_eq!(specificity("li:nth-child(2n + 1 of .item)"), (0, 2, 1));
+        assert_eq!(specificity("li:nth-child(odd)"), (0, 1, 1));
+    }
+
+    #[test]
+    fn test_selector_list_uses_highest_specificity() {
+        assert_eq!(specificity("a, #b, .c"), (1, 0, 0));
+        assert_eq!(specificity(""), (0, 0, 0));
+    }
+}
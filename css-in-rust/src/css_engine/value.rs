@@ -0,0 +1,387 @@
+//! CSS value parsing
+//!
+//! This module splits a declaration value such as `10px solid #fff` into typed
+//! tokens so that transforms (unit conversion, color manipulation) can work on
+//! structured data instead of raw strings. It is intentionally small: it does
+//! not validate values against a property grammar.
+
+/// Units recognised on dimension values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LengthUnit {
+    Px,
+    Rem,
+    Em,
+    Percent,
+    Vw,
+    Vh,
+    Vmin,
+    Vmax,
+    Ch,
+    Ex,
+    Pt,
+    Cm,
+    Mm,
+    In,
+    Deg,
+    Rad,
+    Turn,
+    S,
+    Ms,
+    Fr,
+    /// Any other unit, stored lowercase
+    Other(String),
+}
+
+impl LengthUnit {
+    /// Parses a unit suffix (case-insensitive)
+    pub fn from_css(unit: &str) -> Self {
+        match unit.to_ascii_lowercase().as_str() {
+            "px" => LengthUnit::Px,
+            "rem" => LengthUnit::Rem,
+            "em" => LengthUnit::Em,
+            "%" => LengthUnit::Percent,
+            "vw" => LengthUnit::Vw,
+            "vh" => LengthUnit::Vh,
+            "vmin" => LengthUnit::Vmin,
+            "vmax" => LengthUnit::Vmax,
+            "ch" => LengthUnit::Ch,
+            "ex" => LengthUnit::Ex,
+            "pt" => LengthUnit::Pt,
+            "cm" => LengthUnit::Cm,
+            "mm" => LengthUnit::Mm,
+            "in" => LengthUnit::In,
+            "deg" => LengthUnit::Deg,
+            "rad" => LengthUnit::Rad,
+            "turn" => LengthUnit::Turn,
+            "s" => LengthUnit::S,
+            "ms" => LengthUnit::Ms,
+            "fr" => LengthUnit::Fr,
+            other => LengthUnit::Other(other.to_string()),
+        }
+    }
+
+    /// Returns the CSS spelling of the unit
+    pub fn as_css(&self) -> &str {
+        match self {
+            LengthUnit::Px => "px",
+            LengthUnit::Rem => "rem",
+            LengthUnit::Em => "em",
+            LengthUnit::Percent => "%",
+            LengthUnit::Vw => "vw",
+            LengthUnit::Vh => "vh",
+            LengthUnit::Vmin => "vmin",
+            LengthUnit::Vmax => "vmax",
+            LengthUnit::Ch => "ch",
+            LengthUnit::Ex => "ex",
+            LengthUnit::Pt => "pt",
+            LengthUnit::Cm => "cm",
+            LengthUnit::Mm => "mm",
+            LengthUnit::In => "in",
+            LengthUnit::Deg => "deg",
+            LengthUnit::Rad => "rad",
+            LengthUnit::Turn => "turn",
+            LengthUnit::S => "s",
+            LengthUnit::Ms => "ms",
+            LengthUnit::Fr => "fr",
+            LengthUnit::Other(unit) => unit,
+        }
+    }
+}
+
+/// A typed token in a CSS declaration value
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssValueToken {
+    /// A number with a unit, including percentages, angles and times (`10px`, `50%`, `90deg`)
+    Length(f64, LengthUnit),
+    /// A unitless number
+    Number(f64),
+    /// A hex color or color function, kept as written (`#fff`, `rgb(0 0 0 / 50%)`)
+    Color(String),
+    /// A function call with its parsed arguments (`calc(100% - 2rem)`)
+    Function(String, Vec<CssValueToken>),
+    /// An identifier such as `solid`, `auto` or a custom property name
+    Keyword(String),
+    /// A quoted string, without the quotes
+    String(String),
+    /// A math operator or `/` separator between tokens
+    Operator(char),
+    /// A comma separating list items or function arguments
+    Comma,
+}
+
+/// Functions whose result is a color
+const COLOR_FUNCTIONS: &[&str] = &[
+    "rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color",
+];
+
+/// Parses a declaration value into typed tokens
+///
+/// Whitespace separates tokens and is otherwise dropped. A `+` or `-` directly
+/// followed by a digit is part of the number; on its own it is an
+/// [`CssValueToken::Operator`]. Function arguments are parsed recursively,
+/// except `url()` whose raw contents become a single [`CssValueToken::String`].
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::css_engine::value::{parse_value, CssValueToken, LengthUnit};
+///
+/// assert_eq!(
+///     parse_value("10px solid #fff"),
+///     vec![
+///         CssValueToken::Length(10.0, LengthUnit::Px),
+///         CssValueToken::Keyword("solid".to_string()),
+///         CssValueToken::Color("#fff".to_string()),
+///     ]
+/// );
+/// ```
+pub fn parse_value(value: &str) -> Vec<CssValueToken> {
+    let chars: Vec<char> = value.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            ',' => {
+                tokens.push(CssValueToken::Comma);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let end = skip_string(&chars, i);
+                let inner: String = chars[i + 1..closed_end(&chars, i, end, c)].iter().collect();
+                tokens.push(CssValueToken::String(inner));
+                i = end;
+            }
+            '#' => {
+                let end = skip_while(&chars, i + 1, |c| c.is_ascii_alphanumeric());
+                tokens.push(CssValueToken::Color(chars[i..end].iter().collect()));
+                i = end;
+            }
+            _ if starts_number(&chars, i) => {
+                let (token, end) = read_number(&chars, i);
+                tokens.push(token);
+                i = end;
+            }
+            _ if starts_identifier(&chars, i) => {
+                let end = skip_while(&chars, i, |c| c.is_alphanumeric() || c == '-' || c == '_');
+                let name: String = chars[i..end].iter().collect();
+                if chars.get(end) == Some(&'(') {
+                    let close = skip_block(&chars, end);
+                    let inner: String = chars[end + 1..closed_end(&chars, end, close, ')')]
+                        .iter()
+                        .collect();
+                    tokens.push(function_token(name, &inner, &chars[i..close]));
+                    i = close;
+                } else {
+                    tokens.push(CssValueToken::Keyword(name));
+                    i = end;
+                }
+            }
+            '+' | '-' | '*' | '/' => {
+                tokens.push(CssValueToken::Operator(c));
+                i += 1;
+            }
+            _ => {
+                tokens.push(CssValueToken::Keyword(c.to_string()));
+                i += 1;
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Builds the token for a function call
+fn function_token(name: String, inner: &str, source: &[char]) -> CssValueToken {
+    let lower = name.to_ascii_lowercase();
+    if COLOR_FUNCTIONS.contains(&lower.as_str()) {
+        CssValueToken::Color(source.iter().collect())
+    } else if lower == "url" {
+        let url = inner.trim().trim_matches(|c| c == '"' || c == '\'');
+        CssValueToken::Function(name, vec![CssValueToken::String(url.to_string())])
+    } else {
+        CssValueToken::Function(name, parse_value(inner))
+    }
+}
+
+/// Whether a number starts at `i` (`1`, `.5`, `-2`, `+3.1`)
+fn starts_number(chars: &[char], i: usize) -> bool {
+    let digit_at = |j: usize| chars.get(j).is_some_and(|c| c.is_ascii_digit());
+    match chars[i] {
+        c if c.is_ascii_digit() => true,
+        '.' => digit_at(i + 1),
+        '+' | '-' => digit_at(i + 1) || (chars.get(i + 1) == Some(&'.') && digit_at(i + 2)),
+        _ => false,
+    }
+}
+
+/// Whether an identifier starts at `i` (`solid`, `-webkit-box`, `--gap`)
+fn starts_identifier(chars: &[char], i: usize) -> bool {
+    let is_start = |c: char| c.is_alphabetic() || c == '_';
+    match chars[i] {
+        '-' => chars.get(i + 1).is_some_and(|&c| is_start(c) || c == '-'),
+        c => is_start(c),
+    }
+}
+
+/// Reads a number and its optional unit starting at `i`
+fn read_number(chars: &[char], i: usize) -> (CssValueToken, usize) {
+    let mut end = i + 1;
+    end = skip_while(chars, end, |c| c.is_ascii_digit() || c == '.');
+
+    // Exponent such as `1e3`; the `e` in `1em` belongs to the unit
+    if matches!(chars.get(end), Some('e' | 'E')) {
+        let mut exp = end + 1;
+        if matches!(chars.get(exp), Some('+' | '-')) {
+            exp += 1;
+        }
+        if chars.get(exp).is_some_and(|c| c.is_ascii_digit()) {
+            end = skip_while(chars, exp, |c| c.is_ascii_digit());
+        }
+    }
+
+    let number: String = chars[i..end].iter().collect();
+    let amount = number.parse::<f64>().unwrap_or(0.0);
+
+    if chars.get(end) == Some(&'%') {
+        return (CssValueToken::Length(amount, LengthUnit::Percent), end + 1);
+    }
+    let unit_end = skip_while(chars, end, |c| c.is_alphabetic());
+    if unit_end > end {
+        let unit: String = chars[end..unit_end].iter().collect();
+        (
+            CssValueToken::Length(amount, LengthUnit::from_css(&unit)),
+            unit_end,
+        )
+    } else {
+        (CssValueToken::Number(amount), end)
+    }
+}
+
+/// Returns the index after the last consecutive char matching `pred`
+fn skip_while(chars: &[char], mut i: usize, pred: impl Fn(char) -> bool) -> usize {
+    while i < chars.len() && pred(chars[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the index after the string literal starting at `start`
+fn skip_string(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Returns the end of the contents of a quoted or parenthesised span
+///
+/// `end` is the index after the span; the closing char is excluded unless the
+/// span was left unterminated.
+fn closed_end(chars: &[char], start: usize, end: usize, closing: char) -> usize {
+    if end > start + 1 && chars[end - 1] == closing {
+        end - 1
+    } else {
+        end
+    }
+}
+
+/// Returns the index after the parenthesised block starting at `start`
+fn skip_block(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '"' | '\'' => {
+                i = skip_string(chars, i);
+                continue;
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_border_shorthand() {
+        assert_eq!(
+            parse_value("10px solid #fff"),
+            vec![
+                CssValueToken::Length(10.0, LengthUnit::Px),
+                CssValueToken::Keyword("solid".to_string()),
+                CssValueToken::Color("#fff".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_calc_function() {
+        assert_eq!(
+            parse_value("calc(100% - 2rem)"),
+            vec![CssValueToken::Function(
+                "calc".to_string(),
+                vec![
+                    CssValueToken::Length(100.0, LengthUnit::Percent),
+                    CssValueToken::Operator('-'),
+                    CssValueToken::Length(2.0, LengthUnit::Rem),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_mixed_values() {
+        assert_eq!(
+            parse_value("-0.5em 1.5 var(--gap, 4px) rgb(0 0 0 / 50%)"),
+            vec![
+                CssValueToken::Length(-0.5, LengthUnit::Em),
+                CssValueToken::Number(1.5),
+                CssValueToken::Function(
+                    "var".to_string(),
+                    vec![
+                        CssValueToken::Keyword("--gap".to_string()),
+                        CssValueToken::Comma,
+                        CssValueToken::Length(4.0, LengthUnit::Px),
+                    ]
+                ),
+                CssValueToken::Color("rgb(0 0 0 / 50%)".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_value("url(\"a b.png\") 'Inter', 1e2"),
+            vec![
+                CssValueToken::Function(
+                    "url".to_string(),
+                    vec![CssValueToken::String("a b.png".to_string())]
+                ),
+                CssValueToken::String("Inter".to_string()),
+                CssValueToken::Comma,
+                CssValueToken::Number(100.0),
+            ]
+        );
+        assert_eq!(
+            parse_value("'open"),
+            vec![CssValueToken::String("open".to_string())]
+        );
+    }
+}
@@ -324,6 +324,7 @@ impl DiagnosticManager {
         self.register_rule("duplicate-property", Box::new(DuplicatePropertyRule));
         self.register_rule("performance-warning", Box::new(PerformanceWarningRule));
         self.register_rule("accessibility-check", Box::new(AccessibilityRule));
+        self.register_rule("invalid-var", Box::new(InvalidVarRule));
     }
 
     /// 注册诊断规则
@@ -712,3 +713,203 @@ impl DiagnosticRule for AccessibilityRule {
         "检查可访问性问题"
     }
 }
+
+/// 无效 var() 规则
+///
+/// 检查缺少右括号、变量名为空或变量名不以 `--` 开头的 `var()` 调用。
+/// 诊断范围覆盖整个 `var(...)`，`offset` 为字节偏移量。
+struct InvalidVarRule;
+
+impl DiagnosticRule for InvalidVarRule {
+    fn check(&self, code: &str, file_path: &str) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let bytes = code.as_bytes();
+        let mut search_from = 0;
+
+        while let Some(found) = code[search_from..].find("var(") {
+            let start = search_from + found;
+            search_from = start + "var(".len();
+
+            // 跳过 `somevar(` 这类以 var 结尾的其他函数名
+            let preceded_by_ident = start > 0 && {
+                let prev = bytes[start - 1];
+                prev.is_ascii_alphanumeric() || prev == b'-' || prev == b'_'
+            };
+            if preceded_by_ident {
+                continue;
+            }
+
+            let args_start = search_from;
+            let (end, closed) = var_call_end(code, args_start);
+            let args = &code[args_start..if closed { end - 1 } else { end }];
+            let name = args.split(',').next().unwrap_or("").trim();
+            let range = byte_range(code, start, end);
+
+            let diagnostic = if !closed {
+                Diagnostic::new(
+                    DiagnosticLevel::Error,
+                    DiagnosticType::SyntaxError,
+                    "E002".to_string(),
+                    "var() 缺少右括号".to_string(),
+                    range.clone(),
+                    file_path.to_string(),
+                )
+                .with_fix(DiagnosticFix::new(
+                    "添加右括号".to_string(),
+                    vec![TextEdit::insert(range.end, ")".to_string())],
+                ))
+            } else if name.is_empty() {
+                Diagnostic::new(
+                    DiagnosticLevel::Error,
+                    DiagnosticType::SyntaxError,
+                    "E002".to_string(),
+                    "var() 缺少变量名".to_string(),
+                    range,
+                    file_path.to_string(),
+                )
+            } else if !name.starts_with("--") {
+                let name_start = args_start + (args.len() - args.trim_start().len());
+                let fixed = format!("--{}", name.trim_start_matches('-'));
+                Diagnostic::new(
+                    DiagnosticLevel::Error,
+                    DiagnosticType::SyntaxError,
+                    "E002".to_string(),
+                    format!("var() 变量名必须以 `--` 开头: {}", name),
+                    range,
+                    file_path.to_string(),
+                )
+                .with_fix(
+                    DiagnosticFix::new(
+                        format!("改为 `{}`", fixed),
+                        vec![TextEdit::replace(
+                            byte_range(code, name_start, name_start + name.len()),
+                            fixed,
+                        )],
+                    )
+                    .as_preferred(),
+                )
+            } else {
+                continue;
+            };
+
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+
+    fn name(&self) -> &str {
+        "invalid-var"
+    }
+
+    fn description(&self) -> &str {
+        "检查无效的 var() 用法"
+    }
+}
+
+/// 查找 `var(` 参数之后的结束位置
+///
+/// 返回结束字节偏移量及是否找到匹配的右括号；未闭合时在声明结束处（`;`、`}`、换行）或代码末尾停止。
+fn var_call_end(code: &str, args_start: usize) -> (usize, bool) {
+    let mut depth = 1usize;
+    let mut quote: Option<char> = None;
+
+    for (index, c) in code[args_start..].char_indices() {
+        let offset = args_start + index;
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (offset + 1, true);
+                }
+            }
+            ';' | '}' | '\n' => return (offset, false),
+            _ => {}
+        }
+    }
+
+    (code.len(), false)
+}
+
+/// 将字节范围转换为代码范围，行号和列号从1开始
+fn byte_range(code: &str, start: usize, end: usize) -> CodeRange {
+    CodeRange::new(byte_position(code, start), byte_position(code, end))
+}
+
+/// 计算字节偏移量对应的代码位置
+fn byte_position(code: &str, offset: usize) -> CodePosition {
+    let before = &code[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let column = before[line_start..].chars().count() + 1;
+    CodePosition::new(line, column, offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var_diagnostics(code: &str) -> Vec<Diagnostic> {
+        InvalidVarRule.check(code, "test.css")
+    }
+
+    #[test]
+    fn test_valid_var_passes() {
+        assert!(var_diagnostics(".a { color: var(--ok); }").is_empty());
+        assert!(var_diagnostics(".a { margin: var(--gap, var(--fallback, 4px)); }").is_empty());
+        assert!(var_diagnostics(".a { width: somevar(x); }").is_empty());
+    }
+
+    #[test]
+    fn test_var_without_dashes() {
+        let code = ".a { color: var(primary); }";
+        let diagnostics = var_diagnostics(code);
+
+        assert_eq!(diagnostics.len(), 1);
+        let range = &diagnostics[0].range;
+        assert_eq!(&code[range.start.offset..range.end.offset], "var(primary)");
+        assert_eq!((range.start.line, range.start.column), (1, 13));
+        assert_eq!(diagnostics[0].code, "E002");
+        assert_eq!(diagnostics[0].fixes[0].edits[0].new_text, "--primary");
+
+        let diagnostics = var_diagnostics(".a {\n  color: var(-primary);\n}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 2);
+        assert_eq!(diagnostics[0].fixes[0].edits[0].new_text, "--primary");
+    }
+
+    #[test]
+    fn test_unclosed_and_empty_var() {
+        let code = ".a { color: var(--x; }";
+        let diagnostics = var_diagnostics(code);
+
+        assert_eq!(diagnostics.len(), 1);
+        let range = &diagnostics[0].range;
+        assert_eq!(&code[range.start.offset..range.end.offset], "var(--x");
+        assert_eq!(diagnostics[0].message, "var() 缺少右括号");
+
+        let code = "color: var( , red)";
+        let diagnostics = var_diagnostics(code);
+        assert_eq!(diagnostics.len(), 1);
+        let range = &diagnostics[0].range;
+        assert_eq!((range.start.offset, range.end.offset), (7, code.len()));
+        assert_eq!(diagnostics[0].message, "var() 缺少变量名");
+    }
+
+    #[test]
+    fn test_invalid_var_rule_is_registered() {
+        let diagnostics = DiagnosticManager::new().analyze_code(".a { color: var(x); }", "a.css");
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.code == "E002"));
+    }
+}
@@ -277,6 +277,10 @@ pub struct IdeIntegration {
     capabilities: LspCapabilities,
     /// 消息处理器
     message_handlers: HashMap<String, Box<dyn MessageHandler + Send + Sync>>,
+    /// 服务器实际监听的端口
+    bound_port: Option<u16>,
+    /// 监听连接的线程
+    server_thread: Option<thread::JoinHandle<()>>,
 }
 
 /// LSP服务器状态
@@ -305,6 +309,64 @@ pub struct LanguageServerProtocol {
     pub supported_methods: Vec<String>,
     /// 服务器能力
     pub capabilities: LspCapabilities,
+    /// 已注册的主题变量，键为变量名（如 `--ant-color-primary`）
+    pub theme_variables: HashMap<String, ThemeVariableInfo>,
+    /// 已打开的文档内容，键为文档 URI
+    pub documents: HashMap<String, String>,
+    /// 主题变量的定义位置，键为变量名
+    pub token_definitions: HashMap<String, DefinitionLocation>,
+}
+
+/// 定义位置
+///
+/// 对应 LSP 的 `Location`，行号和字符位置从0开始。
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefinitionLocation {
+    /// 定义所在文件的 URI
+    pub uri: String,
+    /// 起始行
+    pub start_line: usize,
+    /// 起始字符
+    pub start_character: usize,
+    /// 结束行
+    pub end_line: usize,
+    /// 结束字符
+    pub end_character: usize,
+}
+
+impl DefinitionLocation {
+    /// 创建单行内的定义位置
+    pub fn new(uri: &str, line: usize, start_character: usize, end_character: usize) -> Self {
+        Self {
+            uri: uri.to_string(),
+            start_line: line,
+            start_character,
+            end_line: line,
+            end_character,
+        }
+    }
+
+    /// 转换为 LSP `Location`
+    pub fn to_lsp_location(&self) -> serde_json::Value {
+        serde_json::json!({
+            "uri": self.uri,
+            "range": {
+                "start": { "line": self.start_line, "character": self.start_character },
+                "end": { "line": self.end_line, "character": self.end_character }
+            }
+        })
+    }
+}
+
+/// 主题变量信息
+///
+/// 用于悬停提示显示变量的值及其来源 token。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeVariableInfo {
+    /// 变量值，可以引用其他变量（如 `var(--ant-blue-6)`）
+    pub value: String,
+    /// 来源 token 路径（如 `color.primary`）
+    pub token_path: String,
 }
 
 /// 客户端连接
@@ -338,6 +400,10 @@ pub struct IdeConfig {
     pub enable_language_server: bool,
     /// 服务器端口
     pub server_port: u16,
+    /// 端口被占用时向后尝试的端口数量
+    ///
+    /// 依次尝试 `server_port + 1` 到 `server_port + port_fallback_range`，为0时只尝试配置的端口
+    pub port_fallback_range: u16,
     /// 是否启用悬停提示
     pub enable_hover: bool,
     /// 是否启用跳转到定义
@@ -394,6 +460,8 @@ impl IdeIntegration {
             config,
             capabilities,
             message_handlers: HashMap::new(),
+            bound_port: None,
+            server_thread: None,
         }
     }
 
@@ -484,7 +552,9 @@ impl IdeIntegration {
     }
 
     /// 启动LSP服务器
-    pub fn start_language_server(&mut self) -> Result<(), LspError> {
+    ///
+    /// 先尝试配置的端口，被占用时依次尝试后续 `port_fallback_range` 个端口，返回实际监听的端口。
+    pub fn start_language_server(&mut self) -> Result<u16, LspError> {
         if !self.config.enable_language_server {
             return Err(LspError::ServerError(-1, "语言服务器未启用".to_string()));
         }
@@ -496,11 +566,16 @@ impl IdeIntegration {
         }
 
         // 启动TCP服务器
-        let addr = format!("127.0.0.1:{}", self.config.server_port);
-        let listener = TcpListener::bind(&addr)
-            .map_err(|e| LspError::ServerError(-2, format!("无法绑定地址 {}: {}", addr, e)))?;
+        let (listener, port) = match self.bind_listener() {
+            Ok(bound) => bound,
+            Err(err) => {
+                *self.server_state.lock().unwrap() = LspServerState::Error(err.to_string());
+                return Err(err);
+            }
+        };
+        self.bound_port = Some(port);
 
-        println!("CSS-in-Rust LSP服务器启动在 {}", addr);
+        println!("CSS-in-Rust LSP服务器启动在 127.0.0.1:{}", port);
 
         // 更新状态为运行中
         {
@@ -512,8 +587,13 @@ impl IdeIntegration {
         let clients = Arc::clone(&self.clients);
         let server_state = Arc::clone(&self.server_state);
 
-        thread::spawn(move || {
+        self.server_thread = Some(thread::spawn(move || {
             for stream in listener.incoming() {
+                // 停止时会通过一次本地连接唤醒此循环
+                if *server_state.lock().unwrap() != LspServerState::Running {
+                    break;
+                }
+
                 match stream {
                     Ok(stream) => {
                         let client_addr = stream.peer_addr().unwrap();
@@ -546,18 +626,39 @@ impl IdeIntegration {
                         eprintln!("连接错误: {}", e);
                     }
                 }
+            }
+        }));
 
-                // 检查服务器状态
-                {
-                    let state = server_state.lock().unwrap();
-                    if *state == LspServerState::Stopping {
-                        break;
-                    }
-                }
+        Ok(port)
+    }
+
+    /// 绑定监听端口，配置的端口被占用时向后尝试
+    fn bind_listener(&self) -> Result<(TcpListener, u16), LspError> {
+        let first = self.config.server_port;
+        let last = first.saturating_add(self.config.port_fallback_range);
+        let mut last_error = None;
+
+        for port in first..=last {
+            match TcpListener::bind(("127.0.0.1", port)) {
+                Ok(listener) => return Ok((listener, port)),
+                Err(e) => last_error = Some(e),
             }
-        });
+        }
 
-        Ok(())
+        Err(LspError::ServerError(
+            -2,
+            format!(
+                "无法绑定端口 {}-{}: {}",
+                first,
+                last,
+                last_error.map(|e| e.to_string()).unwrap_or_default()
+            ),
+        ))
+    }
+
+    /// 获取服务器实际监听的端口，未启动时返回 `None`
+    pub fn get_bound_port(&self) -> Option<u16> {
+        self.bound_port
     }
 
     /// 处理客户端连接
@@ -589,6 +690,15 @@ impl IdeIntegration {
             *state = LspServerState::Stopping;
         }
 
+        // 唤醒阻塞在 accept 上的监听线程，等待其退出并释放端口
+        if let Some(port) = self.bound_port {
+            if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+                if let Some(handle) = self.server_thread.take() {
+                    let _ = handle.join();
+                }
+            }
+        }
+
         // 断开所有客户端连接
         {
             let mut clients = self.clients.lock().unwrap();
@@ -600,6 +710,7 @@ impl IdeIntegration {
             let mut state = self.server_state.lock().unwrap();
             *state = LspServerState::Stopped;
         }
+        self.bound_port = None;
 
         println!("LSP服务器已停止");
         Ok(())
@@ -838,6 +949,212 @@ let g:css_in_rust_max_diagnostics = {}"#,
     }
 }
 
+impl LanguageServerProtocol {
+    /// 创建新的语言服务器协议实例
+    pub fn new() -> Self {
+        Self {
+            version: "3.17".to_string(),
+            supported_methods: vec![
+                "initialize".to_string(),
+                "textDocument/didOpen".to_string(),
+                "textDocument/hover".to_string(),
+                "textDocument/definition".to_string(),
+            ],
+            capabilities: IdeIntegration::create_default_capabilities(),
+            theme_variables: HashMap::new(),
+            documents: HashMap::new(),
+            token_definitions: HashMap::new(),
+        }
+    }
+
+    /// 注册主题变量
+    pub fn register_theme_variable(&mut self, name: &str, value: &str, token_path: &str) {
+        self.theme_variables.insert(
+            name.to_string(),
+            ThemeVariableInfo {
+                value: value.to_string(),
+                token_path: token_path.to_string(),
+            },
+        );
+    }
+
+    /// 注册主题变量的定义位置（如 token 文件或主题注册代码中的位置）
+    pub fn register_token_definition(&mut self, name: &str, location: DefinitionLocation) {
+        self.token_definitions.insert(name.to_string(), location);
+    }
+
+    /// 记录打开的文档内容
+    pub fn open_document(&mut self, uri: &str, text: &str) {
+        self.documents.insert(uri.to_string(), text.to_string());
+    }
+
+    /// 解析变量的最终值
+    ///
+    /// 值为 `var(--other)` 时继续查找被引用的变量，遇到循环引用或未注册的变量时停止。
+    pub fn resolve_variable(&self, name: &str) -> Option<String> {
+        let mut value = self.theme_variables.get(name)?.value.clone();
+        let mut visited = vec![name.to_string()];
+
+        while let Some(reference) = single_var_reference(&value) {
+            if visited.contains(&reference) {
+                break;
+            }
+            let Some(next) = self.theme_variables.get(&reference) else {
+                break;
+            };
+            value = next.value.clone();
+            visited.push(reference);
+        }
+
+        Some(value)
+    }
+
+    /// 生成悬停内容
+    ///
+    /// `line` 和 `character` 为从0开始的行号和字符位置。位置位于已注册变量的 `var()` 内时，
+    /// 返回 LSP Hover 结果，包含解析后的值和来源 token 路径。
+    pub fn hover(&self, text: &str, line: usize, character: usize) -> Option<serde_json::Value> {
+        let line_text = text.lines().nth(line)?;
+        let (start, end, name) = var_at(line_text, character)?;
+        let info = self.theme_variables.get(&name)?;
+        let resolved = self.resolve_variable(&name)?;
+
+        let mut markdown = format!("**`{}`**: `{}`", name, resolved);
+        if resolved != info.value {
+            markdown.push_str(&format!("\n\n引用: `{}`", info.value));
+        }
+        markdown.push_str(&format!("\n\n来源 token: `{}`", info.token_path));
+
+        Some(serde_json::json!({
+            "contents": {
+                "kind": "markdown",
+                "value": markdown
+            },
+            "range": {
+                "start": { "line": line, "character": start },
+                "end": { "line": line, "character": end }
+            }
+        }))
+    }
+
+    /// 查找 `var()` 所引用变量的定义位置
+    ///
+    /// `line` 和 `character` 为从0开始的行号和字符位置；变量未注册定义位置时返回 `None`。
+    pub fn goto_definition(
+        &self,
+        text: &str,
+        line: usize,
+        character: usize,
+    ) -> Option<DefinitionLocation> {
+        let line_text = text.lines().nth(line)?;
+        let (_, _, name) = var_at(line_text, character)?;
+        self.token_definitions.get(&name).cloned()
+    }
+
+    /// 处理 `textDocument/hover` 请求
+    ///
+    /// 文档需先通过 [`open_document`](Self::open_document) 记录；位置不在已注册变量上时返回 `None`。
+    pub fn handle_hover(&self, message: &LspMessage) -> Result<Option<LspMessage>, LspError> {
+        let (text, line, character) = self.document_position(message)?;
+
+        Ok(self.hover(text, line, character).map(|hover| LspMessage {
+            message_type: LspMessageType::Response,
+            method: "textDocument/hover".to_string(),
+            params: hover,
+            id: message.id.clone(),
+        }))
+    }
+
+    /// 处理 `textDocument/definition` 请求
+    ///
+    /// 文档需先通过 [`open_document`](Self::open_document) 记录；找不到定义时返回 `None`。
+    pub fn handle_definition(&self, message: &LspMessage) -> Result<Option<LspMessage>, LspError> {
+        let (text, line, character) = self.document_position(message)?;
+
+        Ok(self
+            .goto_definition(text, line, character)
+            .map(|location| LspMessage {
+                message_type: LspMessageType::Response,
+                method: "textDocument/definition".to_string(),
+                params: location.to_lsp_location(),
+                id: message.id.clone(),
+            }))
+    }
+
+    /// 从 `TextDocumentPositionParams` 中取出文档内容和位置
+    fn document_position(&self, message: &LspMessage) -> Result<(&str, usize, usize), LspError> {
+        let params = &message.params;
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .ok_or_else(|| LspError::InvalidParams("缺少 textDocument.uri".to_string()))?;
+        let (Some(line), Some(character)) = (
+            params["position"]["line"].as_u64(),
+            params["position"]["character"].as_u64(),
+        ) else {
+            return Err(LspError::InvalidParams("缺少 position".to_string()));
+        };
+        let text = self
+            .documents
+            .get(uri)
+            .ok_or_else(|| LspError::InvalidParams(format!("文档未打开: {}", uri)))?;
+
+        Ok((text, line as usize, character as usize))
+    }
+}
+
+impl Default for LanguageServerProtocol {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 若值仅为一个 `var(--name)`（可带回退值），返回被引用的变量名
+fn single_var_reference(value: &str) -> Option<String> {
+    let value = value.trim();
+    let (start, end, name) = var_at(value, value.find("var(")?)?;
+    (start == 0 && end == value.chars().count()).then_some(name)
+}
+
+/// 查找包含指定字符位置的 `var()`，返回其字符范围和变量名
+///
+/// 嵌套时返回最内层的 `var()`。
+fn var_at(line: &str, character: usize) -> Option<(usize, usize, String)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut found = None;
+
+    for start in 0..chars.len() {
+        if !chars[start..].starts_with(&['v', 'a', 'r', '(']) {
+            continue;
+        }
+
+        let mut depth = 0usize;
+        let mut end = chars.len();
+        for (index, &c) in chars.iter().enumerate().skip(start + 3) {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = index + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if (start..end).contains(&character) {
+            let args: String = chars[start + 4..end.saturating_sub(1).max(start + 4)]
+                .iter()
+                .collect();
+            let name = args.split(',').next().unwrap_or("").trim().to_string();
+            found = Some((start, end, name));
+        }
+    }
+
+    found
+}
+
 /// 服务器统计信息
 #[derive(Debug, Clone)]
 pub struct ServerStats {
@@ -912,6 +1229,7 @@ impl Default for IdeConfig {
         Self {
             enable_language_server: true,
             server_port: 9257, // CSS-in-Rust LSP默认端口
+            port_fallback_range: 10,
             enable_hover: true,
             enable_goto_definition: true,
             enable_refactoring: true,
@@ -943,3 +1261,147 @@ impl fmt::Display for LspError {
 }
 
 impl std::error::Error for LspError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hover_request(uri: &str, line: u64, character: u64) -> LspMessage {
+        position_request("textDocument/hover", uri, line, character)
+    }
+
+    fn position_request(method: &str, uri: &str, line: u64, character: u64) -> LspMessage {
+        LspMessage {
+            message_type: LspMessageType::Request,
+            method: method.to_string(),
+            params: serde_json::json!({
+                "textDocument": { "uri": uri },
+                "position": { "line": line, "character": character }
+            }),
+            id: Some(serde_json::json!(1)),
+        }
+    }
+
+    #[test]
+    fn test_hover_resolves_theme_variable() {
+        let mut lsp = LanguageServerProtocol::new();
+        lsp.register_theme_variable("--ant-blue-6", "#1677ff", "color.blue.6");
+        lsp.register_theme_variable("--ant-color-primary", "var(--ant-blue-6)", "color.primary");
+        lsp.open_document(
+            "file:///button.css",
+            ".button {\n  color: var(--ant-color-primary);\n  margin: 0;\n}",
+        );
+
+        // 第二行 `var(` 从第9个字符开始
+        let response = lsp
+            .handle_hover(&hover_request("file:///button.css", 1, 15))
+            .unwrap()
+            .expect("hover over var()");
+        assert_eq!(response.id, Some(serde_json::json!(1)));
+
+        let markdown = response.params["contents"]["value"].as_str().unwrap();
+        assert!(markdown.contains("`#1677ff`"), "{markdown}");
+        assert!(markdown.contains("`color.primary`"), "{markdown}");
+        assert_eq!(response.params["range"]["start"]["character"], 9);
+        assert_eq!(response.params["range"]["end"]["character"], 33);
+
+        // 不在 var() 上
+        assert!(lsp
+            .handle_hover(&hover_request("file:///button.css", 2, 4))
+            .unwrap()
+            .is_none());
+        assert!(matches!(
+            lsp.handle_hover(&hover_request("file:///missing.css", 0, 0)),
+            Err(LspError::InvalidParams(_))
+        ));
+    }
+
+    #[test]
+    fn test_hover_nested_var_uses_innermost() {
+        let mut lsp = LanguageServerProtocol::new();
+        lsp.register_theme_variable("--gap", "8px", "spacing.gap");
+
+        let line = "margin: var(--missing, var(--gap));";
+        let hover = lsp.hover(line, 0, line.find("--gap").unwrap()).unwrap();
+        assert!(hover["contents"]["value"]
+            .as_str()
+            .unwrap()
+            .contains("`8px`"));
+        assert!(lsp
+            .hover(line, 0, line.find("--missing").unwrap())
+            .is_none());
+    }
+
+    #[test]
+    fn test_goto_definition_for_theme_token() {
+        let mut lsp = LanguageServerProtocol::new();
+        let definition = DefinitionLocation::new("file:///theme/tokens.rs", 41, 8, 28);
+        lsp.register_token_definition("--ant-color-primary", definition.clone());
+        lsp.open_document(
+            "file:///button.css",
+            ".button { border: 1px solid var(--ant-color-primary); color: var(--x); }",
+        );
+
+        let response = lsp
+            .handle_definition(&position_request(
+                "textDocument/definition",
+                "file:///button.css",
+                0,
+                36,
+            ))
+            .unwrap()
+            .expect("definition for registered token");
+        assert_eq!(response.params["uri"], "file:///theme/tokens.rs");
+        assert_eq!(response.params["range"]["start"]["line"], 41);
+        assert_eq!(response.params["range"]["start"]["character"], 8);
+        assert_eq!(response.params["range"]["end"]["character"], 28);
+
+        let text = &lsp.documents["file:///button.css"];
+        assert_eq!(lsp.goto_definition(text, 0, 29), Some(definition));
+        // 未注册定义位置的变量
+        assert_eq!(
+            lsp.goto_definition(text, 0, text.find("--x").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_language_server_falls_back_to_next_free_port() {
+        // 占用一个由系统分配的空闲端口，避免依赖固定端口
+        let occupied = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let config = IdeConfig {
+            server_port: occupied.local_addr().unwrap().port(),
+            ..IdeConfig::default()
+        };
+
+        let mut ide = IdeIntegration::new(IdeType::Generic, config.clone());
+        let port = ide.start_language_server().unwrap();
+
+        assert_ne!(port, config.server_port);
+        assert!(port > config.server_port);
+        assert!(port <= config.server_port + config.port_fallback_range);
+        assert_eq!(ide.get_bound_port(), Some(port));
+        assert_eq!(ide.get_server_state(), LspServerState::Running);
+
+        // 不允许回退时，端口被占用会导致启动失败
+        let mut strict = IdeIntegration::new(
+            IdeType::Generic,
+            IdeConfig {
+                server_port: port,
+                port_fallback_range: 0,
+                ..config
+            },
+        );
+        assert!(strict.start_language_server().is_err());
+        assert!(matches!(
+            strict.get_server_state(),
+            LspServerState::Error(_)
+        ));
+        assert_eq!(strict.get_bound_port(), None);
+
+        // 停止后监听线程退出并释放端口
+        ide.stop_language_server().unwrap();
+        assert_eq!(ide.get_server_state(), LspServerState::Stopped);
+        assert!(TcpListener::bind(("127.0.0.1", port)).is_ok());
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! 提供开发时的各种便利功能和工具
 
+use std::collections::HashMap;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use crate::hot_reload::{FileWatcher, FileWatcherConfig, WatchEvent, WatchEventType};
 
 pub mod code_completion;
 pub mod diagnostics;
@@ -16,7 +20,9 @@ pub use code_completion::{CompletionItem, CompletionProvider};
 pub use diagnostics::{Diagnostic, DiagnosticLevel, DiagnosticManager};
 pub use error_reporting::{ErrorContext, ErrorFormat, ErrorReporter};
 pub use ide_integration::IdeConfig;
-pub use ide_integration::{IdeIntegration, IdeType, LanguageServerProtocol};
+pub use ide_integration::{
+    DefinitionLocation, IdeIntegration, IdeType, LanguageServerProtocol, ThemeVariableInfo,
+};
 pub use syntax_highlighting::{HighlightTheme, SyntaxHighlighter};
 
 /// 开发体验配置
@@ -75,6 +81,8 @@ pub struct IdeSettings {
     pub enable_language_server: bool,
     /// 语言服务器端口
     pub language_server_port: u16,
+    /// 端口被占用时向后尝试的端口数量
+    pub language_server_port_fallback_range: u16,
     /// 是否启用悬停提示
     pub enable_hover_hints: bool,
     /// 是否启用跳转到定义
@@ -90,6 +98,7 @@ impl Default for IdeSettings {
         Self {
             enable_language_server: true,
             language_server_port: 9257,
+            language_server_port_fallback_range: 10,
             enable_hover_hints: true,
             enable_goto_definition: true,
             enable_refactoring: true,
@@ -114,7 +123,10 @@ impl DevExperienceManager {
         Self {
             diagnostic_manager: DiagnosticManager::new(),
             syntax_highlighter: SyntaxHighlighter::new(&config.highlight_theme),
-            ide_integration: IdeIntegration::new(IdeType::VsCode, IdeConfig::default()),
+            ide_integration: IdeIntegration::new(
+                IdeType::VsCode,
+                Self::create_ide_config(&config.ide_settings),
+            ),
             // 根据配置设置错误报告格式
             error_reporter: Self::create_error_reporter(&config.error_format),
             completion_provider: CompletionProvider::new(),
@@ -122,6 +134,20 @@ impl DevExperienceManager {
         }
     }
 
+    /// 根据IDE设置创建IDE配置
+    fn create_ide_config(settings: &IdeSettings) -> IdeConfig {
+        IdeConfig {
+            enable_language_server: settings.enable_language_server,
+            server_port: settings.language_server_port,
+            port_fallback_range: settings.language_server_port_fallback_range,
+            enable_hover: settings.enable_hover_hints,
+            enable_goto_definition: settings.enable_goto_definition,
+            enable_refactoring: settings.enable_refactoring,
+            workspace_root: settings.workspace_root.clone(),
+            ..IdeConfig::default()
+        }
+    }
+
     /// 根据配置创建错误报告器
     fn create_error_reporter(
         error_format: &crate::dev_experience::error_reporting::ErrorFormat,
@@ -197,6 +223,13 @@ impl DevExperienceManager {
         Ok(())
     }
 
+    /// 获取语言服务器实际监听的端口
+    ///
+    /// 配置的端口被占用时可能与 `language_server_port` 不同；未启动时返回 `None`。
+    pub fn language_server_port(&self) -> Option<u16> {
+        self.ide_integration.get_bound_port()
+    }
+
     /// 停止开发服务
     pub fn stop_dev_services(&mut self) {
         self.diagnostic_manager.stop_live_diagnostics();
@@ -334,6 +367,64 @@ impl ProcessResult {
     }
 }
 
+/// 工作区报告
+///
+/// 汇总多个文件的处理结果，用于生成 CI 摘要。
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceReport {
+    /// 各文件的路径和处理结果，按添加顺序排列
+    files: Vec<(PathBuf, ProcessResult)>,
+}
+
+impl WorkspaceReport {
+    /// 创建空的工作区报告
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加一个文件的处理结果
+    pub fn add(&mut self, path: impl Into<PathBuf>, result: ProcessResult) {
+        self.files.push((path.into(), result));
+    }
+
+    /// 获取所有文件的处理结果
+    pub fn files(&self) -> &[(PathBuf, ProcessResult)] {
+        &self.files
+    }
+
+    /// 获取所有文件的错误总数
+    pub fn total_errors(&self) -> usize {
+        self.files
+            .iter()
+            .map(|(_, result)| result.error_count())
+            .sum()
+    }
+
+    /// 获取所有文件的警告总数
+    pub fn total_warnings(&self) -> usize {
+        self.files
+            .iter()
+            .map(|(_, result)| result.warning_count())
+            .sum()
+    }
+
+    /// 按诊断级别分组所有诊断，每条诊断附带其文件路径
+    ///
+    /// 同一级别内保持文件的添加顺序和文件内的诊断顺序。
+    pub fn by_severity(&self) -> HashMap<DiagnosticLevel, Vec<(&Path, &Diagnostic)>> {
+        let mut grouped: HashMap<DiagnosticLevel, Vec<(&Path, &Diagnostic)>> = HashMap::new();
+        for (path, result) in &self.files {
+            for diagnostic in &result.diagnostics {
+                grouped
+                    .entry(diagnostic.level.clone())
+                    .or_default()
+                    .push((path.as_path(), diagnostic));
+            }
+        }
+        grouped
+    }
+}
+
 /// 性能提示
 #[derive(Debug, Clone)]
 pub struct PerformanceHint {
@@ -369,6 +460,8 @@ pub enum DevExperienceError {
     IdeIntegrationFailed(String),
     /// 配置错误
     ConfigError(String),
+    /// 文件监控失败
+    FileWatchFailed(String),
 }
 
 impl fmt::Display for DevExperienceError {
@@ -389,15 +482,53 @@ impl fmt::Display for DevExperienceError {
             DevExperienceError::ConfigError(msg) => {
                 write!(f, "配置错误: {}", msg)
             }
+            DevExperienceError::FileWatchFailed(msg) => {
+                write!(f, "文件监控失败: {}", msg)
+            }
         }
     }
 }
 
 impl std::error::Error for DevExperienceError {}
 
+/// 文件变更分析报告
+///
+/// 由 [`DevTools::watch_and_analyze`] 在每次 CSS 文件创建或修改后生成。
+#[derive(Debug, Clone)]
+pub struct ChangeReport {
+    /// 变更的文件
+    pub path: PathBuf,
+    /// 变更类型
+    pub event_type: WatchEventType,
+    /// 分析结果
+    pub result: ProcessResult,
+}
+
+impl ChangeReport {
+    /// 格式化为控制台输出
+    pub fn format(&self) -> String {
+        let mut output = format!(
+            "📄 {}: {} 个错误, {} 个警告",
+            self.path.display(),
+            self.result.error_count(),
+            self.result.warning_count()
+        );
+        for diagnostic in &self.result.diagnostics {
+            output.push('\n');
+            output.push_str(&diagnostic.format());
+        }
+        output
+    }
+}
+
+/// 监控分析时处理的样式文件扩展名
+const WATCHED_STYLE_EXTENSIONS: &[&str] = &["css", "scss", "less"];
+
 /// 开发体验工具集
 pub struct DevTools {
     manager: DevExperienceManager,
+    /// 监控分析使用的文件监控器
+    watcher: Option<FileWatcher>,
 }
 
 impl DevTools {
@@ -405,6 +536,76 @@ impl DevTools {
     pub fn new(config: DevExperienceConfig) -> Self {
         Self {
             manager: DevExperienceManager::new(config),
+            watcher: None,
+        }
+    }
+
+    /// 监控目录并在 CSS 文件变更时运行诊断
+    ///
+    /// 每次 `.css`、`.scss` 或 `.less` 文件被创建或修改时，按 [`analyze_css_file`](Self::analyze_css_file)
+    /// 的方式分析文件并打印报告；报告同时发送到返回的接收端。再次调用会替换之前的监控，
+    /// [`stop_dev_mode`](Self::stop_dev_mode) 会停止监控。
+    pub fn watch_and_analyze(
+        &mut self,
+        dirs: Vec<PathBuf>,
+    ) -> Result<mpsc::Receiver<ChangeReport>, DevExperienceError> {
+        self.stop_watching();
+
+        let mut watcher = FileWatcher::with_config(FileWatcherConfig {
+            extension_filter: Some(
+                WATCHED_STYLE_EXTENSIONS
+                    .iter()
+                    .map(|ext| ext.to_string())
+                    .collect(),
+            ),
+            ..FileWatcherConfig::default()
+        });
+        for dir in dirs {
+            watcher
+                .watch_directory(dir)
+                .map_err(|e| DevExperienceError::FileWatchFailed(e.to_string()))?;
+        }
+
+        // 监控线程使用相同配置的独立分析器
+        let analyzer = DevExperienceManager::new(self.manager.config.clone());
+        let (sender, receiver) = mpsc::channel();
+        watcher.set_event_handler(move |event: &WatchEvent| {
+            if let Some(report) = Self::analyze_change(&analyzer, event) {
+                println!("{}", report.format());
+                let _ = sender.send(report);
+            }
+        });
+        watcher
+            .start()
+            .map_err(|e| DevExperienceError::FileWatchFailed(e.to_string()))?;
+
+        self.watcher = Some(watcher);
+        Ok(receiver)
+    }
+
+    /// 分析一次文件变更，删除等非内容变更返回 `None`
+    fn analyze_change(analyzer: &DevExperienceManager, event: &WatchEvent) -> Option<ChangeReport> {
+        if !matches!(
+            event.event_type,
+            WatchEventType::Created | WatchEventType::Modified
+        ) || event.is_directory_event()
+        {
+            return None;
+        }
+
+        let content = std::fs::read_to_string(&event.path).ok()?;
+        let result = analyzer.process_css_code(&content, &event.path.to_string_lossy());
+        Some(ChangeReport {
+            path: event.path.clone(),
+            event_type: event.event_type.clone(),
+            result,
+        })
+    }
+
+    /// 停止监控分析
+    fn stop_watching(&mut self) {
+        if let Some(mut watcher) = self.watcher.take() {
+            watcher.stop();
         }
     }
 
@@ -419,6 +620,7 @@ impl DevTools {
     /// 停止开发模式
     pub fn stop_dev_mode(&mut self) {
         println!("🛑 停止开发模式...");
+        self.stop_watching();
         self.manager.stop_dev_services();
         println!("✅ 开发服务已停止");
     }
@@ -458,3 +660,80 @@ impl DevTools {
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_experience::diagnostics::{CodePosition, CodeRange, DiagnosticType};
+
+    fn diagnostic(level: DiagnosticLevel, file: &str) -> Diagnostic {
+        Diagnostic::new(
+            level,
+            DiagnosticType::SyntaxError,
+            "T001".to_string(),
+            "test".to_string(),
+            CodeRange::point(CodePosition::new(1, 1, 0)),
+            file.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_workspace_report_aggregates_files() {
+        let mut button = ProcessResult::new();
+        button.diagnostics = vec![
+            diagnostic(DiagnosticLevel::Error, "button.css"),
+            diagnostic(DiagnosticLevel::Warning, "button.css"),
+            diagnostic(DiagnosticLevel::Info, "button.css"),
+        ];
+        let mut card = ProcessResult::new();
+        card.diagnostics = vec![
+            diagnostic(DiagnosticLevel::Error, "card.css"),
+            diagnostic(DiagnosticLevel::Error, "card.css"),
+        ];
+
+        let mut report = WorkspaceReport::new();
+        report.add("src/button.css", button);
+        report.add(PathBuf::from("src/card.css"), card);
+
+        assert_eq!(report.files().len(), 2);
+        assert_eq!(report.total_errors(), 3);
+        assert_eq!(report.total_warnings(), 1);
+
+        let by_severity = report.by_severity();
+        let errors = &by_severity[&DiagnosticLevel::Error];
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].0, Path::new("src/button.css"));
+        assert_eq!(errors[2].0, Path::new("src/card.css"));
+        assert_eq!(by_severity[&DiagnosticLevel::Info].len(), 1);
+        assert!(!by_severity.contains_key(&DiagnosticLevel::Hint));
+    }
+
+    #[test]
+    fn test_watch_and_analyze_reports_changes() {
+        let dir =
+            std::env::temp_dir().join(format!("css_in_rust_dev_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("button.css");
+        std::fs::write(&file, ".button { color: red; }\n").unwrap();
+
+        let mut tools = DevTools::new(DevExperienceConfig::default());
+        let reports = tools.watch_and_analyze(vec![dir.clone()]).unwrap();
+
+        std::fs::write(&file, ".button {\n  color: var(primary);\n}\n").unwrap();
+        let report = reports
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .expect("change report");
+
+        assert_eq!(report.path, file);
+        assert_eq!(report.event_type, WatchEventType::Modified);
+        assert!(report
+            .result
+            .diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.code == "E002"));
+        assert!(report.format().contains("button.css"));
+
+        tools.stop_dev_mode();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
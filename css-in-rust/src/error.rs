@@ -0,0 +1,134 @@
+//! Crate-wide error type
+//!
+//! Each subsystem keeps its own error enum; [`Error`] wraps them so application
+//! code can use `?` across subsystems and handle failures in one place.
+
+use std::fmt;
+
+use crate::css_engine::CssError;
+use crate::dev_experience::DevExperienceError;
+use crate::hot_reload::HotReloadError;
+use crate::runtime::InjectionError;
+
+/// Unified error for all css-in-rust subsystems
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::InjectionError;
+/// use css_in_rust::Error;
+///
+/// fn inject() -> Result<(), InjectionError> {
+///     Err(InjectionError::HeadNotFound)
+/// }
+///
+/// fn render() -> Result<(), Error> {
+///     inject()?;
+///     Ok(())
+/// }
+///
+/// let err = render().unwrap_err();
+/// assert_eq!(err.to_string(), "style injection error: Document head not found");
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// CSS processing error
+    Css(CssError),
+    /// Hot reload error
+    HotReload(HotReloadError),
+    /// Developer tooling error
+    DevExperience(DevExperienceError),
+    /// Runtime style injection error
+    Injection(InjectionError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Css(err) => write!(f, "CSS error: {}", err),
+            Error::HotReload(err) => write!(f, "hot reload error: {}", err),
+            Error::DevExperience(err) => write!(f, "dev experience error: {}", err),
+            Error::Injection(err) => write!(f, "style injection error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Css(err) => Some(err),
+            Error::HotReload(err) => Some(err),
+            Error::DevExperience(err) => Some(err),
+            Error::Injection(err) => Some(err),
+        }
+    }
+}
+
+impl From<CssError> for Error {
+    fn from(err: CssError) -> Self {
+        Error::Css(err)
+    }
+}
+
+impl From<HotReloadError> for Error {
+    fn from(err: HotReloadError) -> Self {
+        Error::HotReload(err)
+    }
+}
+
+impl From<DevExperienceError> for Error {
+    fn from(err: DevExperienceError) -> Self {
+        Error::DevExperience(err)
+    }
+}
+
+impl From<InjectionError> for Error {
+    fn from(err: InjectionError) -> Self {
+        Error::Injection(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_subsystem_errors_convert() {
+        let css: Error = CssError::OptimizationError("bad selector".to_string()).into();
+        assert!(matches!(css, Error::Css(_)));
+        assert!(css.to_string().starts_with("CSS error: "));
+        assert!(css.to_string().contains("bad selector"));
+
+        let hot_reload: Error = HotReloadError::NetworkError("reset".to_string()).into();
+        assert!(matches!(hot_reload, Error::HotReload(_)));
+        assert!(hot_reload.to_string().starts_with("hot reload error: "));
+        assert!(hot_reload.to_string().contains("reset"));
+
+        let dev: Error = DevExperienceError::ConfigError("missing port".to_string()).into();
+        assert!(matches!(dev, Error::DevExperience(_)));
+        assert!(dev.to_string().starts_with("dev experience error: "));
+        assert!(dev.to_string().contains("missing port"));
+
+        let injection: Error = InjectionError::DocumentUnavailable.into();
+        assert_eq!(
+            injection.to_string(),
+            "style injection error: Document is not available"
+        );
+        assert!(injection.source().is_some());
+    }
+
+    #[test]
+    fn test_question_mark_across_subsystems() {
+        fn start(fail_reload: bool) -> Result<(), Error> {
+            if fail_reload {
+                Err(HotReloadError::Disabled)?;
+            }
+            Err(InjectionError::HeadNotFound)?;
+            Ok(())
+        }
+
+        assert!(matches!(start(true), Err(Error::HotReload(_))));
+        assert!(matches!(start(false), Err(Error::Injection(_))));
+    }
+}
@@ -9,6 +9,9 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant, SystemTime};
 
+use crate::theme::core::css::variables::CssVariableUtils;
+use crate::theme::theme_types::Theme;
+
 pub mod change_detector;
 pub mod file_watcher;
 pub mod reload_manager;
@@ -21,7 +24,7 @@ pub use reload_manager::{
     BuildResult as ReloadBuildResult, BuildType, ReloadConfig, ReloadEvent, ReloadManager,
 };
 pub use websocket_server::{
-    BuildStatus, LogLevel, WebSocketConfig, WebSocketMessage, WebSocketServer,
+    BuildStatus, LogLevel, WebSocketConfig, WebSocketError, WebSocketMessage, WebSocketServer,
 };
 
 /// 热更新配置
@@ -47,6 +50,8 @@ pub struct HotReloadConfig {
     pub max_retries: usize,
     /// 重试间隔（毫秒）
     pub retry_interval_ms: u64,
+    /// 监控文件的最大字节数，超过时忽略该文件
+    pub max_file_size: u64,
 }
 
 impl Default for HotReloadConfig {
@@ -77,10 +82,85 @@ impl Default for HotReloadConfig {
             enable_css_injection: true,
             max_retries: 3,
             retry_interval_ms: 1000,
+            max_file_size: Self::DEFAULT_MAX_FILE_SIZE,
         }
     }
 }
 
+impl HotReloadConfig {
+    /// 允许的最小 WebSocket 端口（低于此值为特权端口）
+    pub const MIN_WEBSOCKET_PORT: u16 = 1024;
+    /// 允许的最大防抖延迟（毫秒）
+    pub const MAX_DEBOUNCE_DELAY_MS: u64 = 10_000;
+    /// 默认的监控文件最大字节数（10 MB）
+    pub const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+    /// 二进制检测时读取的文件头字节数
+    const BINARY_SNIFF_LEN: usize = 8192;
+
+    /// 验证配置
+    ///
+    /// 检查 WebSocket 端口是否为非特权端口、防抖延迟是否在 1 到 10000 毫秒之间、
+    /// 监控路径是否为目录，返回说明具体问题的 `ConfigError`。
+    ///
+    /// 不存在的监控目录视为可选（例如默认配置中项目未使用的 `assets`），只记录警告；
+    /// 所有监控目录都不存在时才返回错误。
+    pub fn validate(&self) -> Result<(), HotReloadError> {
+        if self.websocket_port < Self::MIN_WEBSOCKET_PORT {
+            return Err(HotReloadError::ConfigError(format!(
+                "websocket_port {} 超出范围，请使用 {}-{} 之间的端口",
+                self.websocket_port,
+                Self::MIN_WEBSOCKET_PORT,
+                u16::MAX
+            )));
+        }
+
+        if self.debounce_delay_ms == 0 || self.debounce_delay_ms > Self::MAX_DEBOUNCE_DELAY_MS {
+            return Err(HotReloadError::ConfigError(format!(
+                "debounce_delay_ms {} 不合理，请使用 1-{} 毫秒",
+                self.debounce_delay_ms,
+                Self::MAX_DEBOUNCE_DELAY_MS
+            )));
+        }
+
+        for dir in &self.watch_directories {
+            if dir.exists() && !dir.is_dir() {
+                return Err(HotReloadError::ConfigError(format!(
+                    "监控路径不是目录: {}",
+                    dir.display()
+                )));
+            }
+        }
+
+        if !self.watch_directories.is_empty() && self.existing_watch_directories().is_empty() {
+            let dirs: Vec<String> = self
+                .watch_directories
+                .iter()
+                .map(|dir| dir.display().to_string())
+                .collect();
+            return Err(HotReloadError::ConfigError(format!(
+                "监控目录不存在: {}",
+                dirs.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 获取存在的监控目录，不存在的目录会被跳过并记录警告
+    fn existing_watch_directories(&self) -> Vec<&PathBuf> {
+        self.watch_directories
+            .iter()
+            .filter(|dir| {
+                let exists = dir.is_dir();
+                if !exists {
+                    log::warn!("监控目录不存在，已跳过: {}", dir.display());
+                }
+                exists
+            })
+            .collect()
+    }
+}
+
 /// 热更新状态
 #[derive(Debug, Clone, PartialEq)]
 pub enum HotReloadStatus {
@@ -224,6 +304,7 @@ pub struct HotReloadManager {
     stats: Arc<Mutex<HotReloadStats>>,
     event_handlers: Vec<Box<dyn Fn(&HotReloadEvent) + Send + Sync>>,
     pending_changes: Arc<Mutex<HashMap<PathBuf, Instant>>>,
+    last_theme_variables: HashMap<String, String>,
 }
 
 impl HotReloadManager {
@@ -241,6 +322,7 @@ impl HotReloadManager {
             stats: Arc::new(Mutex::new(stats)),
             event_handlers: Vec::new(),
             pending_changes: Arc::new(Mutex::new(HashMap::new())),
+            last_theme_variables: HashMap::new(),
             config,
         }
     }
@@ -251,11 +333,13 @@ impl HotReloadManager {
             return Err(HotReloadError::Disabled);
         }
 
+        self.config.validate()?;
+
         *self.status.lock().unwrap() = HotReloadStatus::Starting;
 
         // 启动文件监控
         let mut file_watcher = FileWatcher::new();
-        for dir in &self.config.watch_directories {
+        for dir in self.config.existing_watch_directories() {
             file_watcher
                 .watch_directory(dir.clone())
                 .map_err(|e| HotReloadError::FileWatchFailed(e.to_string()))?;
@@ -265,10 +349,10 @@ impl HotReloadManager {
         let status = self.status.clone();
         let stats = self.stats.clone();
         let pending_changes = self.pending_changes.clone();
-        let debounce_delay = Duration::from_millis(self.config.debounce_delay_ms);
+        let config = self.config.clone();
 
         file_watcher.set_event_handler(Box::new(move |event: &WatchEvent| {
-            Self::handle_file_event(&*event, &status, &stats, &pending_changes, debounce_delay);
+            Self::handle_file_event(&*event, &config, &status, &stats, &pending_changes);
         }));
 
         self.file_watcher = Some(file_watcher);
@@ -374,14 +458,8 @@ impl HotReloadManager {
             return Err(HotReloadError::CssInjectionDisabled);
         }
 
-        if let Some(server) = &mut self.websocket_server {
-            let _ = server
-                .broadcast(WebSocketMessage::CssHotReload {
-                    files: vec!["gloable.css".to_string()],
-                    css_content: css_content.clone(),
-                    timestamp: SystemTime::now(),
-                })
-                .map_err(|e| HotReloadError::NetworkError(e.to_string()));
+        if let Some(server) = &self.websocket_server {
+            broadcast_css(server, &css_content)?;
 
             self.stats.lock().unwrap().record_css_injection();
 
@@ -394,6 +472,17 @@ impl HotReloadManager {
         Ok(())
     }
 
+    /// 推送主题变量热更新
+    ///
+    /// 只广播与上次推送相比新增、变化或删除的CSS变量，浏览器无需整页刷新即可预览主题切换。
+    pub fn push_theme_update(&mut self, theme: &Theme) -> Result<(), HotReloadError> {
+        if let Some(server) = &self.websocket_server {
+            broadcast_theme_update(server, &mut self.last_theme_variables, theme)?;
+        }
+
+        Ok(())
+    }
+
     /// 刷新浏览器
     pub fn refresh_browser(&mut self) {
         if let Some(server) = &mut self.websocket_server {
@@ -439,18 +528,24 @@ impl HotReloadManager {
     }
 
     /// 处理文件事件
+    ///
+    /// 不满足 [`HotReloadManager::should_watch_file`] 条件的文件（如过大或二进制文件）会被跳过。
     fn handle_file_event(
         event: &WatchEvent,
+        config: &HotReloadConfig,
         status: &Arc<Mutex<HotReloadStatus>>,
         stats: &Arc<Mutex<HotReloadStats>>,
         pending_changes: &Arc<Mutex<HashMap<PathBuf, Instant>>>,
-        _debounce_delay: Duration,
     ) {
         // 检查是否暂停
         if matches!(*status.lock().unwrap(), HotReloadStatus::Paused) {
             return;
         }
 
+        if !Self::is_watchable(config, &event.path) {
+            return;
+        }
+
         // 记录待处理的变更
         {
             let mut pending = pending_changes.lock().unwrap();
@@ -507,11 +602,19 @@ impl HotReloadManager {
     }
 
     /// 检查文件是否应该被监控
+    ///
+    /// 除扩展名和忽略模式外，还会跳过超过 `max_file_size` 的文件以及文件头含有空字节的二进制文件。
+    /// 无法读取元数据的路径（例如已删除的文件）不做大小和内容检查。
     pub fn should_watch_file(&self, path: &Path) -> bool {
+        Self::is_watchable(&self.config, path)
+    }
+
+    /// 按配置检查文件是否应该被监控
+    fn is_watchable(config: &HotReloadConfig, path: &Path) -> bool {
         // 检查扩展名
         if let Some(ext) = path.extension() {
             if let Some(ext_str) = ext.to_str() {
-                if !self.config.watch_extensions.contains(&ext_str.to_string()) {
+                if !config.watch_extensions.contains(&ext_str.to_string()) {
                     return false;
                 }
             }
@@ -519,15 +622,44 @@ impl HotReloadManager {
 
         // 检查忽略模式
         let path_str = path.to_string_lossy();
-        for pattern in &self.config.ignore_patterns {
+        for pattern in &config.ignore_patterns {
             if Self::matches_pattern(&path_str, pattern) {
                 return false;
             }
         }
 
+        // 检查文件大小和内容
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.is_file() {
+                if metadata.len() > config.max_file_size {
+                    return false;
+                }
+                if Self::looks_binary(path) {
+                    return false;
+                }
+            }
+        }
+
         true
     }
 
+    /// 文件头含有空字节时视为二进制文件
+    fn looks_binary(path: &Path) -> bool {
+        use std::io::Read;
+
+        let Ok(file) = fs::File::open(path) else {
+            return false;
+        };
+        let mut head = Vec::with_capacity(HotReloadConfig::BINARY_SNIFF_LEN);
+        match file
+            .take(HotReloadConfig::BINARY_SNIFF_LEN as u64)
+            .read_to_end(&mut head)
+        {
+            Ok(_) => head.contains(&0),
+            Err(_) => false,
+        }
+    }
+
     /// 检查路径是否匹配模式
     fn matches_pattern(path: &str, pattern: &str) -> bool {
         // 简单的glob模式匹配
@@ -592,6 +724,74 @@ impl std::fmt::Display for HotReloadError {
 
 impl std::error::Error for HotReloadError {}
 
+/// 可广播热更新消息的目标
+trait MessageBroadcaster {
+    /// 广播消息，返回成功发送的客户端数量
+    fn broadcast_message(&self, message: WebSocketMessage) -> Result<usize, WebSocketError>;
+}
+
+impl MessageBroadcaster for WebSocketServer {
+    fn broadcast_message(&self, message: WebSocketMessage) -> Result<usize, WebSocketError> {
+        self.broadcast(message)
+    }
+}
+
+/// 广播CSS热注入消息，广播失败时返回网络错误
+fn broadcast_css<B: MessageBroadcaster + ?Sized>(
+    broadcaster: &B,
+    css_content: &str,
+) -> Result<usize, HotReloadError> {
+    broadcaster
+        .broadcast_message(WebSocketMessage::CssHotReload {
+            files: vec!["gloable.css".to_string()],
+            css_content: css_content.to_string(),
+            timestamp: SystemTime::now(),
+        })
+        .map_err(|e| HotReloadError::NetworkError(e.to_string()))
+}
+
+/// 广播主题变量中相对 `last_variables` 新增、变化或删除的部分
+///
+/// 删除的变量名按字母顺序放在 `removed` 中，客户端据此移除对应变量。
+/// 没有变化时不发送消息并返回 0；广播成功后更新 `last_variables`。
+fn broadcast_theme_update<B: MessageBroadcaster + ?Sized>(
+    broadcaster: &B,
+    last_variables: &mut HashMap<String, String>,
+    theme: &Theme,
+) -> Result<usize, HotReloadError> {
+    let current: HashMap<String, String> =
+        CssVariableUtils::parse_css_variables(&theme.clone().to_css_variables())
+            .into_iter()
+            .collect();
+
+    let changed: HashMap<String, String> = current
+        .iter()
+        .filter(|(name, value)| last_variables.get(*name) != Some(*value))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+
+    let mut removed: Vec<String> = last_variables
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .cloned()
+        .collect();
+    removed.sort();
+
+    if changed.is_empty() && removed.is_empty() {
+        return Ok(0);
+    }
+
+    let sent = broadcaster
+        .broadcast_message(WebSocketMessage::ThemeUpdate {
+            variables: changed,
+            removed,
+        })
+        .map_err(|e| HotReloadError::NetworkError(e.to_string()))?;
+    *last_variables = current;
+
+    Ok(sent)
+}
+
 /// 热更新工具
 pub struct HotReloadTools {
     manager: HotReloadManager,
@@ -729,3 +929,253 @@ CSS注入: {} 次
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 总是广播失败的服务器
+    struct FailingServer;
+
+    impl MessageBroadcaster for FailingServer {
+        fn broadcast_message(&self, _message: WebSocketMessage) -> Result<usize, WebSocketError> {
+            Err(WebSocketError::NetworkError("connection reset".to_string()))
+        }
+    }
+
+    /// 记录广播消息的服务器
+    #[derive(Default)]
+    struct RecordingServer {
+        messages: Mutex<Vec<WebSocketMessage>>,
+    }
+
+    impl MessageBroadcaster for RecordingServer {
+        fn broadcast_message(&self, message: WebSocketMessage) -> Result<usize, WebSocketError> {
+            self.messages.lock().unwrap().push(message);
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_push_theme_update_broadcasts_changed_variables() {
+        let server = RecordingServer::default();
+        let mut last = HashMap::new();
+        let mut theme = Theme::new("dev").with_custom_variable("--radius", "6px");
+        theme.add_color("primary", "#1677ff");
+
+        assert_eq!(
+            broadcast_theme_update(&server, &mut last, &theme).unwrap(),
+            1
+        );
+        // 未变化时不重复广播
+        assert_eq!(
+            broadcast_theme_update(&server, &mut last, &theme).unwrap(),
+            0
+        );
+
+        theme.add_color("primary", "#ff4d4f");
+        broadcast_theme_update(&server, &mut last, &theme).unwrap();
+
+        // 删除的变量随更新一起广播
+        let mut without_radius = Theme::new("dev");
+        without_radius.add_color("primary", "#ff4d4f");
+        assert_eq!(
+            broadcast_theme_update(&server, &mut last, &without_radius).unwrap(),
+            1
+        );
+        assert!(!last.contains_key("--radius"));
+
+        let messages = server.messages.lock().unwrap();
+        assert_eq!(messages.len(), 3);
+        match &messages[0] {
+            WebSocketMessage::ThemeUpdate { variables, removed } => {
+                assert!(removed.is_empty());
+                assert_eq!(
+                    variables.get("--color-primary").map(String::as_str),
+                    Some("#1677ff")
+                );
+                assert_eq!(variables.get("--radius").map(String::as_str), Some("6px"));
+            }
+            other => panic!("expected ThemeUpdate, got {:?}", other),
+        }
+        match &messages[1] {
+            WebSocketMessage::ThemeUpdate { variables, .. } => {
+                assert_eq!(
+                    variables.get("--color-primary").map(String::as_str),
+                    Some("#ff4d4f")
+                );
+                assert!(!variables.contains_key("--radius"));
+            }
+            other => panic!("expected ThemeUpdate, got {:?}", other),
+        }
+        match &messages[2] {
+            WebSocketMessage::ThemeUpdate { variables, removed } => {
+                assert!(variables.is_empty());
+                assert_eq!(removed, &["--radius"]);
+            }
+            other => panic!("expected ThemeUpdate, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_broadcast_failure_surfaces_as_network_error() {
+        match broadcast_css(&FailingServer, ".a { color: red; }") {
+            Err(HotReloadError::NetworkError(msg)) => {
+                assert!(msg.contains("connection reset"), "{}", msg)
+            }
+            other => panic!("expected NetworkError, got {:?}", other),
+        }
+
+        let server = WebSocketServer::new(WebSocketConfig::default());
+        assert_eq!(broadcast_css(&server, ".a { color: red; }").unwrap(), 0);
+    }
+
+    fn valid_config() -> HotReloadConfig {
+        HotReloadConfig {
+            watch_directories: vec![std::env::temp_dir()],
+            ..Default::default()
+        }
+    }
+
+    /// 在临时目录中写入测试文件
+    fn write_temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("css-in-rust-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_should_watch_file_respects_size_and_binary_content() {
+        let manager = HotReloadManager::new(HotReloadConfig {
+            max_file_size: 16,
+            ..valid_config()
+        });
+
+        let empty = write_temp_file("empty.css", b"");
+        assert!(manager.should_watch_file(&empty));
+
+        let small = write_temp_file("small.css", b".a{color:red}");
+        assert!(manager.should_watch_file(&small));
+
+        let large = write_temp_file("large.css", &[b'a'; 17]);
+        assert!(!manager.should_watch_file(&large));
+
+        let binary = write_temp_file("binary.css", b".a{\0}");
+        assert!(!manager.should_watch_file(&binary));
+
+        // 已删除的文件仍按扩展名判断
+        let missing = empty.with_file_name("missing.css");
+        assert!(manager.should_watch_file(&missing));
+
+        for path in [empty, small, large, binary] {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn test_file_events_skip_oversized_and_binary_files() {
+        let config = HotReloadConfig {
+            max_file_size: 16,
+            ..valid_config()
+        };
+        let status = Arc::new(Mutex::new(HotReloadStatus::Running));
+        let stats = Arc::new(Mutex::new(HotReloadStats::new()));
+        let pending_changes = Arc::new(Mutex::new(HashMap::new()));
+
+        let large = write_temp_file("event-large.css", &[b'a'; 17]);
+        let binary = write_temp_file("event-binary.css", b".a{\0}");
+        let small = write_temp_file("event-small.css", b".a{color:red}");
+
+        for path in [&large, &binary, &small] {
+            let event = WatchEvent::new(WatchEventType::Modified, path.clone());
+            HotReloadManager::handle_file_event(&event, &config, &status, &stats, &pending_changes);
+        }
+
+        let pending = pending_changes.lock().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert!(pending.contains_key(&small));
+        assert_eq!(stats.lock().unwrap().total_file_changes, 1);
+
+        for path in [large, binary, small] {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_config() {
+        assert!(valid_config().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_missing_directory() {
+        let config = HotReloadConfig {
+            watch_directories: vec![PathBuf::from("/nonexistent/css-in-rust-watch")],
+            ..valid_config()
+        };
+
+        match config.validate() {
+            Err(HotReloadError::ConfigError(msg)) => {
+                assert!(msg.contains("/nonexistent/css-in-rust-watch"), "{}", msg);
+            }
+            other => panic!("expected ConfigError, got {:?}", other),
+        }
+
+        // start() 在启动任何组件前就返回配置错误
+        let mut manager = HotReloadManager::new(config);
+        assert!(matches!(
+            manager.start(),
+            Err(HotReloadError::ConfigError(_))
+        ));
+        assert_eq!(manager.get_status(), HotReloadStatus::Stopped);
+    }
+
+    #[test]
+    fn test_validate_skips_missing_optional_directory() {
+        let missing = PathBuf::from("/nonexistent/css-in-rust-assets");
+        let config = HotReloadConfig {
+            watch_directories: vec![std::env::temp_dir(), missing.clone()],
+            ..valid_config()
+        };
+
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.existing_watch_directories(),
+            vec![&std::env::temp_dir()]
+        );
+
+        // 监控路径存在但不是目录时仍然报错
+        let file = write_temp_file("not-a-dir.css", b"");
+        let config = HotReloadConfig {
+            watch_directories: vec![std::env::temp_dir(), file.clone()],
+            ..valid_config()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(HotReloadError::ConfigError(_))
+        ));
+        let _ = fs::remove_file(file);
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_port_and_debounce() {
+        let config = HotReloadConfig {
+            websocket_port: 80,
+            ..valid_config()
+        };
+        match config.validate() {
+            Err(HotReloadError::ConfigError(msg)) => assert!(msg.contains("80"), "{}", msg),
+            other => panic!("expected ConfigError, got {:?}", other),
+        }
+
+        let config = HotReloadConfig {
+            debounce_delay_ms: 0,
+            ..valid_config()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(HotReloadError::ConfigError(_))
+        ));
+    }
+}
@@ -61,6 +61,12 @@ pub enum WebSocketMessage {
         css_content: String,
         timestamp: SystemTime,
     },
+    /// 主题变量热更新，键为带 `--` 前缀的CSS变量名；`removed` 为已删除的变量名
+    ThemeUpdate {
+        variables: HashMap<String, String>,
+        #[serde(default)]
+        removed: Vec<String>,
+    },
     /// JavaScript 重新加载
     JsReload { files: Vec<String>, timestamp: u64 },
     /// 页面完全重新加载
@@ -54,9 +54,11 @@ pub mod animation;
 pub mod build_tools;
 pub mod css_engine;
 pub mod dev_experience;
+pub mod error;
 pub mod hot_reload;
 pub mod macros;
 pub mod performance;
+pub mod playground;
 pub mod runtime;
 pub mod theme;
 pub mod theme_bridge;
@@ -66,7 +68,9 @@ pub mod variants;
 pub use animation::*;
 pub use build_tools::*;
 pub use css_engine::*;
+pub use error::Error;
 pub use hot_reload::*;
+pub use playground::{process_css_playground, PlaygroundResult};
 pub use runtime::*;
 pub use theme::*;
 pub use theme_bridge::*;
@@ -90,8 +94,8 @@ pub use variants::*;
 // Re-export macros when proc-macro feature is enabled
 #[cfg(feature = "proc-macro")]
 pub use css_in_rust_macros::{
-    css, css_if, styled_component as proc_styled_component, styled_component_with_props,
-    themed_style,
+    css, css_global, css_if, css_with_meta, styled_component as proc_styled_component,
+    styled_component_with_props, themed_style,
 };
 
 // Provide fallback implementations when proc-macro feature is disabled
@@ -119,6 +123,15 @@ pub mod fallback_macros {
         }};
     }
 
+    /// Fallback css_global! macro
+    #[macro_export]
+    macro_rules! css_global {
+        ($css:expr) => {{
+            eprintln!("CSS_GLOBAL! macro is not available without the 'proc-macro' feature");
+            String::from($css)
+        }};
+    }
+
     /// Fallback css_class! macro
     #[macro_export]
     macro_rules! css_class {
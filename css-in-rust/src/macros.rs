@@ -271,8 +271,11 @@ fn parse_css_syntax(input: TokenStream) -> syn::Result<String> {
     Ok(result.trim().to_string())
 }
 
-/// Calculate hash for CSS content using a simple hash function
-#[cfg(all(feature = "proc-macro", feature = "optimizer"))]
+/// Calculate a deterministic SHA-256 hash for CSS content
+///
+/// The hash must be stable across Rust versions and platforms so that
+/// server-rendered and client class names agree.
+#[cfg(feature = "proc-macro")]
 fn calculate_css_hash(css: &str) -> String {
     use sha2::{Digest, Sha256};
 
@@ -281,17 +284,6 @@ fn calculate_css_hash(css: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
-/// Calculate hash for CSS content using a simple hash function (fallback)
-#[cfg(all(feature = "proc-macro", not(feature = "optimizer")))]
-fn calculate_css_hash(css: &str) -> String {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-
-    let mut hasher = DefaultHasher::new();
-    css.hash(&mut hasher);
-    format!("{:x}", hasher.finish())
-}
-
 /// Validate CSS syntax
 #[cfg(feature = "proc-macro")]
 fn validate_css_syntax(css: &str) -> Result<(), String> {
@@ -472,4 +464,13 @@ mod tests {
         assert!(code.contains("CSS_INJECTED"));
         assert!(code.contains("color: red; font-size: 16px;"));
     }
+
+    #[test]
+    fn test_css_id_matches_runtime_class_name() {
+        let css = "color: red; font-size: 16px;";
+        let css_id = format!("css-{}", &calculate_css_hash(css)[..8]);
+
+        assert_eq!(css_id, format!("css-{}", &calculate_css_hash(css)[..8]));
+        assert_eq!(css_id, crate::runtime::generate_class_name(css));
+    }
 }
@@ -0,0 +1,140 @@
+//! 基准测试模块
+//!
+//! 提供可在应用中直接调用的样式注入与 CSS 优化基准，输出可复现的耗时数据
+
+use crate::css_engine::optimizer::{CssOptimizer, OptimizationError};
+use crate::runtime::{InjectionError, StyleManager, StyleManagerConfig};
+use std::time::{Duration, Instant};
+
+/// 基准测试报告
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// 执行的操作次数
+    pub operations: usize,
+    /// 总耗时
+    pub total_time: Duration,
+    /// 平均每次操作耗时
+    pub per_op_time: Duration,
+    /// 去重命中次数
+    pub dedup_hits: usize,
+}
+
+impl BenchReport {
+    fn new(operations: usize, total_time: Duration, dedup_hits: usize) -> Self {
+        let per_op_time = if operations == 0 {
+            Duration::ZERO
+        } else {
+            total_time.div_f64(operations as f64)
+        };
+
+        Self {
+            operations,
+            total_time,
+            per_op_time,
+            dedup_hits,
+        }
+    }
+
+    /// 每秒操作次数
+    pub fn ops_per_second(&self) -> f64 {
+        let seconds = self.total_time.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.operations as f64 / seconds
+    }
+}
+
+/// 样式注入吞吐量基准
+///
+/// 通过运行时 `StyleManager` 注入 `count` 条不同的样式，随后再次注入相同的样式以模拟重新渲染，
+/// 因此共执行 `count * 2` 次操作，第二轮全部命中去重。基准结束后会移除注入的样式。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::performance::bench_injection;
+///
+/// let report = bench_injection(100).unwrap();
+/// assert_eq!(report.operations, 200);
+/// assert_eq!(report.dedup_hits, 100);
+/// ```
+pub fn bench_injection(count: usize) -> Result<BenchReport, InjectionError> {
+    let default_config = StyleManagerConfig::default();
+    let manager = StyleManager::with_config(StyleManagerConfig {
+        max_cached_styles: count.max(default_config.max_cached_styles),
+        ..default_config
+    });
+
+    let styles: Vec<(String, String)> = (0..count)
+        .map(|index| {
+            let class_name = format!("bench-injection-{}", index);
+            let css = format!(".{} {{ margin: {}px; }}", class_name, index);
+            (class_name, css)
+        })
+        .collect();
+
+    let start = Instant::now();
+    for _ in 0..2 {
+        for (class_name, css) in &styles {
+            manager.inject_style(css, class_name)?;
+        }
+    }
+    let total_time = start.elapsed();
+
+    let stats = manager.stats();
+    for (class_name, _) in &styles {
+        manager.remove_style(class_name)?;
+    }
+
+    Ok(BenchReport::new(
+        stats.total_inject_calls,
+        total_time,
+        stats.dedup_hits,
+    ))
+}
+
+/// CSS 优化基准
+///
+/// 使用默认配置的 `CssOptimizer` 对同一段 CSS 重复优化 `iters` 次。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::performance::bench_optimization;
+///
+/// let report = bench_optimization(".btn { color: red; }", 10).unwrap();
+/// assert_eq!(report.operations, 10);
+/// ```
+pub fn bench_optimization(css: &str, iters: usize) -> Result<BenchReport, OptimizationError> {
+    let optimizer = CssOptimizer::new();
+
+    let start = Instant::now();
+    for _ in 0..iters {
+        optimizer.optimize_string(css)?;
+    }
+    let total_time = start.elapsed();
+
+    Ok(BenchReport::new(iters, total_time, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_reports_are_sane() {
+        let report = bench_injection(50).unwrap();
+        assert_eq!(report.operations, 100);
+        assert_eq!(report.dedup_hits, 50);
+        assert!(report.total_time.as_secs_f64().is_finite());
+        assert!(report.per_op_time <= report.total_time);
+        assert!(report.ops_per_second().is_finite());
+
+        let report = bench_optimization(".card {\n  padding: 8px;\n}\n", 20).unwrap();
+        assert_eq!(report.operations, 20);
+        assert_eq!(report.dedup_hits, 0);
+        assert!(report.per_op_time.as_secs_f64().is_finite());
+        assert!(report.per_op_time <= report.total_time);
+    }
+}
@@ -0,0 +1,70 @@
+//! 内存统计模块
+//!
+//! 汇总主题缓存、运行时样式表和变体注册表的内存占用估算
+
+use crate::runtime::provider::injected_styles_memory;
+use crate::theme::core::cache::{CacheManager, MemoryUsage};
+use crate::variants::global_variant_manager;
+
+/// 内存使用报告
+///
+/// 各子系统的估算字节数，用于观察注入样式和缓存的内存增长。
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReport {
+    /// 全局主题缓存管理器的内存使用
+    pub cache: MemoryUsage,
+    /// 运行时已注入样式占用的字节数
+    pub runtime_styles: usize,
+    /// 变体注册表占用的字节数
+    pub variant_registry: usize,
+}
+
+impl MemoryReport {
+    /// 所有子系统的总字节数
+    pub fn total_bytes(&self) -> usize {
+        self.cache.total_cache_size + self.runtime_styles + self.variant_registry
+    }
+}
+
+/// 生成当前进程的内存使用报告
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::performance::memory_report;
+///
+/// let report = memory_report();
+/// println!("运行时样式: {} 字节", report.runtime_styles);
+/// println!("总计: {} 字节", report.total_bytes());
+/// ```
+pub fn memory_report() -> MemoryReport {
+    let cache = CacheManager::get_global()
+        .map(|manager| manager.get_memory_usage())
+        .unwrap_or_default();
+
+    MemoryReport {
+        cache,
+        runtime_styles: injected_styles_memory(),
+        variant_registry: global_variant_manager().estimated_memory(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::provider;
+
+    #[test]
+    fn test_runtime_style_memory_grows_with_injection() {
+        let before = memory_report();
+
+        let css = format!(".memory-report-probe {{ {} }}", "color: red; ".repeat(512));
+        provider::inject_style(&css, "memory-report-probe");
+
+        let after = memory_report();
+        assert!(after.runtime_styles >= before.runtime_styles + css.len());
+        assert!(after.total_bytes() > before.total_bytes());
+
+        provider::remove_style("memory-report-probe").unwrap();
+    }
+}
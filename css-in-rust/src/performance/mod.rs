@@ -2,14 +2,18 @@
 //!
 //! 提供编译时缓存、增量编译和运行时性能优化功能
 
+pub mod bench;
 pub mod cache;
 pub mod incremental;
+pub mod memory;
 pub mod metrics;
 pub mod profiler;
 
 // 重新导出主要类型
+pub use bench::{bench_injection, bench_optimization, BenchReport};
 pub use cache::{CacheConfig, CacheEntry, CacheManager};
 pub use incremental::{CompilationState, IncrementalCompiler};
+pub use memory::{memory_report, MemoryReport};
 pub use metrics::{MetricsCollector, PerformanceMetrics};
 pub use profiler::{PerformanceProfiler, ProfilerConfig, ProfilingSession};
 
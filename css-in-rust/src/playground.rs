@@ -0,0 +1,137 @@
+//! Runtime CSS playground
+//!
+//! This module exposes a single entry point that runs a raw CSS string through
+//! the processing pipeline (diagnostics, parsing and optimization) at runtime.
+//! Unlike the `css!` macro it does not require compile-time processing, so it
+//! can be called from wasm, e.g. to power a live playground in the docs.
+
+use crate::css_engine::CssParser;
+use crate::dev_experience::diagnostics::{CodePosition, CodeRange, DiagnosticType};
+use crate::dev_experience::{Diagnostic, DiagnosticLevel, DiagnosticManager};
+use crate::runtime::generate_class_name;
+use crate::theme::core::optimize::{OptimizeConfig, StyleOptimizer};
+
+/// File name reported in playground diagnostics
+const PLAYGROUND_FILE: &str = "playground.css";
+
+/// Result of processing CSS in the playground
+#[derive(Debug, Clone)]
+pub struct PlaygroundResult {
+    /// Class name generated for the input, stable for identical input
+    pub class_name: String,
+    /// Processed CSS, empty when the input could not be parsed
+    pub processed_css: String,
+    /// Diagnostics reported for the input
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl PlaygroundResult {
+    /// Returns whether any diagnostic is an error
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.level == DiagnosticLevel::Error)
+    }
+}
+
+/// Processes a raw CSS string through the runtime pipeline
+///
+/// Plain declarations (`color: red; padding: 4px;`) are wrapped in a rule for
+/// the generated class name, while full stylesheets are processed as-is. The
+/// input is checked by the default diagnostic rules, validated by
+/// [`CssParser`] and optimized by [`StyleOptimizer`]. Parse failures are
+/// reported as error diagnostics instead of being returned as an `Err`, so the
+/// caller can always render the result.
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::process_css_playground;
+///
+/// let result = process_css_playground("color: red; padding: 4px;");
+///
+/// assert!(result.class_name.starts_with("css-"));
+/// assert!(result.processed_css.contains(&result.class_name));
+/// assert!(!result.has_errors());
+/// ```
+pub fn process_css_playground(css: &str) -> PlaygroundResult {
+    let class_name = generate_class_name(css.trim());
+
+    let source = if css.contains('{') {
+        css.to_string()
+    } else {
+        format!(".{} {{ {} }}", class_name, css.trim())
+    };
+
+    let mut diagnostics = DiagnosticManager::new().analyze_code(&source, PLAYGROUND_FILE);
+
+    let processed_css = match CssParser::new().parse(&source) {
+        Ok(stylesheet) => {
+            // The playground has no markup, so every class counts as used
+            let optimizer = StyleOptimizer::new(OptimizeConfig {
+                remove_unused: false,
+                ..OptimizeConfig::default()
+            });
+            let parsed = if stylesheet.optimized.is_empty() {
+                &stylesheet.source
+            } else {
+                &stylesheet.optimized
+            };
+            optimizer.optimize(parsed)
+        }
+        Err(err) => {
+            let (line, column) = err.location().unwrap_or((1, 1));
+            diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticLevel::Error,
+                    DiagnosticType::SyntaxError,
+                    "E000".to_string(),
+                    err.to_string(),
+                    CodeRange::point(CodePosition::new(line, column, 0)),
+                    PLAYGROUND_FILE.to_string(),
+                )
+                .with_description("The CSS could not be parsed".to_string()),
+            );
+            String::new()
+        }
+    };
+
+    PlaygroundResult {
+        class_name,
+        processed_css,
+        diagnostics,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_css_has_no_errors() {
+        let result = process_css_playground("color: red; padding: 4px;");
+
+        assert!(!result.has_errors());
+        assert!(result
+            .processed_css
+            .contains(&format!(".{}", result.class_name)));
+        assert!(result.processed_css.contains("color: red;"));
+        assert_eq!(
+            result.class_name,
+            process_css_playground("color: red; padding: 4px;").class_name
+        );
+        assert_eq!(
+            result.class_name,
+            generate_class_name("color: red; padding: 4px;")
+        );
+    }
+
+    #[test]
+    fn test_invalid_css_reports_diagnostics() {
+        let result = process_css_playground(".broken { color: red;");
+
+        assert!(result.has_errors());
+        assert!(!result.diagnostics.is_empty());
+        assert!(result.processed_css.is_empty());
+    }
+}
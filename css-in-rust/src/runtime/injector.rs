@@ -3,14 +3,27 @@
 //! This module provides the core style injection capabilities for different
 //! target environments (web, SSR, etc.).
 
+use indexmap::IndexMap;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex, RwLock};
 
+/// 已注入样式索引的分片数量
+const INJECTED_INDEX_SHARDS: usize = 16;
+
 #[cfg(not(target_arch = "wasm32"))]
 lazy_static::lazy_static! {
-    static ref SERVER_STYLES: Arc<RwLock<HashMap<String, String>>> = Arc::new(RwLock::new(HashMap::new()));
+    /// 服务端收集的样式（类名, CSS 声明），按首次注入顺序排列
+    static ref SERVER_STYLES: Arc<RwLock<Vec<(String, String)>>> = Arc::new(RwLock::new(Vec::new()));
+    /// 服务端收集的全局样式（样式 ID, 完整 CSS），输出时不包裹类名选择器
+    static ref SERVER_GLOBAL_STYLES: Arc<RwLock<Vec<(String, String)>>> = Arc::new(RwLock::new(Vec::new()));
 }
 
+/// 串行化会清空全局服务端样式缓冲区的测试
+#[cfg(all(test, not(target_arch = "wasm32")))]
+pub(crate) static SERVER_STYLES_TEST_LOCK: Mutex<()> = Mutex::new(());
+
 /// Style injection error
 ///
 /// 表示在样式注入过程中可能发生的各种错误类型。
@@ -28,8 +41,12 @@ lazy_static::lazy_static! {
 ///     match err {
 ///         InjectionError::InjectionFailed(msg) => println!("注入失败: {}", msg),
 ///         InjectionError::RemovalFailed(msg) => println!("移除失败: {}", msg),
+///         InjectionError::ClearFailed(msg) => println!("清除失败: {}", msg),
 ///         InjectionError::PlatformNotSupported(msg) => println!("平台不支持: {}", msg),
 ///         InjectionError::DomOperationFailed(msg) => println!("DOM操作失败: {}", msg),
+///         InjectionError::DocumentUnavailable => println!("document 不可用，稍后重试"),
+///         InjectionError::HeadNotFound => println!("head 尚未就绪，稍后重试"),
+///         InjectionError::CssomRejected(msg) => println!("CSSOM 拒绝插入: {}", msg),
 ///     }
 /// }
 /// ```
@@ -45,6 +62,12 @@ pub enum InjectionError {
     DomOperationFailed(String),
     /// Platform not supported
     PlatformNotSupported(String),
+    /// `window` or `document` is not available (web only)
+    DocumentUnavailable,
+    /// `document.head` is not ready yet (web only)
+    HeadNotFound,
+    /// The browser rejected inserting the style into the CSSOM (web only)
+    CssomRejected(String),
 }
 
 impl std::fmt::Display for InjectionError {
@@ -57,6 +80,9 @@ impl std::fmt::Display for InjectionError {
             InjectionError::PlatformNotSupported(msg) => {
                 write!(f, "Platform not supported: {}", msg)
             }
+            InjectionError::DocumentUnavailable => write!(f, "Document is not available"),
+            InjectionError::HeadNotFound => write!(f, "Document head not found"),
+            InjectionError::CssomRejected(msg) => write!(f, "CSSOM rejected style: {}", msg),
         }
     }
 }
@@ -111,10 +137,15 @@ pub enum InjectionEnvironment {
 /// injector.inject_style(css, class_name).unwrap();
 /// ```
 pub struct StyleInjector {
-    /// 已注入的样式集合
-    injected_styles: Arc<Mutex<HashMap<String, String>>>,
+    /// 已注入的样式集合，按首次注入顺序排列
+    injected_styles: Arc<Mutex<IndexMap<String, String>>>,
+    /// 已注入样式的分片索引，用于重复注入的快速路径
+    injected_index: Arc<InjectedIndex>,
     /// 注入环境
     environment: InjectionEnvironment,
+    /// DOM 就绪前缓存的待注入样式
+    #[cfg(target_arch = "wasm32")]
+    pending_styles: Arc<Mutex<Vec<(String, String)>>>,
 }
 
 impl StyleInjector {
@@ -141,10 +172,7 @@ impl StyleInjector {
         #[cfg(not(target_arch = "wasm32"))]
         let environment = InjectionEnvironment::Server;
 
-        Self {
-            injected_styles: Arc::new(Mutex::new(HashMap::new())),
-            environment,
-        }
+        Self::with_environment(environment)
     }
 
     /// 创建一个用于服务端渲染的样式注入器
@@ -163,10 +191,7 @@ impl StyleInjector {
     /// let ssr_injector = StyleInjector::new_ssr();
     /// ```
     pub fn new_ssr() -> Self {
-        Self {
-            injected_styles: Arc::new(Mutex::new(HashMap::new())),
-            environment: InjectionEnvironment::Server,
-        }
+        Self::with_environment(InjectionEnvironment::Server)
     }
 
     /// 创建一个无操作的样式注入器
@@ -185,10 +210,7 @@ impl StyleInjector {
     /// let noop_injector = StyleInjector::new_noop();
     /// ```
     pub fn new_noop() -> Self {
-        Self {
-            injected_styles: Arc::new(Mutex::new(HashMap::new())),
-            environment: InjectionEnvironment::Noop,
-        }
+        Self::with_environment(InjectionEnvironment::Noop)
     }
 
     /// 创建一个同构应用的样式注入器
@@ -207,9 +229,17 @@ impl StyleInjector {
     /// let isomorphic_injector = StyleInjector::new_isomorphic();
     /// ```
     pub fn new_isomorphic() -> Self {
+        Self::with_environment(InjectionEnvironment::Isomorphic)
+    }
+
+    /// 使用指定环境创建样式注入器
+    fn with_environment(environment: InjectionEnvironment) -> Self {
         Self {
-            injected_styles: Arc::new(Mutex::new(HashMap::new())),
-            environment: InjectionEnvironment::Isomorphic,
+            injected_styles: Arc::new(Mutex::new(IndexMap::new())),
+            injected_index: Arc::new(InjectedIndex::new()),
+            environment,
+            #[cfg(target_arch = "wasm32")]
+            pending_styles: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -277,15 +307,44 @@ impl StyleInjector {
     /// injector.inject_style(css, class_name).unwrap();
     /// ```
     pub fn inject_style(&self, css: &str, class_name: &str) -> Result<(), InjectionError> {
-        // 记录样式
-        {
-            let mut styles = self.injected_styles.lock().map_err(|e| {
-                InjectionError::InjectionFailed(format!("Failed to lock styles: {}", e))
-            })?;
-            styles.insert(class_name.to_string(), css.to_string());
+        self.inject_style_with(css, class_name, |css, class_name| {
+            self.inject_into_environment(css, class_name)
+        })
+    }
+
+    /// 记录样式并通过 `inject` 执行实际注入
+    ///
+    /// 并发注入同一样式时只有一个调用会执行 `inject`；注入失败时撤销记录，
+    /// 之后的调用可以重试。
+    fn inject_style_with(
+        &self,
+        css: &str,
+        class_name: &str,
+        inject: impl FnOnce(&str, &str) -> Result<(), InjectionError>,
+    ) -> Result<(), InjectionError> {
+        let css_hash = style_hash(css);
+
+        // 快速路径：相同样式已注入时只获取索引分片的读锁
+        if self.injected_index.contains(class_name, css_hash) {
+            return Ok(());
         }
 
-        // 根据环境选择注入策略
+        // 记录样式，并发注入同一样式时只有一个调用会继续执行注入
+        let RecordOutcome::Recorded { previous } = self.record_style(css, class_name, css_hash)?
+        else {
+            return Ok(());
+        };
+
+        if let Err(error) = inject(css, class_name) {
+            self.rollback_style(css, class_name, previous);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// 根据环境选择注入策略
+    fn inject_into_environment(&self, css: &str, class_name: &str) -> Result<(), InjectionError> {
         match self.environment {
             InjectionEnvironment::Browser => self.inject_browser_style(css, class_name),
             InjectionEnvironment::Server => self.inject_server_style(css, class_name),
@@ -307,6 +366,56 @@ impl StyleInjector {
         }
     }
 
+    /// 在样式表中记录样式
+    ///
+    /// 样式是新的或CSS已变化时返回 [`RecordOutcome::Recorded`]，需要注入；
+    /// 相同样式已存在时返回 [`RecordOutcome::Unchanged`]。
+    fn record_style(
+        &self,
+        css: &str,
+        class_name: &str,
+        css_hash: u64,
+    ) -> Result<RecordOutcome, InjectionError> {
+        let mut styles = self.injected_styles.lock().map_err(|e| {
+            InjectionError::InjectionFailed(format!("Failed to lock styles: {}", e))
+        })?;
+
+        if styles
+            .get(class_name)
+            .is_some_and(|existing| existing == css)
+        {
+            return Ok(RecordOutcome::Unchanged);
+        }
+
+        let previous = styles.insert(class_name.to_string(), css.to_string());
+        self.injected_index.insert(class_name, css_hash);
+        Ok(RecordOutcome::Recorded { previous })
+    }
+
+    /// 撤销注入失败的样式记录，恢复之前的CSS
+    ///
+    /// 记录已被其他调用替换时保持不变。
+    fn rollback_style(&self, css: &str, class_name: &str, previous: Option<String>) {
+        let Ok(mut styles) = self.injected_styles.lock() else {
+            return;
+        };
+        if styles.get(class_name).map(String::as_str) != Some(css) {
+            return;
+        }
+
+        match previous {
+            Some(previous) => {
+                self.injected_index
+                    .insert(class_name, style_hash(&previous));
+                styles.insert(class_name.to_string(), previous);
+            }
+            None => {
+                styles.shift_remove(class_name);
+                self.injected_index.remove(class_name);
+            }
+        }
+    }
+
     /// Remove a style by class name
     ///
     /// 通过类名移除之前注入的样式。
@@ -338,7 +447,8 @@ impl StyleInjector {
             let mut styles = self.injected_styles.lock().map_err(|e| {
                 InjectionError::RemovalFailed(format!("Failed to lock styles: {}", e))
             })?;
-            styles.remove(class_name);
+            styles.shift_remove(class_name);
+            self.injected_index.remove(class_name);
         }
 
         // 根据环境选择移除策略
@@ -363,6 +473,90 @@ impl StyleInjector {
         }
     }
 
+    /// Remove all styles whose class name starts with the given prefix
+    ///
+    /// 移除类名以指定前缀开头的样式，用于微前端卸载等局部清理场景。
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - 类名前缀
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回移除的样式数量，失败时返回包含错误信息的`InjectionError`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::StyleInjector;
+    ///
+    /// let injector = StyleInjector::new_noop();
+    /// injector.inject_style("color: red;", "app1-title").unwrap();
+    /// injector.inject_style("color: blue;", "app2-title").unwrap();
+    ///
+    /// assert_eq!(injector.remove_styles_by_prefix("app1-").unwrap(), 1);
+    /// assert!(injector.is_style_injected("app2-title"));
+    /// ```
+    pub fn remove_styles_by_prefix(&self, prefix: &str) -> Result<usize, InjectionError> {
+        let class_names: Vec<String> = {
+            let styles = self.injected_styles.lock().map_err(|e| {
+                InjectionError::RemovalFailed(format!("Failed to lock styles: {}", e))
+            })?;
+            styles
+                .keys()
+                .filter(|class_name| class_name.starts_with(prefix))
+                .cloned()
+                .collect()
+        };
+
+        for class_name in &class_names {
+            self.remove_style(class_name)?;
+        }
+
+        Ok(class_names.len())
+    }
+
+    /// 检查指定类名的样式是否已注入
+    pub fn is_style_injected(&self, class_name: &str) -> bool {
+        self.injected_styles
+            .lock()
+            .map(|styles| styles.contains_key(class_name))
+            .unwrap_or(false)
+    }
+
+    /// 获取已注入样式的副本（类名 -> CSS）
+    pub fn injected_styles(&self) -> HashMap<String, String> {
+        self.injected_style_entries().into_iter().collect()
+    }
+
+    /// 按首次注入顺序获取已注入样式（类名, CSS）
+    pub fn injected_style_entries(&self) -> Vec<(String, String)> {
+        self.injected_styles
+            .lock()
+            .map(|styles| {
+                styles
+                    .iter()
+                    .map(|(class_name, css)| (class_name.clone(), css.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 估算已注入样式占用的内存（字节）
+    ///
+    /// 按样式表中类名与 CSS 文本的字节长度累加。
+    pub fn estimated_memory(&self) -> usize {
+        self.injected_styles
+            .lock()
+            .map(|styles| {
+                styles
+                    .iter()
+                    .map(|(class_name, css)| class_name.len() + css.len())
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
     /// Clear all injected styles
     ///
     /// 清除所有通过样式注入器注入的样式。
@@ -390,6 +584,7 @@ impl StyleInjector {
                 InjectionError::ClearFailed(format!("Failed to lock styles: {}", e))
             })?;
             styles.clear();
+            self.injected_index.clear();
         }
 
         // 根据环境选择清除策略
@@ -414,6 +609,41 @@ impl StyleInjector {
         }
     }
 
+    /// Compact injected styles
+    ///
+    /// 将仍在跟踪的样式按类名顺序重新写入单个样式元素，并移除多余的样式容器，
+    /// 减少长期运行的单页应用中的 DOM 节点以及移除样式后留下的碎片。
+    /// 非浏览器环境中为空操作。
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回`Ok(())`，失败时返回包含错误信息的`InjectionError`
+    pub fn compact(&self) -> Result<(), InjectionError> {
+        match self.environment {
+            InjectionEnvironment::Browser | InjectionEnvironment::Isomorphic => {
+                self.compact_browser_styles()
+            }
+            InjectionEnvironment::Server | InjectionEnvironment::Noop => Ok(()),
+        }
+    }
+
+    /// 按类名顺序生成整理后的样式表内容
+    #[cfg(any(target_arch = "wasm32", test))]
+    fn compacted_css(&self) -> Result<String, InjectionError> {
+        let styles = self.injected_styles.lock().map_err(|e| {
+            InjectionError::InjectionFailed(format!("Failed to lock styles: {}", e))
+        })?;
+
+        let mut entries: Vec<_> = styles.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+
+        Ok(entries
+            .into_iter()
+            .map(|(class_name, css)| format!(".{} {{ {} }}", class_name, css))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
     /// Inject style in browser environment
     ///
     /// 在浏览器环境中注入样式，通过DOM操作添加样式元素。
@@ -428,19 +658,48 @@ impl StyleInjector {
     /// 成功时返回`Ok(())`
     #[cfg(target_arch = "wasm32")]
     fn inject_browser_style(&self, css: &str, class_name: &str) -> Result<(), InjectionError> {
+        use wasm_bindgen::closure::Closure;
         use wasm_bindgen::JsCast;
-        use web_sys::{window, Document, Element, HtmlStyleElement};
 
-        // 获取window和document
-        let window = window()
-            .ok_or_else(|| InjectionError::InjectionFailed("Failed to get window".to_string()))?;
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or(InjectionError::DocumentUnavailable)?;
+        let dom_loading = document.ready_state() == "loading";
+
+        // head 尚未就绪时先加入队列，等待 DOMContentLoaded 后按顺序注入
+        let needs_listener = inject_or_queue(
+            &self.pending_styles,
+            css,
+            class_name,
+            dom_loading,
+            Self::apply_browser_style,
+        )?;
+
+        if needs_listener {
+            let pending = self.pending_styles.clone();
+            let on_ready = Closure::once_into_js(move || {
+                if let Err(e) = flush_pending(&pending, Self::apply_browser_style) {
+                    log::warn!("Failed to flush pending styles: {}", e);
+                }
+            });
+
+            document
+                .add_event_listener_with_callback("DOMContentLoaded", on_ready.unchecked_ref())
+                .map_err(|e| InjectionError::DomOperationFailed(format!("{:?}", e)))?;
+        }
 
-        let document = window
-            .document()
-            .ok_or_else(|| InjectionError::InjectionFailed("Failed to get document".to_string()))?;
+        Ok(())
+    }
+
+    /// 将样式写入文档中的样式元素
+    #[cfg(target_arch = "wasm32")]
+    fn apply_browser_style(css: &str, class_name: &str) -> Result<(), InjectionError> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or(InjectionError::DocumentUnavailable)?;
 
         // 创建或获取样式容器
-        let style_element = self.get_or_create_style_element(&document)?;
+        let style_element = Self::get_or_create_style_element(&document)?;
 
         // 格式化CSS为作用域样式
         let scoped_css = format!(".{} {{ {} }}", class_name, css);
@@ -469,12 +728,9 @@ impl StyleInjector {
         use web_sys::{window, Document, Element, HtmlStyleElement};
 
         // 获取window和document
-        let window = window()
-            .ok_or_else(|| InjectionError::RemovalFailed("Failed to get window".to_string()))?;
-
-        let document = window
-            .document()
-            .ok_or_else(|| InjectionError::RemovalFailed("Failed to get document".to_string()))?;
+        let document = window()
+            .and_then(|window| window.document())
+            .ok_or(InjectionError::DocumentUnavailable)?;
 
         // 查找样式元素
         if let Some(style_element) = document.get_element_by_id("css-in-rust-styles") {
@@ -506,12 +762,9 @@ impl StyleInjector {
         use web_sys::{window, Document};
 
         // 获取window和document
-        let window = window()
-            .ok_or_else(|| InjectionError::ClearFailed("Failed to get window".to_string()))?;
-
-        let document = window
-            .document()
-            .ok_or_else(|| InjectionError::ClearFailed("Failed to get document".to_string()))?;
+        let document = window()
+            .and_then(|window| window.document())
+            .ok_or(InjectionError::DocumentUnavailable)?;
 
         // 移除样式元素
         if let Some(style_element) = document.get_element_by_id("css-in-rust-styles") {
@@ -525,6 +778,16 @@ impl StyleInjector {
         Ok(())
     }
 
+    /// 在浏览器环境中整理样式元素
+    #[cfg(target_arch = "wasm32")]
+    fn compact_browser_styles(&self) -> Result<(), InjectionError> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or(InjectionError::DocumentUnavailable)?;
+
+        coalesce_containers(&document, &self.compacted_css()?)
+    }
+
     /// Get or create style element
     ///
     /// 获取或创建样式元素，用于注入CSS。
@@ -538,7 +801,6 @@ impl StyleInjector {
     /// 成功时返回样式元素
     #[cfg(target_arch = "wasm32")]
     fn get_or_create_style_element(
-        &self,
         document: &web_sys::Document,
     ) -> Result<web_sys::HtmlStyleElement, InjectionError> {
         use wasm_bindgen::JsCast;
@@ -569,13 +831,10 @@ impl StyleInjector {
         style_element.set_type("text/css");
 
         // 添加到文档头部
-        let head = document.head().ok_or_else(|| {
-            InjectionError::InjectionFailed("Failed to get document head".to_string())
-        })?;
+        let head = document_head(Some(document))?;
 
-        head.append_child(&style_element).map_err(|_| {
-            InjectionError::InjectionFailed("Failed to append style element".to_string())
-        })?;
+        head.append_child(&style_element)
+            .map_err(|e| InjectionError::CssomRejected(format!("{:?}", e)))?;
 
         Ok(style_element)
     }
@@ -600,8 +859,11 @@ impl StyleInjector {
             InjectionError::InjectionFailed(format!("无法获取服务端样式写锁: {}", e))
         })?;
 
-        // 存储样式，以便稍后生成
-        styles.insert(class_name.to_string(), css.to_string());
+        // 存储样式，以便稍后生成；重复注入的类名保留原有位置
+        match styles.iter_mut().find(|(name, _)| name == class_name) {
+            Some((_, existing)) => *existing = css.to_string(),
+            None => styles.push((class_name.to_string(), css.to_string())),
+        }
 
         Ok(())
     }
@@ -626,7 +888,7 @@ impl StyleInjector {
             .map_err(|e| InjectionError::RemovalFailed(format!("无法获取服务端样式写锁: {}", e)))?;
 
         // 移除样式
-        styles.remove(class_name);
+        styles.retain(|(name, _)| name != class_name);
 
         Ok(())
     }
@@ -648,10 +910,75 @@ impl StyleInjector {
 
         // 清空样式集合
         styles.clear();
+        drop(styles);
+
+        SERVER_GLOBAL_STYLES
+            .write()
+            .map_err(|e| InjectionError::RemovalFailed(format!("无法获取服务端样式写锁: {}", e)))?
+            .clear();
 
         Ok(())
     }
 
+    /// Register an unscoped stylesheet for SSR
+    ///
+    /// 记录 `css_global!` 生成的全局样式（`:root` 变量、`@keyframes`、重置样式等），
+    /// 输出时原样写在作用域样式之前，不包裹 `.类名 { }`。相同 `style_id` 只保留一份。
+    /// 浏览器和空操作环境中为空操作。
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - 完整的全局 CSS
+    /// * `style_id` - 全局样式的标识
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回`Ok(())`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::StyleInjector;
+    ///
+    /// let injector = StyleInjector::new_ssr();
+    /// injector
+    ///     .inject_global_style(":root{--gap:4px}", "css-global-doc")
+    ///     .unwrap();
+    ///
+    /// let css = injector.collect_ssr_css().unwrap();
+    /// assert!(css.contains(":root{--gap:4px}"));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn inject_global_style(&self, css: &str, style_id: &str) -> Result<(), InjectionError> {
+        if matches!(
+            self.environment,
+            InjectionEnvironment::Browser | InjectionEnvironment::Noop
+        ) {
+            return Ok(());
+        }
+
+        let mut styles = SERVER_GLOBAL_STYLES.write().map_err(|e| {
+            InjectionError::InjectionFailed(format!("无法获取服务端样式写锁: {}", e))
+        })?;
+
+        match styles.iter_mut().find(|(id, _)| id == style_id) {
+            Some((_, existing)) => *existing = css.to_string(),
+            None => styles.push((style_id.to_string(), css.to_string())),
+        }
+
+        Ok(())
+    }
+
+    /// 按注入顺序获取服务端全局样式（样式 ID, CSS）
+    #[cfg(not(target_arch = "wasm32"))]
+    fn server_global_style_entries(&self) -> Result<Vec<(String, String)>, InjectionError> {
+        let styles = SERVER_GLOBAL_STYLES.read().map_err(|e| {
+            InjectionError::InjectionFailed(format!("无法获取服务端样式读锁: {}", e))
+        })?;
+
+        Ok(styles.clone())
+    }
+
     /// 获取收集的服务端样式
     ///
     /// 返回所有收集的服务端样式，用于SSR渲染。
@@ -673,6 +1000,12 @@ impl StyleInjector {
     /// ```
     #[cfg(not(target_arch = "wasm32"))]
     pub fn get_collected_styles(&self) -> Result<HashMap<String, String>, InjectionError> {
+        Ok(self.server_style_entries()?.into_iter().collect())
+    }
+
+    /// 按注入顺序获取服务端样式（类名, CSS 声明）
+    #[cfg(not(target_arch = "wasm32"))]
+    fn server_style_entries(&self) -> Result<Vec<(String, String)>, InjectionError> {
         let styles = SERVER_STYLES.read().map_err(|e| {
             InjectionError::InjectionFailed(format!("无法获取服务端样式读锁: {}", e))
         })?;
@@ -680,6 +1013,41 @@ impl StyleInjector {
         Ok(styles.clone())
     }
 
+    /// 收集服务端缓冲的CSS
+    ///
+    /// 服务端（或同构）模式下的注入不会访问 `web_sys`，而是写入服务端缓冲区。
+    /// 此方法将缓冲区中的声明按注入顺序包裹为 `.类名 { 声明 }` 规则并拼接，便于直接写入服务端响应。
+    /// 通过 [`StyleInjector::inject_global_style`] 记录的全局样式原样排在最前面。
+    ///
+    /// # Returns
+    ///
+    /// 拼接后的CSS字符串
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::StyleInjector;
+    ///
+    /// let injector = StyleInjector::new_ssr();
+    /// injector.inject_style("color: blue;", "ssr-btn").unwrap();
+    ///
+    /// let css = injector.collect_ssr_css().unwrap();
+    /// assert!(css.contains(".ssr-btn { color: blue; }"));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn collect_ssr_css(&self) -> Result<String, InjectionError> {
+        let global_css = self
+            .server_global_style_entries()?
+            .into_iter()
+            .map(|(_, css)| css);
+        let scoped_css = self
+            .server_style_entries()?
+            .into_iter()
+            .map(|(class_name, css)| format!(".{} {{ {} }}", class_name, css));
+
+        Ok(global_css.chain(scoped_css).collect::<Vec<_>>().join("\n"))
+    }
+
     /// 生成HTML样式标签
     ///
     /// 将收集的样式转换为HTML样式标签字符串，用于插入到SSR响应中。
@@ -701,14 +1069,22 @@ impl StyleInjector {
     /// ```
     #[cfg(not(target_arch = "wasm32"))]
     pub fn generate_style_html(&self) -> Result<String, InjectionError> {
+        let global_styles = self.server_global_style_entries()?;
         let styles = self.get_collected_styles()?;
 
-        if styles.is_empty() {
+        if global_styles.is_empty() && styles.is_empty() {
             return Ok(String::new());
         }
 
         let mut html = String::new();
 
+        for (style_id, css) in global_styles {
+            html.push_str(&format!(
+                "<style data-css-global=\"{}\">\n{}\n</style>\n",
+                style_id, css
+            ));
+        }
+
         for (class_name, css) in styles {
             html.push_str(&format!(
                 "<style data-css-class=\"{}\">\n.{} {{ {} }}\n</style>\n",
@@ -729,7 +1105,7 @@ impl StyleInjector {
             InjectionError::InjectionFailed(format!("Failed to lock styles: {}", e))
         })?;
 
-        Ok(styles.clone())
+        Ok(styles.clone().into_iter().collect())
     }
 
     /// 在浏览器环境中，这是一个空方法，因为无法生成服务端HTML
@@ -759,6 +1135,12 @@ impl StyleInjector {
         // 服务端环境中，这是一个空操作
         Ok(())
     }
+
+    /// 在服务端环境中，这是一个空方法，因为没有浏览器端样式元素
+    fn compact_browser_styles(&self) -> Result<(), InjectionError> {
+        // 服务端环境中，这是一个空操作
+        Ok(())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -782,10 +1164,246 @@ impl StyleInjector {
     }
 }
 
+/// 注入时需要的最小文档能力，便于在非浏览器环境中模拟
+#[cfg(any(target_arch = "wasm32", test))]
+trait StyleDocument {
+    type Head;
+
+    fn head(&self) -> Option<Self::Head>;
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StyleDocument for web_sys::Document {
+    type Head = web_sys::HtmlHeadElement;
+
+    fn head(&self) -> Option<Self::Head> {
+        web_sys::Document::head(self)
+    }
+}
+
+/// 整理样式容器时需要的文档能力，便于在非浏览器环境中模拟
+#[cfg(any(target_arch = "wasm32", test))]
+trait StyleContainerHost {
+    type Container;
+
+    /// 文档中所有的样式容器，按文档顺序排列
+    fn containers(&self) -> Vec<Self::Container>;
+
+    /// 创建新的样式容器
+    fn create_container(&self) -> Result<Self::Container, InjectionError>;
+
+    /// 替换样式容器的内容
+    fn set_text(&self, container: &Self::Container, css: &str);
+
+    /// 从文档中移除样式容器
+    fn remove(&self, container: &Self::Container) -> Result<(), InjectionError>;
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StyleContainerHost for web_sys::Document {
+    type Container = web_sys::Element;
+
+    fn containers(&self) -> Vec<Self::Container> {
+        use wasm_bindgen::JsCast;
+
+        let Ok(nodes) = self.query_selector_all("style#css-in-rust-styles") else {
+            return Vec::new();
+        };
+
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+            .collect()
+    }
+
+    fn create_container(&self) -> Result<Self::Container, InjectionError> {
+        StyleInjector::get_or_create_style_element(self).map(Into::into)
+    }
+
+    fn set_text(&self, container: &Self::Container, css: &str) {
+        container.set_text_content(Some(css));
+    }
+
+    fn remove(&self, container: &Self::Container) -> Result<(), InjectionError> {
+        container.remove();
+        Ok(())
+    }
+}
+
+/// 将样式写入第一个样式容器并移除其余容器
+#[cfg(any(target_arch = "wasm32", test))]
+fn coalesce_containers<H: StyleContainerHost>(host: &H, css: &str) -> Result<(), InjectionError> {
+    let mut containers = host.containers().into_iter();
+    let primary = match containers.next() {
+        Some(container) => container,
+        None => host.create_container()?,
+    };
+
+    host.set_text(&primary, css);
+    for container in containers {
+        host.remove(&container)?;
+    }
+
+    Ok(())
+}
+
+/// 尝试注入样式，文档头部尚未就绪时加入待注入队列
+///
+/// 队列非空时先按顺序刷新队列，保证注入顺序与调用顺序一致；就绪回调中途刷新失败时，
+/// 剩余样式由之后的注入继续刷新。只有文档仍在解析（`dom_loading`）时才会排队，
+/// 否则 DOMContentLoaded 不会再触发，直接返回 `HeadNotFound`。
+/// 返回 `true` 表示队列刚从空变为非空，调用方需要注册就绪回调。
+#[cfg(any(target_arch = "wasm32", test))]
+fn inject_or_queue(
+    pending: &Mutex<Vec<(String, String)>>,
+    css: &str,
+    class_name: &str,
+    dom_loading: bool,
+    mut apply: impl FnMut(&str, &str) -> Result<(), InjectionError>,
+) -> Result<bool, InjectionError> {
+    let mut queue = pending
+        .lock()
+        .map_err(|e| InjectionError::InjectionFailed(format!("Failed to lock queue: {}", e)))?;
+
+    match apply_queue(&mut queue, &mut apply) {
+        Ok(_) => match apply(css, class_name) {
+            Err(InjectionError::HeadNotFound) => {}
+            result => return result.map(|_| false),
+        },
+        Err(InjectionError::HeadNotFound) => {}
+        Err(e) => return Err(e),
+    }
+
+    if !dom_loading {
+        return Err(InjectionError::HeadNotFound);
+    }
+
+    queue.push((class_name.to_string(), css.to_string()));
+    Ok(queue.len() == 1)
+}
+
+/// 按注入顺序应用待注入队列，返回成功应用的样式数量
+///
+/// 遇到错误时停止，未应用的样式保留在队列中，由之后的注入继续刷新。
+#[cfg(any(target_arch = "wasm32", test))]
+fn flush_pending(
+    pending: &Mutex<Vec<(String, String)>>,
+    mut apply: impl FnMut(&str, &str) -> Result<(), InjectionError>,
+) -> Result<usize, InjectionError> {
+    let mut queue = pending
+        .lock()
+        .map_err(|e| InjectionError::InjectionFailed(format!("Failed to lock queue: {}", e)))?;
+
+    apply_queue(&mut queue, &mut apply)
+}
+
+/// 从队首依次应用样式，遇到错误时停止
+#[cfg(any(target_arch = "wasm32", test))]
+fn apply_queue(
+    queue: &mut Vec<(String, String)>,
+    apply: &mut impl FnMut(&str, &str) -> Result<(), InjectionError>,
+) -> Result<usize, InjectionError> {
+    let mut applied = 0;
+    while let Some((class_name, css)) = queue.first() {
+        apply(css, class_name)?;
+        queue.remove(0);
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+/// 获取文档头部，缺失时返回结构化错误以便调用方重试或降级
+#[cfg(any(target_arch = "wasm32", test))]
+fn document_head<D: StyleDocument>(document: Option<&D>) -> Result<D::Head, InjectionError> {
+    document
+        .ok_or(InjectionError::DocumentUnavailable)?
+        .head()
+        .ok_or(InjectionError::HeadNotFound)
+}
+
+/// 已注入样式的分片索引
+///
+/// 按类名哈希分片，每个分片是一个读写锁保护的 类名 -> CSS哈希 映射。
+/// 重复注入相同样式时只需获取对应分片的读锁，不同类名之间也不会互相阻塞。
+/// 索引只在持有 `injected_styles` 锁时写入，因此与样式表保持一致。
+struct InjectedIndex {
+    shards: Vec<RwLock<HashMap<String, u64>>>,
+}
+
+impl InjectedIndex {
+    fn new() -> Self {
+        Self {
+            shards: (0..INJECTED_INDEX_SHARDS)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+        }
+    }
+
+    fn shard(&self, class_name: &str) -> &RwLock<HashMap<String, u64>> {
+        &self.shards[style_hash(class_name) as usize % self.shards.len()]
+    }
+
+    /// 检查类名是否已以相同CSS注入
+    fn contains(&self, class_name: &str, css_hash: u64) -> bool {
+        self.shard(class_name)
+            .read()
+            .map(|shard| shard.get(class_name) == Some(&css_hash))
+            .unwrap_or(false)
+    }
+
+    fn insert(&self, class_name: &str, css_hash: u64) {
+        if let Ok(mut shard) = self.shard(class_name).write() {
+            shard.insert(class_name.to_string(), css_hash);
+        }
+    }
+
+    fn remove(&self, class_name: &str) {
+        if let Ok(mut shard) = self.shard(class_name).write() {
+            shard.remove(class_name);
+        }
+    }
+
+    fn clear(&self) {
+        for shard in &self.shards {
+            if let Ok(mut shard) = shard.write() {
+                shard.clear();
+            }
+        }
+    }
+}
+
+/// 在样式表中记录样式的结果
+enum RecordOutcome {
+    /// 相同样式已存在，无需注入
+    Unchanged,
+    /// 已记录新样式，`previous` 为被替换的CSS
+    Recorded { previous: Option<String> },
+}
+
+/// 计算字符串的进程内哈希
+fn style_hash(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    struct MockDocument {
+        head: Option<&'static str>,
+    }
+
+    impl StyleDocument for MockDocument {
+        type Head = &'static str;
+
+        fn head(&self) -> Option<Self::Head> {
+            self.head
+        }
+    }
+
     #[test]
     fn test_style_injector_creation() {
         let injector = StyleInjector::new();
@@ -813,4 +1431,319 @@ mod tests {
             .unwrap()
             .contains_key(class_name));
     }
+
+    #[test]
+    fn test_concurrent_injection_injects_each_class_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+
+        let injector = Arc::new(StyleInjector::new_noop());
+        let injections = Arc::new(Mutex::new(HashMap::<String, usize>::new()));
+
+        let handles: Vec<_> = (0..16)
+            .map(|thread_index| {
+                let injector = Arc::clone(&injector);
+                let injections = Arc::clone(&injections);
+                thread::spawn(move || {
+                    for i in 0..200 {
+                        // 线程之间的类名大量重叠
+                        let class_name = format!("c-{}", (i + thread_index) % 50);
+                        let css = format!("width: {}px;", (i + thread_index) % 50);
+                        injector
+                            .inject_style_with(&css, &class_name, |_, class_name| {
+                                *injections
+                                    .lock()
+                                    .unwrap()
+                                    .entry(class_name.to_string())
+                                    .or_default() += 1;
+                                Ok(())
+                            })
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let injections = injections.lock().unwrap();
+        assert_eq!(injections.len(), 50);
+        assert!(injections.values().all(|count| *count == 1));
+        for i in 0..50 {
+            assert!(injector.is_style_injected(&format!("c-{}", i)));
+        }
+
+        // 移除后快速路径不再命中，重新注入会再次执行
+        injector.remove_style("c-0").unwrap();
+        let reinjected = AtomicUsize::new(0);
+        injector
+            .inject_style_with("width: 0px;", "c-0", |_, _| {
+                reinjected.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(reinjected.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_failed_injection_is_rolled_back_and_retried() {
+        let injector = StyleInjector::new_noop();
+        let fail = |_: &str, _: &str| Err(InjectionError::InjectionFailed("head missing".into()));
+
+        assert!(injector
+            .inject_style_with("color: red;", "retry", fail)
+            .is_err());
+        assert!(!injector.is_style_injected("retry"));
+
+        // 失败后再次注入会重新执行环境注入
+        let mut attempts = 0;
+        injector
+            .inject_style_with("color: red;", "retry", |_, _| {
+                attempts += 1;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(attempts, 1);
+        assert!(injector.is_style_injected("retry"));
+
+        // 更新CSS失败时恢复之前的样式
+        assert!(injector
+            .inject_style_with("color: blue;", "retry", fail)
+            .is_err());
+        assert_eq!(
+            injector.injected_styles().get("retry").map(String::as_str),
+            Some("color: red;")
+        );
+        assert!(injector
+            .injected_index
+            .contains("retry", style_hash("color: red;")));
+    }
+
+    #[test]
+    fn test_changed_css_bypasses_fast_path() {
+        let injector = StyleInjector::new_noop();
+        injector.inject_style("color: red;", "title").unwrap();
+        injector.inject_style("color: blue;", "title").unwrap();
+
+        assert_eq!(
+            injector
+                .injected_styles
+                .lock()
+                .unwrap()
+                .get("title")
+                .map(String::as_str),
+            Some("color: blue;")
+        );
+        assert!(!injector
+            .injected_index
+            .contains("title", style_hash("color: red;")));
+    }
+
+    #[test]
+    fn test_remove_styles_by_prefix() {
+        let injector = StyleInjector::new_noop();
+        injector.inject_style("color: red;", "app1-x").unwrap();
+        injector.inject_style("color: blue;", "app1-y").unwrap();
+        injector.inject_style("color: green;", "app2-y").unwrap();
+
+        assert_eq!(injector.remove_styles_by_prefix("app1-").unwrap(), 2);
+        assert!(!injector.is_style_injected("app1-x"));
+        assert!(!injector.is_style_injected("app1-y"));
+        assert!(injector.is_style_injected("app2-y"));
+        assert_eq!(injector.remove_styles_by_prefix("app1-").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_missing_head_yields_head_not_found() {
+        let loading = MockDocument { head: None };
+        assert!(matches!(
+            document_head(Some(&loading)),
+            Err(InjectionError::HeadNotFound)
+        ));
+
+        assert!(matches!(
+            document_head::<MockDocument>(None),
+            Err(InjectionError::DocumentUnavailable)
+        ));
+
+        let ready = MockDocument { head: Some("head") };
+        assert_eq!(document_head(Some(&ready)).unwrap(), "head");
+    }
+
+    #[test]
+    fn test_early_injection_is_flushed_when_dom_ready() {
+        use std::cell::RefCell;
+
+        let document = RefCell::new(MockDocument { head: None });
+        let applied = RefCell::new(Vec::new());
+        let apply = |css: &str, class_name: &str| {
+            document_head(Some(&*document.borrow()))?;
+            applied
+                .borrow_mut()
+                .push(format!(".{} {{ {} }}", class_name, css));
+            Ok(())
+        };
+
+        let pending = Mutex::new(Vec::new());
+        assert!(inject_or_queue(&pending, "color: red;", "early-a", true, apply).unwrap());
+        assert!(!inject_or_queue(&pending, "color: blue;", "early-b", true, apply).unwrap());
+        assert!(applied.borrow().is_empty());
+
+        // DOMContentLoaded
+        document.borrow_mut().head = Some("head");
+        assert_eq!(flush_pending(&pending, apply).unwrap(), 2);
+        assert!(pending.lock().unwrap().is_empty());
+
+        assert!(!inject_or_queue(&pending, "color: green;", "late", false, apply).unwrap());
+        assert_eq!(
+            *applied.borrow(),
+            vec![
+                ".early-a { color: red; }",
+                ".early-b { color: blue; }",
+                ".late { color: green; }",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_injection_after_dom_ready_is_not_queued() {
+        let pending = Mutex::new(Vec::new());
+        let missing_head = |_: &str, _: &str| Err(InjectionError::HeadNotFound);
+
+        // DOMContentLoaded 已触发，排队的样式不会再被刷新
+        assert!(matches!(
+            inject_or_queue(&pending, "color: red;", "ready-a", false, missing_head),
+            Err(InjectionError::HeadNotFound)
+        ));
+        assert!(pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_partial_flush_is_resumed_by_next_injection() {
+        use std::cell::{Cell, RefCell};
+
+        let failures = Cell::new(1);
+        let applied = RefCell::new(Vec::new());
+        let apply = |_: &str, class_name: &str| {
+            if class_name == "partial-b" && failures.get() > 0 {
+                failures.set(failures.get() - 1);
+                return Err(InjectionError::DomOperationFailed("busy".to_string()));
+            }
+            applied.borrow_mut().push(class_name.to_string());
+            Ok(())
+        };
+
+        let pending = Mutex::new(vec![
+            ("partial-a".to_string(), "color: red;".to_string()),
+            ("partial-b".to_string(), "color: blue;".to_string()),
+        ]);
+
+        // 就绪回调刷新到一半失败
+        assert!(flush_pending(&pending, apply).is_err());
+        assert_eq!(pending.lock().unwrap().len(), 1);
+
+        // 下一次注入先刷新剩余队列，再注入自身
+        assert!(!inject_or_queue(&pending, "color: green;", "partial-c", false, apply).unwrap());
+        assert!(pending.lock().unwrap().is_empty());
+        assert_eq!(
+            *applied.borrow(),
+            vec!["partial-a", "partial-b", "partial-c"]
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_ssr_injection_accumulates_css() {
+        let _guard = SERVER_STYLES_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let injector = StyleInjector::new_isomorphic();
+        // 类名按字母倒序注入，验证输出保持注入顺序而非按类名排序
+        injector
+            .inject_style("color: red;", "ssr-accumulate-z")
+            .unwrap();
+        injector
+            .inject_style("color: blue;", "ssr-accumulate-a")
+            .unwrap();
+
+        let css = injector.collect_ssr_css().unwrap();
+        let z = css.find(".ssr-accumulate-z { color: red; }").unwrap();
+        let a = css.find(".ssr-accumulate-a { color: blue; }").unwrap();
+        assert!(z < a);
+    }
+
+    #[test]
+    fn test_compact_coalesces_surviving_styles() {
+        use std::cell::RefCell;
+
+        struct MockHost {
+            containers: RefCell<Vec<(usize, String)>>,
+            next_id: RefCell<usize>,
+        }
+
+        impl StyleContainerHost for MockHost {
+            type Container = usize;
+
+            fn containers(&self) -> Vec<usize> {
+                self.containers.borrow().iter().map(|(id, _)| *id).collect()
+            }
+
+            fn create_container(&self) -> Result<usize, InjectionError> {
+                let id = *self.next_id.borrow();
+                *self.next_id.borrow_mut() += 1;
+                self.containers.borrow_mut().push((id, String::new()));
+                Ok(id)
+            }
+
+            fn set_text(&self, container: &usize, css: &str) {
+                for (id, text) in self.containers.borrow_mut().iter_mut() {
+                    if id == container {
+                        *text = css.to_string();
+                    }
+                }
+            }
+
+            fn remove(&self, container: &usize) -> Result<(), InjectionError> {
+                self.containers
+                    .borrow_mut()
+                    .retain(|(id, _)| id != container);
+                Ok(())
+            }
+        }
+
+        let injector = StyleInjector::new_noop();
+        injector.inject_style("color: red;", "compact-c").unwrap();
+        injector.inject_style("color: blue;", "compact-a").unwrap();
+        injector.inject_style("color: green;", "compact-b").unwrap();
+        injector.remove_style("compact-b").unwrap();
+
+        // 多次注入和移除后遗留的碎片化样式容器
+        let host = MockHost {
+            containers: RefCell::new(vec![
+                (0, ".compact-c { color: red; }".to_string()),
+                (1, "\n\n".to_string()),
+                (2, ".compact-a { color: blue; }".to_string()),
+            ]),
+            next_id: RefCell::new(3),
+        };
+
+        coalesce_containers(&host, &injector.compacted_css().unwrap()).unwrap();
+
+        let containers = host.containers.borrow();
+        assert_eq!(containers.len(), 1);
+        assert_eq!(
+            containers[0].1,
+            ".compact-a { color: blue; }\n.compact-c { color: red; }"
+        );
+
+        // 没有样式容器时会创建一个
+        let empty = MockHost {
+            containers: RefCell::new(Vec::new()),
+            next_id: RefCell::new(0),
+        };
+        coalesce_containers(&empty, "").unwrap();
+        assert_eq!(empty.containers.borrow().len(), 1);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module provides high-level style management capabilities.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::time::Instant;
@@ -26,6 +27,7 @@ use super::provider::ProviderType;
 ///     max_cached_styles: 500,
 ///     enable_deduplication: true,
 ///     provider_type: ProviderType::Web,
+///     inject_enabled: true,
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -36,12 +38,17 @@ pub struct StyleManagerConfig {
     pub enable_deduplication: bool,
     /// Provider type for style injection
     pub provider_type: ProviderType,
+    /// Whether to inject styles into the environment
+    ///
+    /// 设为 `false` 时进入仅收集模式：样式只记录到内部缓冲区，
+    /// 不会调用注入器，可通过 [`StyleManager::collect`] 获取（适用于 SSR）。
+    pub inject_enabled: bool,
 }
 
 impl Default for StyleManagerConfig {
     /// 创建默认的样式管理器配置
     ///
-    /// 默认配置设置了1000个最大缓存样式，启用样式去重，并使用自动检测的提供器类型。
+    /// 默认配置设置了1000个最大缓存样式，启用样式去重和样式注入，并使用自动检测的提供器类型。
     ///
     /// # Examples
     ///
@@ -52,16 +59,56 @@ impl Default for StyleManagerConfig {
     /// assert_eq!(config.max_cached_styles, 1000);
     /// assert_eq!(config.enable_deduplication, true);
     /// assert!(matches!(config.provider_type, ProviderType::Auto));
+    /// assert!(config.inject_enabled);
     /// ```
     fn default() -> Self {
         Self {
             max_cached_styles: 1000,
             enable_deduplication: true,
             provider_type: ProviderType::Auto,
+            inject_enabled: true,
         }
     }
 }
 
+/// Style manager statistics
+///
+/// 样式管理器的运行统计，用于衡量缓存和去重效果。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::StyleManager;
+///
+/// let manager = StyleManager::new();
+/// manager.inject_style("color: red;", "stats-example").unwrap();
+/// manager.inject_style("color: red;", "stats-example").unwrap();
+///
+/// let stats = manager.stats();
+/// assert_eq!(stats.total_inject_calls, 2);
+/// assert_eq!(stats.dedup_hits, 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleManagerStats {
+    /// 当前缓存的样式数量
+    pub style_count: usize,
+    /// 调用 `inject_style` 的总次数
+    pub total_inject_calls: usize,
+    /// 因样式相同而跳过注入的次数
+    pub dedup_hits: usize,
+}
+
+/// Snapshot of injected styles
+///
+/// 记录某一时刻通过样式管理器注入的全部样式（类名, CSS），按首次注入顺序排列，
+/// 恢复后相同优先级规则的层叠顺序保持不变。
+/// 由 [`StyleManager::snapshot`] 生成，可通过 [`StyleManager::restore`] 恢复。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleSnapshot {
+    /// 按注入顺序排列的类名和 CSS
+    pub styles: Vec<(String, String)>,
+}
+
 /// Style manager for handling CSS injection and caching
 ///
 /// 提供高级的样式管理功能，包括样式注入、缓存和去重。
@@ -85,6 +132,7 @@ impl Default for StyleManagerConfig {
 ///     max_cached_styles: 500,
 ///     enable_deduplication: true,
 ///     provider_type: ProviderType::Web,
+///     inject_enabled: true,
 /// };
 /// let custom_manager = StyleManager::with_config(config);
 /// ```
@@ -92,6 +140,9 @@ pub struct StyleManager {
     config: StyleManagerConfig,
     injector: StyleInjector,
     cached_styles: Mutex<HashMap<String, (String, Instant)>>,
+    stats: Mutex<StyleManagerStats>,
+    /// 仅收集模式下记录的样式（类名, CSS），按首次注入顺序排列
+    collected: Mutex<Vec<(String, String)>>,
 }
 
 impl StyleManager {
@@ -133,6 +184,7 @@ impl StyleManager {
     ///     max_cached_styles: 200,
     ///     enable_deduplication: false,
     ///     provider_type: ProviderType::Ssr,
+    ///     inject_enabled: true,
     /// };
     ///
     /// // 使用自定义配置创建样式管理器
@@ -140,21 +192,9 @@ impl StyleManager {
     /// ```
     pub fn with_config(config: StyleManagerConfig) -> Self {
         // 根据配置的 provider_type 创建合适的样式注入器
-        let injector = match config.provider_type {
-            ProviderType::Auto => {
-                // 自动检测最合适的注入器
-                #[cfg(target_arch = "wasm32")]
-                {
-                    // 在 WASM 环境中使用 Web 注入器
-                    StyleInjector::new()
-                }
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    // 在非 WASM 环境中使用服务端注入器或无操作注入器
-                    StyleInjector::new()
-                }
-            }
-            ProviderType::Web => {
+        // Auto 会按目标平台和 CSS_IN_RUST_PROVIDER 环境变量解析为具体类型
+        let injector = match config.provider_type.resolve() {
+            ProviderType::Web | ProviderType::Auto => {
                 // 使用 Web 注入器
                 StyleInjector::new()
             }
@@ -176,6 +216,8 @@ impl StyleManager {
             config,
             injector,
             cached_styles: Mutex::new(HashMap::new()),
+            stats: Mutex::new(StyleManagerStats::default()),
+            collected: Mutex::new(Vec::new()),
         }
     }
 
@@ -183,6 +225,8 @@ impl StyleManager {
     ///
     /// 将CSS样式注入到当前环境中，并与指定的类名关联。
     /// 样式管理器会根据配置进行缓存和去重。
+    /// 类名会先经过 [`sanitize_class_name`] 处理，确保是合法的CSS标识符；
+    /// CSS 中引用原类名的类选择器会同步改写为规范化后的类名。
     ///
     /// # Arguments
     ///
@@ -191,7 +235,7 @@ impl StyleManager {
     ///
     /// # Returns
     ///
-    /// 成功时返回`Ok(())`，失败时返回包含错误信息的`InjectionError`
+    /// 成功时返回实际使用的类名，失败时返回包含错误信息的`InjectionError`
     ///
     /// # Examples
     ///
@@ -209,32 +253,57 @@ impl StyleManager {
     ///     color: white;
     /// }";
     /// let result = manager.inject_style(nav_css, "nav-style");
-    /// assert!(result.is_ok());
+    /// assert_eq!(result.unwrap(), "nav-style");
+    ///
+    /// // 非法类名会被规范化，选择器随之改写
+    /// let class_name = manager.inject_style(".1st nav { color: white; }", "1st nav").unwrap();
+    /// assert_eq!(class_name, "css-1st-nav");
+    /// assert_eq!(
+    ///     manager.get_cached_style("1st nav").unwrap(),
+    ///     ".css-1st-nav { color: white; }"
+    /// );
     /// ```
-    pub fn inject_style(&self, css: &str, class_name: &str) -> Result<(), InjectionError> {
+    pub fn inject_style(&self, css: &str, class_name: &str) -> Result<String, InjectionError> {
+        let sanitized = sanitize_class_name(class_name);
+        let css = &*rename_class_selector(css, class_name.trim(), &sanitized);
+        let class_name = sanitized;
+        self.stats.lock().unwrap().total_inject_calls += 1;
+
         // 如果启用了样式去重，检查是否已经注入过相同的样式
         if self.config.enable_deduplication {
             let mut cached_styles = self.cached_styles.lock().unwrap();
 
             // 检查是否已经缓存了相同的样式
-            if let Some((cached_css, _)) = cached_styles.get(class_name) {
+            if let Some((cached_css, _)) = cached_styles.get(&class_name) {
                 if cached_css == css {
                     // 更新访问时间（LRU策略）
-                    if let Some((cached_css, _)) = cached_styles.remove(class_name) {
-                        cached_styles.insert(class_name.to_string(), (cached_css, Instant::now()));
+                    if let Some((cached_css, _)) = cached_styles.remove(&class_name) {
+                        cached_styles.insert(class_name.clone(), (cached_css, Instant::now()));
                     }
                     // 已经注入过相同的样式，直接返回成功
-                    return Ok(());
+                    self.stats.lock().unwrap().dedup_hits += 1;
+                    return Ok(class_name);
                 }
             }
 
             // 更新缓存
             self.manage_cache_size(&mut cached_styles);
-            cached_styles.insert(class_name.to_string(), (css.to_string(), Instant::now()));
+            cached_styles.insert(class_name.clone(), (css.to_string(), Instant::now()));
+        }
+
+        // 仅收集模式：记录样式，不触碰注入器
+        if !self.config.inject_enabled {
+            let mut collected = self.collected.lock().unwrap();
+            match collected.iter_mut().find(|(name, _)| *name == class_name) {
+                Some((_, collected_css)) => *collected_css = css.to_string(),
+                None => collected.push((class_name.clone(), css.to_string())),
+            }
+            return Ok(class_name);
         }
 
         // 注入样式
-        self.injector.inject_style(css, class_name)
+        self.injector.inject_style(css, &class_name)?;
+        Ok(class_name)
     }
 
     /// Remove a style by class name
@@ -264,13 +333,21 @@ impl StyleManager {
     /// assert!(result.is_ok());
     /// ```
     pub fn remove_style(&self, class_name: &str) -> Result<(), InjectionError> {
+        let class_name = sanitize_class_name(class_name);
+
         // 如果启用了样式去重，从缓存中移除
         if self.config.enable_deduplication {
             let mut cached_styles = self.cached_styles.lock().unwrap();
-            cached_styles.remove(class_name);
+            cached_styles.remove(&class_name);
+        }
+
+        if !self.config.inject_enabled {
+            let mut collected = self.collected.lock().unwrap();
+            collected.retain(|(name, _)| *name != class_name);
+            return Ok(());
         }
 
-        self.injector.remove_style(class_name)
+        self.injector.remove_style(&class_name)
     }
 
     /// Clear all injected styles
@@ -303,9 +380,130 @@ impl StyleManager {
             cached_styles.clear();
         }
 
+        if !self.config.inject_enabled {
+            self.collected.lock().unwrap().clear();
+            return Ok(());
+        }
+
         self.injector.clear_all_styles()
     }
 
+    /// Compact injected styles
+    ///
+    /// 丢弃已不在注入器中的缓存记录，并将仍然生效的样式按稳定顺序合并到单个样式元素中。
+    /// 适合在长期运行的单页应用中大量注入和移除样式后调用。
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回`Ok(())`，失败时返回包含错误信息的`InjectionError`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::StyleManager;
+    ///
+    /// let manager = StyleManager::new();
+    /// manager.inject_style("padding: 8px;", "btn-style").unwrap();
+    /// manager.inject_style("margin: 16px;", "card-style").unwrap();
+    /// manager.remove_style("card-style").unwrap();
+    ///
+    /// manager.compact().unwrap();
+    /// assert!(manager.is_style_cached("btn-style"));
+    /// ```
+    pub fn compact(&self) -> Result<(), InjectionError> {
+        if self.config.enable_deduplication {
+            let mut cached_styles = self.cached_styles.lock().unwrap();
+            cached_styles.retain(|class_name, _| self.injector.is_style_injected(class_name));
+        }
+
+        self.injector.compact()
+    }
+
+    /// Collect styles recorded in collect-only mode
+    ///
+    /// 当 [`StyleManagerConfig::inject_enabled`] 为 `false` 时，返回所有已记录的样式，
+    /// 按首次注入顺序以换行连接，可直接嵌入服务端渲染的 `<style>` 标签。
+    /// 注入模式下始终返回空字符串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::{StyleManager, StyleManagerConfig};
+    ///
+    /// let manager = StyleManager::with_config(StyleManagerConfig {
+    ///     inject_enabled: false,
+    ///     ..StyleManagerConfig::default()
+    /// });
+    /// manager.inject_style(".a { color: red; }", "a").unwrap();
+    /// manager.inject_style(".b { color: blue; }", "b").unwrap();
+    ///
+    /// assert_eq!(manager.collect(), ".a { color: red; }\n.b { color: blue; }");
+    /// ```
+    pub fn collect(&self) -> String {
+        let collected = self.collected.lock().unwrap();
+        collected
+            .iter()
+            .map(|(_, css)| css.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Capture the currently injected styles
+    ///
+    /// 获取当前已注入样式的快照，可在之后通过 [`StyleManager::restore`] 恢复，
+    /// 便于在测试之间隔离样式状态。仅收集模式下快照取自收集缓冲区。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::{ProviderType, StyleManager, StyleManagerConfig};
+    ///
+    /// let manager = StyleManager::with_config(StyleManagerConfig {
+    ///     provider_type: ProviderType::Noop,
+    ///     ..StyleManagerConfig::default()
+    /// });
+    /// manager.inject_style("color: red;", "base").unwrap();
+    ///
+    /// let snapshot = manager.snapshot();
+    /// manager.inject_style("color: blue;", "temp").unwrap();
+    ///
+    /// manager.restore(snapshot).unwrap();
+    /// assert!(manager.is_style_cached("base"));
+    /// assert!(!manager.is_style_cached("temp"));
+    /// ```
+    pub fn snapshot(&self) -> StyleSnapshot {
+        if !self.config.inject_enabled {
+            return StyleSnapshot {
+                styles: self.collected.lock().unwrap().clone(),
+            };
+        }
+
+        StyleSnapshot {
+            styles: self.injector.injected_style_entries(),
+        }
+    }
+
+    /// Restore styles from a snapshot
+    ///
+    /// 清除所有已注入的样式，然后按快照中的顺序重新注入样式。
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - 由 [`StyleManager::snapshot`] 生成的快照
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回`Ok(())`，清除或注入失败时返回`InjectionError`
+    pub fn restore(&self, snapshot: StyleSnapshot) -> Result<(), InjectionError> {
+        self.clear_all_styles()?;
+
+        for (class_name, css) in snapshot.styles {
+            self.inject_style(&css, &class_name)?;
+        }
+
+        Ok(())
+    }
+
     /// 管理缓存大小，确保不超过配置的最大值
     fn manage_cache_size(&self, cached_styles: &mut HashMap<String, (String, Instant)>) {
         if cached_styles.len() >= self.config.max_cached_styles {
@@ -349,6 +547,15 @@ impl StyleManager {
         cached_styles.len()
     }
 
+    /// 获取样式管理器统计信息
+    ///
+    /// 返回当前缓存的样式数量、注入调用次数以及去重命中次数。
+    pub fn stats(&self) -> StyleManagerStats {
+        let mut stats = self.stats.lock().unwrap().clone();
+        stats.style_count = self.cached_styles_count();
+        stats
+    }
+
     /// 检查样式是否已缓存
     ///
     /// 检查指定类名的样式是否已经在缓存中。
@@ -374,7 +581,7 @@ impl StyleManager {
     /// ```
     pub fn is_style_cached(&self, class_name: &str) -> bool {
         let cached_styles = self.cached_styles.lock().unwrap();
-        cached_styles.contains_key(class_name)
+        cached_styles.contains_key(&sanitize_class_name(class_name))
     }
 
     /// 获取缓存中样式的内容
@@ -403,7 +610,9 @@ impl StyleManager {
     /// ```
     pub fn get_cached_style(&self, class_name: &str) -> Option<String> {
         let cached_styles = self.cached_styles.lock().unwrap();
-        cached_styles.get(class_name).map(|(css, _)| css.clone())
+        cached_styles
+            .get(&sanitize_class_name(class_name))
+            .map(|(css, _)| css.clone())
     }
 
     /// 获取当前使用的提供器类型
@@ -489,6 +698,82 @@ impl Default for StyleManager {
     }
 }
 
+/// 将类名规范化为合法的CSS标识符
+///
+/// 非法字符替换为 `-`；以数字或 `-` 加数字开头（以及空类名）时添加 `css-` 前缀。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::sanitize_class_name;
+///
+/// assert_eq!(sanitize_class_name("button-primary"), "button-primary");
+/// assert_eq!(sanitize_class_name("btn.primary:hover"), "btn-primary-hover");
+/// assert_eq!(sanitize_class_name("2xl"), "css-2xl");
+/// ```
+pub fn sanitize_class_name(class_name: &str) -> String {
+    let sanitized: String = class_name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    let mut chars = sanitized.chars();
+    let needs_prefix = match (chars.next(), chars.next()) {
+        (None, _) | (Some('-'), None) => true,
+        (Some(first), _) if first.is_ascii_digit() => true,
+        (Some('-'), Some(second)) => second.is_ascii_digit(),
+        _ => false,
+    };
+
+    if needs_prefix {
+        format!("css-{}", sanitized)
+    } else {
+        sanitized
+    }
+}
+
+/// 将 CSS 中的类选择器 `.from` 改写为 `.to`
+///
+/// 只替换后面紧跟非标识符字符的完整匹配；引号字符串内部保持不变。
+fn rename_class_selector<'a>(css: &'a str, from: &str, to: &str) -> Cow<'a, str> {
+    let pattern = format!(".{}", from);
+    if from.is_empty() || from == to || !css.contains(&pattern) {
+        return Cow::Borrowed(css);
+    }
+
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii();
+    let mut renamed = String::with_capacity(css.len());
+    let mut quote: Option<char> = None;
+    let mut rest = css;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+        } else if c == '"' || c == '\'' {
+            quote = Some(c);
+        } else if rest.starts_with(&pattern) && !rest[pattern.len()..].starts_with(is_ident) {
+            renamed.push('.');
+            renamed.push_str(to);
+            rest = &rest[pattern.len()..];
+            continue;
+        }
+
+        renamed.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    Cow::Owned(renamed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -508,6 +793,7 @@ mod tests {
             max_cached_styles: 500,
             enable_deduplication: false,
             provider_type: ProviderType::Web,
+            inject_enabled: true,
         };
         assert_eq!(custom_config.max_cached_styles, 500);
         assert_eq!(custom_config.enable_deduplication, false);
@@ -521,6 +807,7 @@ mod tests {
             max_cached_styles: 2,
             enable_deduplication: true,
             provider_type: ProviderType::Auto,
+            inject_enabled: true,
         });
 
         // 注入样式
@@ -548,6 +835,9 @@ mod tests {
         assert!(!manager.is_style_cached("test2"));
 
         // 测试清除所有样式
+        let _guard = crate::runtime::injector::SERVER_STYLES_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
         manager.clear_all_styles().unwrap();
         assert_eq!(manager.cached_styles_count(), 0);
     }
@@ -559,6 +849,7 @@ mod tests {
             max_cached_styles: 10,
             enable_deduplication: true,
             provider_type: ProviderType::Auto,
+            inject_enabled: true,
         });
 
         // 注入样式
@@ -575,6 +866,153 @@ mod tests {
         assert_eq!(manager.cached_styles_count(), 1);
     }
 
+    #[test]
+    fn test_snapshot_and_restore() {
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Noop,
+            ..StyleManagerConfig::default()
+        });
+        manager.inject_style("color: red;", "base").unwrap();
+        manager.inject_style("margin: 0;", "reset").unwrap();
+
+        let snapshot = manager.snapshot();
+        assert_eq!(
+            snapshot.styles,
+            vec![
+                ("base".to_string(), "color: red;".to_string()),
+                ("reset".to_string(), "margin: 0;".to_string()),
+            ]
+        );
+
+        manager.inject_style("color: blue;", "temp").unwrap();
+        manager.inject_style("color: green;", "base").unwrap();
+        manager.remove_style("reset").unwrap();
+
+        manager.restore(snapshot.clone()).unwrap();
+        assert_eq!(manager.snapshot(), snapshot);
+        assert!(!manager.is_style_cached("temp"));
+        assert_eq!(
+            manager.get_cached_style("base"),
+            Some("color: red;".to_string())
+        );
+        assert!(manager.is_style_cached("reset"));
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_restore_preserves_ssr_injection_order() {
+        let _guard = crate::runtime::injector::SERVER_STYLES_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Ssr,
+            ..StyleManagerConfig::default()
+        });
+        // 类名按字母倒序注入，恢复后不应按类名重新排序
+        manager
+            .inject_style("color: red;", "restore-order-z")
+            .unwrap();
+        manager
+            .inject_style("color: blue;", "restore-order-a")
+            .unwrap();
+        manager
+            .inject_style("color: green;", "restore-order-m")
+            .unwrap();
+        // 服务端缓冲区为全局共享，只比较本测试注入的规则
+        let own_css = |manager: &StyleManager| {
+            manager
+                .injector
+                .collect_ssr_css()
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with(".restore-order-"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let before = own_css(&manager);
+        assert_eq!(
+            before,
+            ".restore-order-z { color: red; }\n\
+             .restore-order-a { color: blue; }\n\
+             .restore-order-m { color: green; }"
+        );
+
+        let snapshot = manager.snapshot();
+        manager
+            .inject_style("margin: 0;", "restore-order-temp")
+            .unwrap();
+        manager.restore(snapshot).unwrap();
+
+        assert_eq!(own_css(&manager), before);
+    }
+
+    #[test]
+    fn test_inject_style_returns_sanitized_class_name() {
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Noop,
+            ..StyleManagerConfig::default()
+        });
+
+        let class_name = manager.inject_style("color: red;", "my button!").unwrap();
+        assert_eq!(class_name, "my-button-");
+        assert!(manager.is_style_cached("my-button-"));
+
+        let class_name = manager.inject_style("color: blue;", "-9lives").unwrap();
+        assert_eq!(class_name, "css--9lives");
+
+        // 合法类名保持不变，重复注入返回同一类名
+        let class_name = manager.inject_style("color: red;", "valid_name").unwrap();
+        assert_eq!(class_name, "valid_name");
+        assert_eq!(
+            manager.inject_style("color: red;", "valid_name").unwrap(),
+            "valid_name"
+        );
+    }
+
+    #[test]
+    fn test_inject_style_rewrites_selector_for_sanitized_class() {
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Noop,
+            inject_enabled: false,
+            ..StyleManagerConfig::default()
+        });
+
+        let class_name = manager
+            .inject_style(
+                ".1st nav { color: red; }\n.1st nav:hover, .1st navbar { content: \".1st nav\"; }",
+                "1st nav",
+            )
+            .unwrap();
+        assert_eq!(class_name, "css-1st-nav");
+        assert_eq!(
+            manager.collect(),
+            ".css-1st-nav { color: red; }\n.css-1st-nav:hover, .1st navbar { content: \".1st nav\"; }"
+        );
+    }
+
+    #[test]
+    fn test_dedup_stats() {
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Noop,
+            ..StyleManagerConfig::default()
+        });
+
+        manager.inject_style("color: red;", "dedup-class").unwrap();
+        manager.inject_style("color: red;", "dedup-class").unwrap();
+
+        let stats = manager.stats();
+        assert_eq!(stats.total_inject_calls, 2);
+        assert_eq!(stats.dedup_hits, 1);
+        assert_eq!(stats.style_count, 1);
+
+        // 相同类名但CSS不同不计为去重命中
+        manager.inject_style("color: blue;", "dedup-class").unwrap();
+        let stats = manager.stats();
+        assert_eq!(stats.total_inject_calls, 3);
+        assert_eq!(stats.dedup_hits, 1);
+        assert_eq!(stats.style_count, 1);
+    }
+
     #[test]
     fn test_lru_cache_strategy() {
         // 创建启用缓存的样式管理器，最大缓存数量为2
@@ -582,6 +1020,7 @@ mod tests {
             max_cached_styles: 2,
             enable_deduplication: true,
             provider_type: ProviderType::Auto,
+            inject_enabled: true,
         });
 
         // 注入第一个样式
@@ -645,6 +1084,7 @@ mod tests {
             max_cached_styles: 5,
             enable_deduplication: true,
             provider_type: ProviderType::Auto,
+            inject_enabled: true,
         });
 
         // 注入5个样式
@@ -684,4 +1124,69 @@ mod tests {
         let nonexistent = manager.get_cached_style("nonexistent");
         assert_eq!(nonexistent, None);
     }
+
+    #[test]
+    fn test_collect_only_mode() {
+        // 仅收集模式：样式记录到缓冲区，不经过 Web 注入器
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Web,
+            inject_enabled: false,
+            ..StyleManagerConfig::default()
+        });
+
+        manager
+            .inject_style(".a { color: red; }", "collect-a")
+            .unwrap();
+        manager
+            .inject_style(".b { color: blue; }", "collect-b")
+            .unwrap();
+        manager
+            .inject_style(".b { color: green; }", "collect-b")
+            .unwrap();
+
+        assert_eq!(
+            manager.collect(),
+            ".a { color: red; }\n.b { color: green; }"
+        );
+        assert!(manager.injector.injected_styles().is_empty());
+
+        let snapshot = manager.snapshot();
+        assert_eq!(
+            snapshot.styles,
+            vec![
+                ("collect-a".to_string(), ".a { color: red; }".to_string()),
+                ("collect-b".to_string(), ".b { color: green; }".to_string()),
+            ]
+        );
+
+        manager.remove_style("collect-a").unwrap();
+        assert_eq!(manager.collect(), ".b { color: green; }");
+
+        manager.clear_all_styles().unwrap();
+        assert!(manager.collect().is_empty());
+
+        manager.restore(snapshot).unwrap();
+        assert_eq!(
+            manager.collect(),
+            ".a { color: red; }\n.b { color: green; }"
+        );
+    }
+
+    #[test]
+    fn test_collect_empty_when_injecting() {
+        let manager = StyleManager::with_config(StyleManagerConfig {
+            provider_type: ProviderType::Noop,
+            ..StyleManagerConfig::default()
+        });
+        manager
+            .inject_style(".a { color: red; }", "inject-a")
+            .unwrap();
+
+        assert!(manager.collect().is_empty());
+        assert!(manager
+            .snapshot()
+            .styles
+            .iter()
+            .any(|(class_name, _)| class_name == "inject-a"));
+    }
 }
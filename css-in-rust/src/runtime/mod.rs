@@ -5,12 +5,20 @@
 pub mod injector;
 pub mod manager;
 pub mod provider;
+pub mod style_meta;
+pub mod utils;
 
 pub use injector::InjectionEnvironment;
 pub use injector::{InjectionError, StyleInjector};
-pub use manager::{StyleManager, StyleManagerConfig};
+pub use manager::{
+    sanitize_class_name, StyleManager, StyleManagerConfig, StyleManagerStats, StyleSnapshot,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use provider::inject_global_style;
 pub use provider::{
-    clear_all_styles, current_environment, generate_style_html, init, init_with_provider,
-    remove_style,
+    clear_all_styles, clear_styles_by_prefix, current_environment, generate_style_html, init,
+    init_with_provider, injected_styles_memory, provider_info, remove_style,
 };
-pub use provider::{inject_style, ProviderType, StyleProvider};
+pub use provider::{inject_style, ProviderType, StyleProvider, StyleProviderInfo};
+pub use style_meta::StyleMeta;
+pub use utils::generate_class_name;
@@ -9,6 +9,9 @@ use std::sync::OnceLock;
 /// Global style injector instance
 static STYLE_INJECTOR: OnceLock<StyleInjector> = OnceLock::new();
 
+/// 覆盖 `ProviderType::Auto` 解析结果的环境变量
+pub const PROVIDER_ENV_VAR: &str = "CSS_IN_RUST_PROVIDER";
+
 /// Provider type for style injection
 ///
 /// 定义了不同的样式注入提供器类型，用于适配不同的运行环境。
@@ -45,23 +48,119 @@ pub enum ProviderType {
 }
 
 impl ProviderType {
+    /// 解析为具体的提供器类型
+    ///
+    /// 非 `Auto` 类型原样返回。`Auto` 的解析优先级：
+    ///
+    /// 1. 环境变量 `CSS_IN_RUST_PROVIDER`（`web` 或 `ssr`，不区分大小写）
+    /// 2. 目标平台默认值：`wasm32` 上为 `Web`，其他平台为 `Ssr`
+    ///
+    /// 无法识别的环境变量值会被忽略。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::ProviderType;
+    ///
+    /// assert_eq!(ProviderType::Noop.resolve(), ProviderType::Noop);
+    /// assert_ne!(ProviderType::Auto.resolve(), ProviderType::Auto);
+    /// ```
+    pub fn resolve(&self) -> ProviderType {
+        if *self != ProviderType::Auto {
+            return *self;
+        }
+
+        let env_override = std::env::var(PROVIDER_ENV_VAR).ok();
+        Self::resolve_auto(env_override.as_deref())
+    }
+
+    /// 根据环境变量覆盖值解析 `Auto`
+    fn resolve_auto(env_override: Option<&str>) -> ProviderType {
+        match env_override.map(|value| value.trim().to_ascii_lowercase()) {
+            Some(value) if value == "web" => ProviderType::Web,
+            Some(value) if value == "ssr" => ProviderType::Ssr,
+            _ if cfg!(target_arch = "wasm32") => ProviderType::Web,
+            _ => ProviderType::Ssr,
+        }
+    }
+
     /// 转换为对应的注入环境
     pub(crate) fn to_injection_environment(&self) -> InjectionEnvironment {
-        match self {
-            ProviderType::Auto => {
-                // 自动检测环境
-                #[cfg(target_arch = "wasm32")]
-                return InjectionEnvironment::Browser;
-
-                #[cfg(not(target_arch = "wasm32"))]
-                return InjectionEnvironment::Server;
-            }
-            ProviderType::Web => InjectionEnvironment::Browser,
+        match self.resolve() {
+            ProviderType::Auto | ProviderType::Web => InjectionEnvironment::Browser,
             ProviderType::Ssr => InjectionEnvironment::Server,
             ProviderType::Noop => InjectionEnvironment::Noop,
             ProviderType::Isomorphic => InjectionEnvironment::Isomorphic,
         }
     }
+
+    /// 由注入环境得到对应的提供器类型
+    pub(crate) fn from_injection_environment(environment: InjectionEnvironment) -> Self {
+        match environment {
+            InjectionEnvironment::Browser => ProviderType::Web,
+            InjectionEnvironment::Server => ProviderType::Ssr,
+            InjectionEnvironment::Isomorphic => ProviderType::Isomorphic,
+            InjectionEnvironment::Noop => ProviderType::Noop,
+        }
+    }
+
+    /// 获取该提供器类型在当前平台上的能力信息
+    ///
+    /// `Auto` 会先解析为当前平台实际使用的提供器类型。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::runtime::ProviderType;
+    ///
+    /// let info = ProviderType::Noop.info();
+    /// assert!(!info.supports_removal);
+    /// assert!(!info.supports_ssr_collect);
+    /// ```
+    pub fn info(&self) -> StyleProviderInfo {
+        let provider_type = self.resolve();
+
+        // 服务端样式收集只在非 wasm 平台可用
+        let ssr_available = cfg!(not(target_arch = "wasm32"));
+
+        let (supports_removal, supports_ssr_collect) = match provider_type {
+            ProviderType::Web => (true, false),
+            ProviderType::Ssr | ProviderType::Isomorphic => (true, ssr_available),
+            ProviderType::Noop | ProviderType::Auto => (false, false),
+        };
+
+        StyleProviderInfo {
+            provider_type,
+            supports_removal,
+            supports_ssr_collect,
+        }
+    }
+}
+
+/// Capabilities of a style provider
+///
+/// 描述样式提供器的类型及其支持的能力，便于在运行时根据环境选择不同的处理方式。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::{ProviderType, StyleProvider, StyleInjector};
+///
+/// let info = StyleInjector::new_ssr().info();
+/// assert_eq!(info.provider_type, ProviderType::Ssr);
+///
+/// if info.supports_ssr_collect {
+///     // 渲染结束后收集样式写入响应
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyleProviderInfo {
+    /// 实际使用的提供器类型
+    pub provider_type: ProviderType,
+    /// 是否支持移除已注入的样式
+    pub supports_removal: bool,
+    /// 是否支持收集样式用于服务端渲染输出
+    pub supports_ssr_collect: bool,
 }
 
 /// Trait for style providers
@@ -130,6 +229,35 @@ pub trait StyleProvider {
     ///
     /// 成功时返回`Ok(())`，失败时返回包含错误信息的`InjectionError`
     fn clear_all_styles(&self) -> Result<(), crate::runtime::InjectionError>;
+
+    /// Describe the provider and its capabilities
+    ///
+    /// 默认实现返回当前平台自动选择的提供器信息。
+    fn info(&self) -> StyleProviderInfo {
+        ProviderType::Auto.info()
+    }
+}
+
+impl StyleProvider for StyleInjector {
+    fn inject_styles(
+        &self,
+        css: &str,
+        class_name: &str,
+    ) -> Result<(), crate::runtime::InjectionError> {
+        self.inject_style(css, class_name)
+    }
+
+    fn remove_styles(&self, class_name: &str) -> Result<(), crate::runtime::InjectionError> {
+        self.remove_style(class_name)
+    }
+
+    fn clear_all_styles(&self) -> Result<(), crate::runtime::InjectionError> {
+        StyleInjector::clear_all_styles(self)
+    }
+
+    fn info(&self) -> StyleProviderInfo {
+        ProviderType::from_injection_environment(self.environment()).info()
+    }
 }
 
 /// Initialize the global style system
@@ -231,6 +359,36 @@ pub fn inject_style(css: &str, class_name: &str) -> String {
     class_name.to_string()
 }
 
+/// Inject an unscoped global stylesheet
+///
+/// 将全局 CSS 原样记录到全局样式注入器的服务端缓冲区，不包裹类名选择器。
+/// `css_global!` 在非浏览器目标上通过此函数让 `:root` 变量、`@keyframes`
+/// 和重置样式出现在服务端渲染输出中。
+///
+/// # Arguments
+///
+/// * `css` - 完整的全局 CSS
+/// * `style_id` - 全局样式的标识，相同标识只保留一份
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::{provider, ProviderType};
+///
+/// provider::init_with_provider(ProviderType::Ssr);
+/// provider::inject_global_style("body{margin:0}", "css-global-reset");
+///
+/// assert!(provider::generate_style_html().contains("body{margin:0}"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn inject_global_style(css: &str, style_id: &str) {
+    let injector = STYLE_INJECTOR.get_or_init(StyleInjector::new);
+
+    if let Err(e) = injector.inject_global_style(css, style_id) {
+        eprintln!("Failed to inject global style '{}': {:?}", style_id, e);
+    }
+}
+
 /// Remove a style by class name
 ///
 /// 通过类名移除之前注入的样式。
@@ -294,6 +452,53 @@ pub fn clear_all_styles() -> Result<(), crate::runtime::InjectionError> {
     injector.clear_all_styles()
 }
 
+/// Remove all styles whose class name starts with the given prefix
+///
+/// 移除类名以指定前缀开头的样式，不影响其他样式。
+/// 适用于微前端卸载时只清理自身样式的场景。
+///
+/// # Arguments
+///
+/// * `prefix` - 类名前缀
+///
+/// # Returns
+///
+/// 成功时返回移除的样式数量，失败时返回包含错误信息的`InjectionError`
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::provider;
+///
+/// provider::inject_style("color: red;", "widget-a");
+/// provider::inject_style("color: blue;", "shell-nav");
+///
+/// // 卸载 widget 时只移除其样式
+/// let removed = provider::clear_styles_by_prefix("widget-").unwrap();
+/// assert_eq!(removed, 1);
+/// ```
+pub fn clear_styles_by_prefix(prefix: &str) -> Result<usize, crate::runtime::InjectionError> {
+    let injector = STYLE_INJECTOR.get_or_init(StyleInjector::new);
+    injector.remove_styles_by_prefix(prefix)
+}
+
+/// Check whether a style has been injected
+///
+/// 检查全局样式注入器中是否已注入指定类名的样式。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::provider;
+///
+/// provider::inject_style("color: red;", "injected-check");
+/// assert!(provider::is_style_injected("injected-check"));
+/// ```
+pub fn is_style_injected(class_name: &str) -> bool {
+    let injector = STYLE_INJECTOR.get_or_init(StyleInjector::new);
+    injector.is_style_injected(class_name)
+}
+
 /// Get the current environment of the style injector
 ///
 /// 获取当前样式注入器的运行环境。
@@ -319,6 +524,42 @@ pub fn current_environment() -> InjectionEnvironment {
     injector.environment()
 }
 
+/// Get the capabilities of the active style provider
+///
+/// 获取全局样式注入器当前使用的提供器类型及其能力。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::provider;
+///
+/// let info = provider::provider_info();
+/// if info.supports_removal {
+///     provider::remove_style("stale-class").unwrap();
+/// }
+/// ```
+pub fn provider_info() -> StyleProviderInfo {
+    let injector = STYLE_INJECTOR.get_or_init(StyleInjector::new);
+    injector.info()
+}
+
+/// Estimate the memory used by injected styles
+///
+/// 估算全局样式注入器中已注入样式占用的字节数。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::provider;
+///
+/// provider::inject_style(".memory-demo { color: red; }", "memory-demo");
+/// assert!(provider::injected_styles_memory() > 0);
+/// ```
+pub fn injected_styles_memory() -> usize {
+    let injector = STYLE_INJECTOR.get_or_init(StyleInjector::new);
+    injector.estimated_memory()
+}
+
 /// Generate HTML style tags for server-side rendering
 ///
 /// 生成包含所有收集样式的HTML样式标签，用于服务端渲染。
@@ -373,12 +614,65 @@ mod tests {
         assert_eq!(result, class_name);
     }
 
+    #[test]
+    fn test_clear_styles_by_prefix() {
+        // 使用独立的注入器，避免与并行测试共享全局状态
+        let injector = StyleInjector::new_noop();
+        injector.inject_style("color: red;", "app1-x").unwrap();
+        injector.inject_style("color: blue;", "app2-y").unwrap();
+
+        let removed = injector.remove_styles_by_prefix("app1-").unwrap();
+        assert_eq!(removed, 1);
+
+        assert!(!injector.is_style_injected("app1-x"));
+        assert!(injector.is_style_injected("app2-y"));
+    }
+
     #[test]
     fn test_provider_type_equality() {
         assert_eq!(ProviderType::Auto, ProviderType::Auto);
         assert_ne!(ProviderType::Auto, ProviderType::Web);
     }
 
+    #[test]
+    fn test_provider_info_reports_capabilities() {
+        let mut web = StyleInjector::new_noop();
+        web.set_environment(InjectionEnvironment::Browser);
+        assert_eq!(
+            web.info(),
+            StyleProviderInfo {
+                provider_type: ProviderType::Web,
+                supports_removal: true,
+                supports_ssr_collect: false,
+            }
+        );
+
+        let ssr = StyleInjector::new_ssr().info();
+        assert_eq!(ssr.provider_type, ProviderType::Ssr);
+        assert!(ssr.supports_removal);
+        assert_eq!(ssr.supports_ssr_collect, cfg!(not(target_arch = "wasm32")));
+
+        let noop = ProviderType::Noop.info();
+        assert!(!noop.supports_removal && !noop.supports_ssr_collect);
+        assert_ne!(ProviderType::Auto.info().provider_type, ProviderType::Auto);
+    }
+
+    #[test]
+    fn test_auto_resolution_env_override() {
+        let target_default = if cfg!(target_arch = "wasm32") {
+            ProviderType::Web
+        } else {
+            ProviderType::Ssr
+        };
+        assert_eq!(ProviderType::resolve_auto(None), target_default);
+        assert_eq!(ProviderType::resolve_auto(Some("bogus")), target_default);
+        assert_eq!(ProviderType::resolve_auto(Some(" SSR ")), ProviderType::Ssr);
+
+        assert_eq!(ProviderType::resolve_auto(Some("web")), ProviderType::Web);
+        assert_eq!(ProviderType::resolve_auto(Some("Ssr")), ProviderType::Ssr);
+        assert_eq!(ProviderType::Web.resolve(), ProviderType::Web);
+    }
+
     #[test]
     fn test_provider_type_to_injection_environment() {
         assert_eq!(
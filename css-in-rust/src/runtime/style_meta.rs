@@ -0,0 +1,41 @@
+//! Style metadata
+//!
+//! `css_with_meta!` 宏在编译期生成的样式元数据。
+
+/// 样式元数据
+///
+/// 包含类名、内容哈希、引用的CSS变量和声明数量，便于构建主题变量到样式的依赖图，
+/// 在变量变化时只失效引用了该变量的样式。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::StyleMeta;
+///
+/// let meta = StyleMeta {
+///     class_name: "css-1a2b3c4d".to_string(),
+///     hash: "1a2b3c4d",
+///     variables: &["--primary"],
+///     declaration_count: 2,
+/// };
+/// assert!(meta.references("--primary"));
+/// assert!(!meta.references("--gap"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleMeta {
+    /// 生成的类名，与 `css!` 对相同CSS生成的类名一致
+    pub class_name: String,
+    /// CSS内容的 SHA-256 哈希（十六进制）
+    pub hash: &'static str,
+    /// 通过 `var(--x)` 引用的CSS变量，按首次出现顺序排列
+    pub variables: &'static [&'static str],
+    /// 声明数量，包括嵌套规则中的声明
+    pub declaration_count: usize,
+}
+
+impl StyleMeta {
+    /// 检查样式是否引用了指定CSS变量
+    pub fn references(&self, variable: &str) -> bool {
+        self.variables.contains(&variable)
+    }
+}
@@ -0,0 +1,47 @@
+//! Runtime utilities
+//!
+//! 运行时共享的辅助函数。
+
+use sha2::{Digest, Sha256};
+
+/// 根据CSS内容生成确定性的类名
+///
+/// 使用 SHA-256 哈希的前 8 个十六进制字符，格式为 `css-xxxxxxxx`，与 `css!` 宏在编译期生成的类名一致。
+/// 与 `DefaultHasher` 不同，结果不随 Rust 版本或平台变化，因此服务端渲染和客户端水合得到相同的类名。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::runtime::generate_class_name;
+///
+/// let class_name = generate_class_name("color: red;");
+/// assert_eq!(class_name, "css-6414e3e5");
+/// assert_eq!(class_name, generate_class_name("color: red;"));
+/// ```
+pub fn generate_class_name(css: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(css.as_bytes());
+    let hash = hasher.finalize();
+
+    format!("css-{}", hex::encode(&hash[..4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_class_name_is_stable() {
+        let css = "display: flex; gap: 8px;";
+        let first = generate_class_name(css);
+
+        for _ in 0..10 {
+            assert_eq!(generate_class_name(css), first);
+        }
+        assert_eq!(first.len(), "css-".len() + 8);
+        assert_ne!(first, generate_class_name("display: grid;"));
+
+        // 固定值确保跨平台、跨版本一致
+        assert_eq!(generate_class_name("color: red;"), "css-6414e3e5");
+    }
+}
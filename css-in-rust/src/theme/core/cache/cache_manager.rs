@@ -2,6 +2,7 @@ use crate::theme::core::cache::cache_entity::{
     CacheEntity, CacheValue, CssVarCacheValue, StyleCacheValue, TokenCacheValue, CSS_VAR_PREFIX,
     STYLE_PREFIX, TOKEN_PREFIX,
 };
+use crate::theme::core::token::definitions::ThemeVariant;
 use serde_json::Value;
 use std::sync::{Arc, LazyLock, Mutex};
 
@@ -214,6 +215,45 @@ impl CacheManager {
         style_value
     }
 
+    /// 获取或创建与主题绑定的样式缓存
+    ///
+    /// 以 (样式输入, 主题名称, 主题模式) 作为缓存键，只有主题或输入变化时才会重新执行
+    /// 创建函数，主题不变时的重复渲染直接复用缓存结果。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 样式输入的缓存键
+    /// * `theme_name` - 当前主题名称
+    /// * `mode` - 当前主题模式
+    /// * `creator` - 创建样式的闭包，返回 (样式字符串, 样式ID, 效果样式, 优先级)
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::cache::CacheManager;
+    /// use css_in_rust::theme::ThemeVariant;
+    ///
+    /// let cache_manager = CacheManager::new("app-container");
+    ///
+    /// let light = cache_manager.get_or_create_themed_style("button", "default", ThemeVariant::Light, || {
+    ///     (".button { color: #000; }".to_string(), "btn-light".to_string(), None, 0)
+    /// });
+    /// let dark = cache_manager.get_or_create_themed_style("button", "default", ThemeVariant::Dark, || {
+    ///     (".button { color: #fff; }".to_string(), "btn-dark".to_string(), None, 0)
+    /// });
+    ///
+    /// assert_ne!(light.style_id, dark.style_id);
+    /// ```
+    pub fn get_or_create_themed_style(
+        &self,
+        key: &str,
+        theme_name: &str,
+        mode: ThemeVariant,
+        creator: impl FnOnce() -> (String, String, Option<String>, i32),
+    ) -> StyleCacheValue {
+        self.get_or_create_style(&format!("{}@{}:{}", key, theme_name, mode), creator)
+    }
+
     /// 获取或创建令牌缓存
     ///
     /// 尝试从缓存中获取令牌，如果不存在则使用提供的创建函数生成新的令牌并缓存。
@@ -610,3 +650,39 @@ impl CacheManager {
         Ok(f(&*manager))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_themed_style_recomputes_only_on_theme_change() {
+        let manager = CacheManager::new("themed-test");
+        let runs = Cell::new(0);
+        let render = |theme: &str, mode: ThemeVariant| {
+            manager.get_or_create_themed_style("card", theme, mode, || {
+                runs.set(runs.get() + 1);
+                (
+                    format!(".card-{}-{} {{}}", theme, mode),
+                    format!("card-{}-{}", theme, mode),
+                    None,
+                    0,
+                )
+            })
+        };
+
+        for _ in 0..3 {
+            render("default", ThemeVariant::Light);
+        }
+        assert_eq!(runs.get(), 1);
+
+        let dark = render("default", ThemeVariant::Dark);
+        assert_eq!(runs.get(), 2);
+        assert_eq!(dark.style_id, "card-default-dark");
+
+        render("brand", ThemeVariant::Dark);
+        render("default", ThemeVariant::Light);
+        assert_eq!(runs.get(), 3);
+    }
+}
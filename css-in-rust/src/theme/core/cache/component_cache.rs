@@ -477,6 +477,28 @@ impl ComponentStyleCache {
         removed_count
     }
 
+    /// 获取组件的所有缓存样式
+    ///
+    /// 按类名排序返回指定组件名下的缓存样式，不影响命中统计和使用计数。
+    ///
+    /// # 参数
+    ///
+    /// * `component` - 组件名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回该组件的缓存样式列表，没有缓存时返回空列表。
+    pub fn styles_for_component(&self, component: &str) -> Vec<&CachedComponentStyle> {
+        let mut styles: Vec<&CachedComponentStyle> = self
+            .cache
+            .iter()
+            .filter(|(key, _)| key.component == component)
+            .map(|(_, style)| style)
+            .collect();
+        styles.sort_by(|a, b| a.class_name.cmp(&b.class_name));
+        styles
+    }
+
     /// 获取缓存统计信息
     ///
     /// 返回当前缓存的使用统计信息，包括命中率、项数等。
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::theme::core::css::CssGenerator;
 use crate::theme::theme_types::Theme;
 use crate::theme::ThemeVariant;
 
@@ -65,6 +66,8 @@ pub struct DefaultComponentStyleGenerator {
     pub state_style_fns: HashMap<String, Box<dyn Fn(&Theme) -> String + Send + Sync>>,
     /// 尺寸样式生成函数
     pub size_style_fns: HashMap<String, Box<dyn Fn(&Theme) -> String + Send + Sync>>,
+    /// 用于将样式限定到组件类名的 CSS 生成器
+    css_generator: CssGenerator,
 }
 
 impl DefaultComponentStyleGenerator {
@@ -79,6 +82,7 @@ impl DefaultComponentStyleGenerator {
             variant_style_fns: HashMap::new(),
             state_style_fns: HashMap::new(),
             size_style_fns: HashMap::new(),
+            css_generator: CssGenerator::new(),
         }
     }
 
@@ -119,10 +123,11 @@ impl DefaultComponentStyleGenerator {
         for variant in &self.config.variants {
             if let Some(style_fn) = self.variant_style_fns.get(variant) {
                 let variant_css = style_fn(theme);
-                css.push_str(&format!(
-                    ".{}-{} {{\n{}\n}}\n",
-                    self.config.name, variant, variant_css
-                ));
+                css.push_str(
+                    &self
+                        .css_generator
+                        .scope_css(&variant_css, &format!("{}-{}", self.config.name, variant)),
+                );
             }
         }
 
@@ -136,9 +141,9 @@ impl DefaultComponentStyleGenerator {
         for state in &self.config.states {
             if let Some(style_fn) = self.state_style_fns.get(state) {
                 let state_css = style_fn(theme);
-                css.push_str(&format!(
-                    ".{}:{}  {{\n{}\n}}\n",
-                    self.config.name, state, state_css
+                css.push_str(&self.css_generator.scope_css(
+                    &format!("&:{} {{ {} }}", state, state_css),
+                    &self.config.name,
                 ));
             }
         }
@@ -153,10 +158,11 @@ impl DefaultComponentStyleGenerator {
         for size in &self.config.sizes {
             if let Some(style_fn) = self.size_style_fns.get(size) {
                 let size_css = style_fn(theme);
-                css.push_str(&format!(
-                    ".{}-{} {{\n{}\n}}\n",
-                    self.config.name, size, size_css
-                ));
+                css.push_str(
+                    &self
+                        .css_generator
+                        .scope_css(&size_css, &format!("{}-{}", self.config.name, size)),
+                );
             }
         }
 
@@ -170,7 +176,7 @@ impl ComponentStyleGenerator for DefaultComponentStyleGenerator {
 
         // 生成基础样式
         let base_css = (self.base_style_fn)(theme);
-        css.push_str(&format!(".{} {{\n{}\n}}\n", self.config.name, base_css));
+        css.push_str(&self.css_generator.scope_css(&base_css, &self.config.name));
 
         // 生成变体样式
         css.push_str(&self.generate_variants_style(theme));
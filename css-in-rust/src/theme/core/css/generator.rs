@@ -342,6 +342,83 @@ impl CssGenerator {
         self.generate_rule(&format!(".{}", class_name), declarations)
     }
 
+    /// 将任意 CSS 限定到类名作用域
+    ///
+    /// 顶层声明包装到 `.class` 规则中；嵌套规则中的 `&` 替换为父选择器，
+    /// 不含 `&` 的选择器作为后代选择器；`@media`、`@supports` 等条件规则保留并在内部继续限定；
+    /// `@keyframes` 和 `@font-face` 原样保留为全局规则；`@import` 等无块 at 规则原样输出在最前面。
+    ///
+    /// # 参数
+    ///
+    /// * `css` - 待限定的 CSS，可包含声明、嵌套规则和 at 规则
+    /// * `class` - 类名（不包含前导点）
+    ///
+    /// # 返回值
+    ///
+    /// 返回限定到类名作用域的 CSS 字符串。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::css::CssGenerator;
+    ///
+    /// let generator = CssGenerator::new();
+    /// let css = generator.scope_css("color: red; &:hover { color: blue; }", "button");
+    /// assert!(css.contains(".button {"));
+    /// assert!(css.contains(".button:hover {"));
+    /// ```
+    pub fn scope_css(&self, css: &str, class: &str) -> String {
+        self.scope_block(&strip_comments(css), &format!(".{}", class))
+    }
+
+    /// 在给定选择器下限定一段 CSS 块
+    fn scope_block(&self, css: &str, selector: &str) -> String {
+        let (statements, declarations, blocks) = split_css_items(css);
+        let mut output = String::new();
+
+        for statement in statements {
+            output.push_str(&statement);
+            output.push(';');
+            if !self.minify {
+                output.push('\n');
+            }
+        }
+
+        if !declarations.is_empty() {
+            output.push_str(&self.generate_rule(selector, &declarations));
+        }
+
+        for (prelude, body) in blocks {
+            let lower = prelude.to_ascii_lowercase();
+            if lower.starts_with("@font-face") || is_keyframes_prelude(&lower) {
+                output.push_str(&self.wrap_block(&prelude, body.trim()));
+            } else if prelude.starts_with('@') {
+                let inner = self.scope_block(&body, selector);
+                output.push_str(&self.wrap_block(&prelude, inner.trim_end()));
+            } else {
+                let nested_selector = resolve_nested_selector(&prelude, selector);
+                output.push_str(&self.scope_block(&body, &nested_selector));
+            }
+        }
+
+        output
+    }
+
+    /// 使用 `prelude { content }` 包装内容
+    fn wrap_block(&self, prelude: &str, content: &str) -> String {
+        if self.minify {
+            return format!("{}{{{}}}", prelude, content);
+        }
+
+        let mut css = String::new();
+        writeln!(css, "{} {{", prelude).unwrap();
+        for line in content.lines() {
+            writeln!(css, "  {}", line.trim_end()).unwrap();
+        }
+        css.push_str("}\n");
+        css
+    }
+
     /// 生成CSS变量
     ///
     /// 根据主题变体生成 CSS 变量声明。
@@ -606,6 +683,159 @@ impl CssGenerator {
     }
 }
 
+/// 移除 CSS 注释
+fn strip_comments(css: &str) -> String {
+    let mut output = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some(start) = rest.find("/*") {
+        output.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// 属性与值（或选择器与块内容）的列表
+type CssPairs = Vec<(String, String)>;
+
+/// 将一段 CSS 拆分为顶层 at 规则语句（如 `@import`）、顶层声明和嵌套块
+fn split_css_items(css: &str) -> (Vec<String>, CssPairs, CssPairs) {
+    let mut statements = Vec::new();
+    let mut declarations = Vec::new();
+    let mut blocks = Vec::new();
+    let mut buffer = String::new();
+    let mut body = String::new();
+    let mut prelude = String::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut parens = 0usize;
+
+    for c in css.chars() {
+        let target = if depth == 0 { &mut buffer } else { &mut body };
+
+        if let Some(q) = quote {
+            target.push(c);
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => {
+                quote = Some(c);
+                target.push(c);
+            }
+            '(' => {
+                parens += 1;
+                target.push(c);
+            }
+            ')' => {
+                parens = parens.saturating_sub(1);
+                target.push(c);
+            }
+            '{' => {
+                if depth == 0 {
+                    prelude = buffer.trim().to_string();
+                    buffer.clear();
+                } else {
+                    body.push(c);
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    blocks.push((std::mem::take(&mut prelude), std::mem::take(&mut body)));
+                } else {
+                    body.push(c);
+                }
+            }
+            ';' if depth == 0 && parens == 0 => {
+                push_item(&mut statements, &mut declarations, &buffer);
+                buffer.clear();
+            }
+            _ => target.push(c),
+        }
+    }
+    push_item(&mut statements, &mut declarations, &buffer);
+
+    (statements, declarations, blocks)
+}
+
+/// 将顶层片段归入 at 规则语句或声明
+fn push_item(statements: &mut Vec<String>, declarations: &mut CssPairs, text: &str) {
+    let text = text.trim();
+    if text.starts_with('@') {
+        statements.push(text.to_string());
+    } else {
+        push_declaration(declarations, text);
+    }
+}
+
+/// 解析 `property: value` 形式的声明
+fn push_declaration(declarations: &mut Vec<(String, String)>, text: &str) {
+    if let Some((property, value)) = text.trim().split_once(':') {
+        let (property, value) = (property.trim(), value.trim());
+        if !property.is_empty() && !value.is_empty() {
+            declarations.push((property.to_string(), value.to_string()));
+        }
+    }
+}
+
+/// 判断是否为 `@keyframes`（含浏览器前缀）规则
+fn is_keyframes_prelude(prelude: &str) -> bool {
+    prelude.starts_with('@')
+        && prelude
+            .split_whitespace()
+            .next()
+            .is_some_and(|keyword| keyword.ends_with("keyframes"))
+}
+
+/// 在括号外按逗号拆分选择器列表
+fn split_selector_list(selectors: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in selectors.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(selectors[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(selectors[start..].trim());
+
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// 将嵌套选择器与父选择器组合
+fn resolve_nested_selector(nested: &str, parent: &str) -> String {
+    let mut resolved = Vec::new();
+
+    for parent in split_selector_list(parent) {
+        for nested in split_selector_list(nested) {
+            if nested.contains('&') {
+                resolved.push(nested.replace('&', parent));
+            } else {
+                resolved.push(format!("{} {}", parent, nested));
+            }
+        }
+    }
+
+    resolved.join(", ")
+}
+
 impl Default for CssGenerator {
     /// 创建默认的 CSS 生成器
     ///
@@ -643,6 +873,68 @@ mod tests {
         assert!(css.is_ok());
     }
 
+    #[test]
+    fn test_scope_flat_rules() {
+        let generator = CssGenerator::new();
+        let css = generator.scope_css("color: red; padding: 4px 8px", "card");
+        assert_eq!(css, ".card {\n  color: red;\n  padding: 4px 8px;\n}\n");
+    }
+
+    #[test]
+    fn test_scope_nested_rules() {
+        let generator = CssGenerator::new().with_minify(true);
+        let css = generator.scope_css(
+            "color: red; &:hover { color: blue; } .icon, & + & { margin: 0; }",
+            "btn",
+        );
+        assert_eq!(
+            css,
+            ".btn{color:red;}.btn:hover{color:blue;}.btn .icon, .btn + .btn{margin:0;}"
+        );
+    }
+
+    #[test]
+    fn test_scope_declarations_with_pseudo_rule() {
+        // 框架适配器传入的典型组件 CSS：声明与伪类规则混合
+        let generator = CssGenerator::new();
+        let css = generator.scope_css("color: red; &:hover { color: blue; }", "c");
+        assert_eq!(
+            css,
+            ".c {\n  color: red;\n}\n.c:hover {\n  color: blue;\n}\n"
+        );
+        assert!(!css.contains("{ .c"));
+    }
+
+    #[test]
+    fn test_scope_keeps_import_rules() {
+        let generator = CssGenerator::new().with_minify(true);
+        let css = generator.scope_css(
+            "color: red; @import url(\"https://fonts.example.com/a.css\"); &:hover { color: blue; }",
+            "btn",
+        );
+        assert_eq!(
+            css,
+            "@import url(\"https://fonts.example.com/a.css\");.btn{color:red;}.btn:hover{color:blue;}"
+        );
+    }
+
+    #[test]
+    fn test_scope_media_query_and_global_at_rules() {
+        let generator = CssGenerator::new().with_minify(true);
+        let css = generator.scope_css(
+            "@media (max-width: 768px) { display: none; &:focus { outline: 0; } } \
+             @keyframes spin { to { transform: rotate(360deg); } } \
+             @font-face { font-family: Icons; src: url(\"icons.woff\"); }",
+            "nav",
+        );
+        assert_eq!(
+            css,
+            "@media (max-width: 768px){.nav{display:none;}.nav:focus{outline:0;}}\
+             @keyframes spin{to { transform: rotate(360deg); }}\
+             @font-face{font-family: Icons; src: url(\"icons.woff\");}"
+        );
+    }
+
     #[test]
     fn test_utility_classes_generation() {
         let mut generator = CssGenerator::new();
@@ -242,13 +242,7 @@ impl StylePipeline {
     /// assert_eq!(class_name.len(), 12); // "css-" + 8个字符
     /// ```
     fn generate_class_name(&self, css: &str) -> String {
-        use sha2::{Digest, Sha256};
-
-        let mut hasher = Sha256::new();
-        hasher.update(css.as_bytes());
-        let hash = hasher.finalize();
-
-        format!("css-{}", hex::encode(&hash[..4]))
+        crate::runtime::generate_class_name(css)
     }
 }
 
@@ -94,6 +94,35 @@ pub struct CssVariableInjector {
     current_variables: HashMap<String, String>,
 }
 
+/// CSS 变量被拒绝的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableRejectReason {
+    /// 变量名不以 `--` 开头
+    InvalidName,
+    /// 变量值为空
+    EmptyValue,
+}
+
+/// 被拒绝注入的 CSS 变量
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedVariable {
+    /// 变量名
+    pub name: String,
+    /// 变量值
+    pub value: String,
+    /// 拒绝原因
+    pub reason: VariableRejectReason,
+}
+
+/// CSS 变量注入结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariableInjectionResult {
+    /// 成功注入的变量数量
+    pub injected: usize,
+    /// 被拒绝的变量，按变量名排序
+    pub rejected: Vec<RejectedVariable>,
+}
+
 /// 注入策略
 #[derive(Debug, Clone)]
 pub enum InjectionStrategy {
@@ -189,18 +218,8 @@ impl CssVariableManager {
 
     /// 解析 CSS 变量字符串并添加到变量集合
     fn parse_and_add_css_variables(&mut self, css: &str) {
-        for line in css.lines() {
-            let line = line.trim();
-            if line.is_empty() || !line.starts_with("--") {
-                continue;
-            }
-
-            if let Some((name, value)) = line.split_once(':') {
-                let name = name.trim();
-                let value = value.trim().trim_end_matches(';').trim();
-                self.variables.insert(name.to_string(), value.to_string());
-            }
-        }
+        self.variables
+            .extend(CssVariableUtils::parse_css_variables(css));
     }
 
     /// 添加变量
@@ -396,30 +415,71 @@ impl CssVariableInjector {
     }
 
     /// 注入 CSS 变量
+    ///
+    /// 变量名不以 `--` 开头或值为空的条目不会被注入，而是在结果的 `rejected` 中返回，
+    /// 其余变量照常注入。
     pub fn inject_css_variables(
         &mut self,
         variables: &HashMap<String, String>,
-    ) -> Result<(), String> {
+    ) -> Result<VariableInjectionResult, String> {
+        let (valid, rejected) = Self::validate_variables(variables);
+
         // 检查是否有变化
-        if &self.current_variables == variables {
-            return Ok(());
+        if self.current_variables == valid {
+            return Ok(VariableInjectionResult {
+                injected: valid.len(),
+                rejected,
+            });
         }
 
         // 生成 CSS 字符串
         let mut css = format!("{} {{\n", self.target_selector);
 
-        for (name, value) in variables {
+        for (name, value) in &valid {
             css.push_str(&format!("  {}: {};\n", name, value));
         }
 
         css.push_str("}\n");
 
         // 保存当前状态
-        self.current_variables = variables.clone();
+        let injected = valid.len();
+        self.current_variables = valid;
         self.current_css = Some(css.clone());
 
         // 注入代码的实现将因环境而异，这里只是一个存根
-        Ok(())
+        Ok(VariableInjectionResult { injected, rejected })
+    }
+
+    /// 拆分有效变量和被拒绝的变量
+    fn validate_variables(
+        variables: &HashMap<String, String>,
+    ) -> (HashMap<String, String>, Vec<RejectedVariable>) {
+        let mut valid = HashMap::new();
+        let mut rejected = Vec::new();
+
+        for (name, value) in variables {
+            let reason = if !name.starts_with("--") || name.len() <= 2 {
+                Some(VariableRejectReason::InvalidName)
+            } else if value.trim().is_empty() {
+                Some(VariableRejectReason::EmptyValue)
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => rejected.push(RejectedVariable {
+                    name: name.clone(),
+                    value: value.clone(),
+                    reason,
+                }),
+                None => {
+                    valid.insert(name.clone(), value.clone());
+                }
+            }
+        }
+
+        rejected.sort_by(|a, b| a.name.cmp(&b.name));
+        (valid, rejected)
     }
 
     /// 获取当前 CSS 内容
@@ -495,6 +555,99 @@ impl CssVariableUtils {
         variables
     }
 
+    /// 解析 CSS 变量声明
+    ///
+    /// 按顶层 `;` 拆分声明，括号和引号内的 `;`、`:` 不会被视为分隔符，因此值可以跨行，
+    /// 也可以包含 `url(data:...)` 这样的冒号。输入可以是裸声明，也可以带有 `:root { ... }` 这样的规则包裹。
+    /// 值末尾的 `!important` 会被移除，跨行值中的换行和缩进会折叠为单个空格，引号字符串内的空白保持不变。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::css::variables::CssVariableUtils;
+    ///
+    /// let vars = CssVariableUtils::parse_css_variables(
+    ///     ":root {\n  --shadow: 0 1px 2px red,\n    0 2px 4px blue;\n  --gap: 4px !important;\n}",
+    /// );
+    /// assert_eq!(vars[0], ("--shadow".to_string(), "0 1px 2px red, 0 2px 4px blue".to_string()));
+    /// assert_eq!(vars[1], ("--gap".to_string(), "4px".to_string()));
+    /// ```
+    pub fn parse_css_variables(css: &str) -> Vec<(String, String)> {
+        let mut variables = Vec::new();
+        let mut declaration = String::new();
+        let mut parens = 0usize;
+        let mut quote: Option<char> = None;
+        let mut chars = css.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if let Some(q) = quote {
+                declaration.push(c);
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        declaration.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
+                continue;
+            }
+
+            match c {
+                '/' if chars.peek() == Some(&'*') => {
+                    // 跳过注释
+                    chars.next();
+                    let mut previous = '\0';
+                    for c in chars.by_ref() {
+                        if previous == '*' && c == '/' {
+                            break;
+                        }
+                        previous = c;
+                    }
+                }
+                '"' | '\'' => {
+                    quote = Some(c);
+                    declaration.push(c);
+                }
+                '(' => {
+                    parens += 1;
+                    declaration.push(c);
+                }
+                ')' => {
+                    parens = parens.saturating_sub(1);
+                    declaration.push(c);
+                }
+                // 规则前导（如 `:root`）不是声明
+                '{' if parens == 0 => declaration.clear(),
+                ';' | '}' if parens == 0 => {
+                    Self::push_variable_declaration(&mut variables, &declaration);
+                    declaration.clear();
+                }
+                _ => declaration.push(c),
+            }
+        }
+        Self::push_variable_declaration(&mut variables, &declaration);
+
+        variables
+    }
+
+    /// 解析单条 `--name: value` 声明
+    fn push_variable_declaration(variables: &mut Vec<(String, String)>, declaration: &str) {
+        let Some((name, value)) = declaration.trim().split_once(':') else {
+            return;
+        };
+        let name = name.trim();
+        if !name.starts_with("--") {
+            return;
+        }
+
+        let value = crate::theme::core::optimize::collapse_whitespace(value.trim());
+        let value = match value.strip_suffix("!important") {
+            Some(rest) => rest.trim_end().to_string(),
+            None => value,
+        };
+        variables.push((name.to_string(), value));
+    }
+
     /// 验证 CSS 变量名
     pub fn validate_variable_name(name: &str) -> bool {
         name.starts_with("--")
@@ -646,4 +799,100 @@ mod tests {
         assert!(vars.contains(&"--primary-color".to_string()));
         assert!(vars.contains(&"--bg-color".to_string()));
     }
+
+    #[test]
+    fn test_parse_multiline_variable() {
+        let css = ":root {
+  --card-shadow: 0 1px 2px 0 rgba(0, 0, 0, 0.03),
+    0 1px 6px -1px rgba(0, 0, 0, 0.02),
+    0 2px 4px 0 rgba(0, 0, 0, 0.02);
+  --radius: 6px !important;
+}";
+        let vars = CssVariableUtils::parse_css_variables(css);
+
+        assert_eq!(
+            vars,
+            vec![
+                (
+                    "--card-shadow".to_string(),
+                    "0 1px 2px 0 rgba(0, 0, 0, 0.03), 0 1px 6px -1px rgba(0, 0, 0, 0.02), 0 2px 4px 0 rgba(0, 0, 0, 0.02)"
+                        .to_string()
+                ),
+                ("--radius".to_string(), "6px".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_variable_with_colons() {
+        let css = "--icon: url(data:image/svg+xml;base64,PHN2Zz4=); \
+                   --bg: linear-gradient(to right, #fff, #000); /* --ignored: 1; */ \
+                   --font: 'a;b', serif";
+        let vars = CssVariableUtils::parse_css_variables(css);
+
+        assert_eq!(
+            vars,
+            vec![
+                (
+                    "--icon".to_string(),
+                    "url(data:image/svg+xml;base64,PHN2Zz4=)".to_string()
+                ),
+                (
+                    "--bg".to_string(),
+                    "linear-gradient(to right, #fff, #000)".to_string()
+                ),
+                ("--font".to_string(), "'a;b', serif".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_variable_preserves_quoted_whitespace() {
+        let css = "--label: \"a  b\"\n    'c\td';";
+        let vars = CssVariableUtils::parse_css_variables(css);
+
+        assert_eq!(
+            vars,
+            vec![("--label".to_string(), "\"a  b\" 'c\td'".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_inject_rejects_malformed_variables() {
+        let mut injector = CssVariableInjector::new(":root");
+        let variables: HashMap<String, String> = [
+            ("--primary", "#1890ff"),
+            ("--radius", "4px"),
+            ("primary", "#ff0000"),
+            ("--empty", "  "),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+        let result = injector.inject_css_variables(&variables).unwrap();
+
+        assert_eq!(result.injected, 2);
+        assert_eq!(
+            result.rejected,
+            vec![
+                RejectedVariable {
+                    name: "--empty".to_string(),
+                    value: "  ".to_string(),
+                    reason: VariableRejectReason::EmptyValue,
+                },
+                RejectedVariable {
+                    name: "primary".to_string(),
+                    value: "#ff0000".to_string(),
+                    reason: VariableRejectReason::InvalidName,
+                },
+            ]
+        );
+
+        let css = injector.get_current_css().unwrap();
+        assert!(css.contains("--primary: #1890ff;"));
+        assert!(css.contains("--radius: 4px;"));
+        assert!(!css.contains("#ff0000"));
+        assert!(!injector.get_current_variables().contains_key("--empty"));
+    }
 }
@@ -1,8 +1,17 @@
+pub mod presets;
+mod storage;
+pub mod switch_metrics;
 pub mod theme_history;
 
+pub use presets::{builtin_presets, ThemePreset, ThemePresetRegistry};
+pub use storage::*;
+pub use switch_metrics::ThemeSwitchMetrics;
+
 use crate::theme::theme_types::Theme;
 use crate::theme::ThemeVariant;
+use std::collections::HashMap;
 use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::Instant;
 use theme_history::ThemeHistory;
 
 /// 全局主题管理器实例
@@ -102,6 +111,10 @@ pub struct ThemeManager {
     config: ThemeManagerConfig,
     /// 主题历史
     theme_history: ThemeHistory,
+    /// 主题偏好存储
+    storage: Arc<dyn ThemeStorage>,
+    /// 主题切换性能指标
+    switch_metrics: Mutex<ThemeSwitchMetrics>,
 }
 
 /// impl Default
@@ -111,6 +124,8 @@ impl Default for ThemeManager {
             current_theme: Arc::new(RwLock::new(Theme::default())),
             config: ThemeManagerConfig::default(),
             theme_history: ThemeHistory::new(),
+            storage: default_storage(),
+            switch_metrics: Mutex::new(ThemeSwitchMetrics::new()),
         }
     }
 }
@@ -151,6 +166,8 @@ impl ThemeManager {
             current_theme: Arc::new(RwLock::new(Theme::default())),
             config,
             theme_history: ThemeHistory::new(),
+            storage: default_storage(),
+            switch_metrics: Mutex::new(ThemeSwitchMetrics::new()),
         }
     }
 
@@ -176,6 +193,44 @@ impl ThemeManager {
         self.current_theme.read().ok().map(|t| t.clone())
     }
 
+    /// 获取当前主题解析后的令牌值
+    ///
+    /// 合并当前主题的令牌变量和自定义变量，并将 `var(--name)` 引用替换为具体值，
+    /// 便于在 Rust 侧直接使用（例如把颜色传给 canvas）。无法解析的引用保持原样。
+    ///
+    /// # Returns
+    ///
+    /// 令牌名称到具体值的映射，无法读取当前主题时返回空映射
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::manager::{ThemeManager, ThemeManagerConfig};
+    /// use css_in_rust::theme::theme_types::Theme;
+    ///
+    /// let manager = ThemeManager::new(ThemeManagerConfig::default());
+    ///
+    /// let mut theme = Theme::new("brand").with_custom_variable("--link-color", "var(--primary)");
+    /// theme.add_color("primary", "#1890ff");
+    /// manager.set_theme(theme).unwrap();
+    ///
+    /// let tokens = manager.resolved_tokens();
+    /// assert_eq!(tokens["primary"], "#1890ff");
+    /// assert_eq!(tokens["--link-color"], "#1890ff");
+    /// ```
+    pub fn resolved_tokens(&self) -> HashMap<String, String> {
+        let Some(theme) = self.get_current_theme() else {
+            return HashMap::new();
+        };
+
+        let mut raw = theme.token_system.variables;
+        raw.extend(theme.custom_variables);
+
+        raw.iter()
+            .map(|(name, value)| (name.clone(), resolve_token_references(value, &raw, 0)))
+            .collect()
+    }
+
     /// 设置主题
     ///
     /// # Arguments
@@ -206,6 +261,8 @@ impl ThemeManager {
     /// }
     /// ```
     pub fn set_theme(&self, theme: Theme) -> Result<(), String> {
+        let start = Instant::now();
+
         // 更新当前主题
         if let Ok(mut current) = self.current_theme.write() {
             // 如果启用了历史记录，添加到历史
@@ -216,6 +273,9 @@ impl ThemeManager {
             }
 
             *current = theme;
+            drop(current);
+
+            self.record_switch(start);
             Ok(())
         } else {
             Err("无法获取主题写锁".to_string())
@@ -246,15 +306,121 @@ impl ThemeManager {
     /// manager.toggle_theme_mode();
     /// ```
     pub fn toggle_theme_mode(&self) {
+        let start = Instant::now();
+
         if let Ok(mut theme) = self.current_theme.write() {
             theme.mode = match theme.mode {
                 ThemeVariant::Light => ThemeVariant::Dark,
                 ThemeVariant::Dark => ThemeVariant::Light,
                 ThemeVariant::Auto => ThemeVariant::Light,
             };
+
+            if self.config.enable_persistence {
+                if let Err(e) = self
+                    .storage
+                    .set(&self.config.storage_key, &theme.mode.to_string())
+                {
+                    eprintln!("保存主题偏好失败: {}", e);
+                }
+            }
+            drop(theme);
+
+            self.record_switch(start);
         }
     }
 
+    /// 获取主题切换性能指标
+    ///
+    /// 返回 `set_theme` 和 `toggle_theme_mode` 的耗时统计快照。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::manager::{ThemeManager, ThemeManagerConfig};
+    ///
+    /// let manager = ThemeManager::new(ThemeManagerConfig::default());
+    /// manager.toggle_theme_mode();
+    /// manager.toggle_theme_mode();
+    ///
+    /// let metrics = manager.switch_metrics();
+    /// assert_eq!(metrics.switch_count(), 2);
+    /// assert!(metrics.p95_switch_time_ms() >= metrics.p50_switch_time_ms());
+    /// ```
+    pub fn switch_metrics(&self) -> ThemeSwitchMetrics {
+        self.switch_metrics
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// 记录一次主题切换耗时
+    fn record_switch(&self, start: Instant) {
+        if let Ok(mut metrics) = self.switch_metrics.lock() {
+            metrics.record_switch(start.elapsed());
+        }
+    }
+
+    /// 设置主题偏好存储
+    ///
+    /// Web 端默认使用 `localStorage`，其他环境默认使用内存存储。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::manager::{MemoryThemeStorage, ThemeManager, ThemeManagerConfig};
+    /// use std::sync::Arc;
+    ///
+    /// let manager = ThemeManager::new(ThemeManagerConfig::default())
+    ///     .with_storage(Arc::new(MemoryThemeStorage::new()));
+    /// ```
+    pub fn with_storage(mut self, storage: Arc<dyn ThemeStorage>) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// 从存储中恢复主题模式
+    ///
+    /// 在应用启动时调用，读取 `storage_key` 下保存的模式并应用到当前主题。
+    /// 未启用持久化或没有有效的保存值时不做任何修改。
+    ///
+    /// # Returns
+    ///
+    /// 恢复的主题模式，没有恢复时返回 `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::manager::{MemoryThemeStorage, ThemeManager, ThemeManagerConfig, ThemeStorage};
+    /// use css_in_rust::theme::ThemeVariant;
+    /// use std::sync::Arc;
+    ///
+    /// let storage = Arc::new(MemoryThemeStorage::new());
+    /// storage.set("theme-preference", "dark").unwrap();
+    ///
+    /// let config = ThemeManagerConfig {
+    ///     enable_persistence: true,
+    ///     ..Default::default()
+    /// };
+    /// let manager = ThemeManager::new(config).with_storage(storage);
+    ///
+    /// assert_eq!(manager.restore_theme_mode(), Some(ThemeVariant::Dark));
+    /// ```
+    pub fn restore_theme_mode(&self) -> Option<ThemeVariant> {
+        if !self.config.enable_persistence {
+            return None;
+        }
+
+        let mode = match self.storage.get(&self.config.storage_key)?.as_str() {
+            "light" => ThemeVariant::Light,
+            "dark" => ThemeVariant::Dark,
+            "auto" => ThemeVariant::Auto,
+            _ => return None,
+        };
+
+        self.current_theme.write().ok()?.mode = mode;
+        Some(mode)
+    }
+
     /// 获取主题历史记录
     ///
     /// # Returns
@@ -435,6 +601,40 @@ impl ThemeManager {
         self.set_theme(theme)
     }
 
+    /// 按名称切换到预设主题
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - 主题预设注册表
+    /// * `preset_name` - 预设名称
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回 `Ok(())`，预设不存在时返回错误信息
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::manager::{ThemeManager, ThemeManagerConfig, ThemePresetRegistry};
+    ///
+    /// let manager = ThemeManager::new(ThemeManagerConfig::default());
+    /// let registry = ThemePresetRegistry::new();
+    ///
+    /// manager.switch_to_preset(&registry, "ant-design").unwrap();
+    /// assert_eq!(manager.get_current_theme().unwrap().name, "ant-design");
+    /// ```
+    pub fn switch_to_preset(
+        &self,
+        registry: &ThemePresetRegistry,
+        preset_name: &str,
+    ) -> Result<(), String> {
+        let preset = registry
+            .get(preset_name)
+            .ok_or_else(|| format!("Theme preset not found: {}", preset_name))?;
+
+        self.set_theme(preset.theme.clone())
+    }
+
     /// 根据主题模式查找主题
     ///
     /// # Arguments
@@ -605,3 +805,122 @@ impl ThemeManager {
         Ok(f(manager))
     }
 }
+
+/// 令牌引用的最大解析深度，防止循环引用
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+/// 将值中的 `var(--name)` 引用替换为对应令牌的具体值
+fn resolve_token_references(value: &str, tokens: &HashMap<String, String>, depth: usize) -> String {
+    if depth >= MAX_REFERENCE_DEPTH {
+        return value.to_string();
+    }
+
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("var(--") {
+        let Some(end) = rest[start..].find(')').map(|end| start + end) else {
+            break;
+        };
+
+        let name = &rest[start + 4..end];
+        let target = tokens
+            .get(name)
+            .or_else(|| tokens.get(name.trim_start_matches("--")));
+
+        resolved.push_str(&rest[..start]);
+        match target {
+            Some(target) => resolved.push_str(&resolve_token_references(target, tokens, depth + 1)),
+            None => resolved.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    resolved.push_str(rest);
+
+    resolved
+}
+
+/// 当前平台的默认主题偏好存储
+fn default_storage() -> Arc<dyn ThemeStorage> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Arc::new(LocalThemeStorage)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Arc::new(MemoryThemeStorage::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn persistent_manager(storage: Arc<MemoryThemeStorage>) -> ThemeManager {
+        let config = ThemeManagerConfig {
+            enable_persistence: true,
+            storage_key: "test-theme".to_string(),
+            ..Default::default()
+        };
+        ThemeManager::new(config).with_storage(storage)
+    }
+
+    #[test]
+    fn test_switch_to_preset() {
+        let manager = ThemeManager::new(ThemeManagerConfig::default());
+        let mut registry = ThemePresetRegistry::new();
+        registry.register(ThemePreset::new(
+            Theme::new("midnight").with_mode(ThemeVariant::Dark),
+            "午夜主题",
+        ));
+
+        manager.switch_to_preset(&registry, "midnight").unwrap();
+        let theme = manager.get_current_theme().unwrap();
+        assert_eq!(theme.name, "midnight");
+        assert_eq!(theme.mode, ThemeVariant::Dark);
+
+        assert!(manager.switch_to_preset(&registry, "unknown").is_err());
+        assert_eq!(manager.get_current_theme().unwrap().name, "midnight");
+    }
+
+    #[test]
+    fn test_resolved_tokens_expose_concrete_values() {
+        let manager = ThemeManager::new(ThemeManagerConfig::default());
+
+        let mut theme = Theme::new("brand")
+            .with_custom_variable("--button-bg", "var(--primary)")
+            .with_custom_variable("--border", "1px solid var(--missing)")
+            .with_custom_variable("--loop", "var(--loop)");
+        theme.add_color("primary", "#1890ff");
+        manager.set_theme(theme).unwrap();
+
+        let tokens = manager.resolved_tokens();
+        assert_eq!(tokens["primary"], "#1890ff");
+        assert_eq!(tokens["--color-primary"], "#1890ff");
+        assert_eq!(tokens["--button-bg"], "#1890ff");
+        assert_eq!(tokens["--border"], "1px solid var(--missing)");
+        assert_eq!(tokens["--loop"], "var(--loop)");
+    }
+
+    #[test]
+    fn test_toggle_persists_and_restore_reads_mode() {
+        let storage = Arc::new(MemoryThemeStorage::new());
+
+        let manager = persistent_manager(storage.clone());
+        manager.toggle_theme_mode();
+        assert_eq!(storage.get("test-theme"), Some("dark".to_string()));
+
+        let reloaded = persistent_manager(storage.clone());
+        assert_eq!(reloaded.restore_theme_mode(), Some(ThemeVariant::Dark));
+        assert_eq!(
+            reloaded.get_current_theme().unwrap().mode,
+            ThemeVariant::Dark
+        );
+
+        let disabled =
+            ThemeManager::new(ThemeManagerConfig::default()).with_storage(storage.clone());
+        disabled.toggle_theme_mode();
+        assert_eq!(disabled.restore_theme_mode(), None);
+        assert_eq!(storage.get("test-theme"), Some("dark".to_string()));
+    }
+}
@@ -0,0 +1,202 @@
+//! 主题预设模块
+//!
+//! 提供可按名称注册和查找的主题预设，以及内置的亮色、暗色和 Ant Design 预设。
+
+use crate::theme::theme_types::Theme;
+use crate::theme::ThemeVariant;
+
+/// 主题预设
+///
+/// 带有描述和标签的具名主题，预设名称即主题名称。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::manager::ThemePreset;
+/// use css_in_rust::theme::theme_types::Theme;
+///
+/// let preset = ThemePreset::new(Theme::new("ocean"), "海洋蓝主题").with_tag("blue");
+/// assert_eq!(preset.name(), "ocean");
+/// assert!(preset.has_tag("blue"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemePreset {
+    /// 预设描述
+    pub description: String,
+    /// 预设标签
+    pub tags: Vec<String>,
+    /// 预设主题
+    pub theme: Theme,
+}
+
+impl ThemePreset {
+    /// 创建主题预设
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - 预设主题，其名称作为预设名称
+    /// * `description` - 预设描述
+    pub fn new(theme: Theme, description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            tags: Vec::new(),
+            theme,
+        }
+    }
+
+    /// 添加标签
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// 获取预设名称
+    pub fn name(&self) -> &str {
+        &self.theme.name
+    }
+
+    /// 检查预设是否带有指定标签
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// 获取内置主题预设
+///
+/// 包括 `light`、`dark` 和 `ant-design` 三个预设。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::manager::builtin_presets;
+///
+/// let names: Vec<_> = builtin_presets().iter().map(|p| p.name().to_string()).collect();
+/// assert_eq!(names, ["light", "dark", "ant-design"]);
+/// ```
+pub fn builtin_presets() -> Vec<ThemePreset> {
+    vec![
+        ThemePreset::new(
+            Theme::new("light").with_mode(ThemeVariant::Light),
+            "默认亮色主题",
+        )
+        .with_tag("builtin")
+        .with_tag("light"),
+        ThemePreset::new(
+            Theme::new("dark").with_mode(ThemeVariant::Dark),
+            "默认暗色主题",
+        )
+        .with_tag("builtin")
+        .with_tag("dark"),
+        ThemePreset::new(Theme::ant_design_full(), "Ant Design 设计体系主题")
+            .with_tag("builtin")
+            .with_tag("light")
+            .with_tag("ant-design"),
+    ]
+}
+
+/// 主题预设注册表
+///
+/// 按注册顺序保存预设，注册同名预设时替换已有预设。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::manager::{ThemePreset, ThemePresetRegistry};
+/// use css_in_rust::theme::theme_types::Theme;
+///
+/// let mut registry = ThemePresetRegistry::new();
+/// registry.register(ThemePreset::new(Theme::new("ocean"), "海洋蓝主题"));
+///
+/// assert!(registry.get("ocean").is_some());
+/// assert!(registry.get("dark").is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemePresetRegistry {
+    /// 已注册的预设
+    presets: Vec<ThemePreset>,
+}
+
+impl Default for ThemePresetRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemePresetRegistry {
+    /// 创建预置内置预设的注册表
+    pub fn new() -> Self {
+        Self {
+            presets: builtin_presets(),
+        }
+    }
+
+    /// 注册预设
+    ///
+    /// 已存在同名预设时替换之。
+    pub fn register(&mut self, preset: ThemePreset) {
+        match self.presets.iter_mut().find(|p| p.name() == preset.name()) {
+            Some(existing) => *existing = preset,
+            None => self.presets.push(preset),
+        }
+    }
+
+    /// 按名称获取预设
+    pub fn get(&self, name: &str) -> Option<&ThemePreset> {
+        self.presets.iter().find(|p| p.name() == name)
+    }
+
+    /// 列出所有预设
+    pub fn list(&self) -> &[ThemePreset] {
+        &self.presets
+    }
+
+    /// 获取带有指定标签的预设
+    pub fn by_tag(&self, tag: &str) -> Vec<&ThemePreset> {
+        self.presets.iter().filter(|p| p.has_tag(tag)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_list_and_filter_presets() {
+        let mut registry = ThemePresetRegistry::new();
+        registry.register(
+            ThemePreset::new(
+                Theme::new("midnight").with_mode(ThemeVariant::Dark),
+                "午夜主题",
+            )
+            .with_tag("dark")
+            .with_tag("custom"),
+        );
+
+        let names: Vec<&str> = registry.list().iter().map(ThemePreset::name).collect();
+        assert_eq!(names, ["light", "dark", "ant-design", "midnight"]);
+
+        let dark: Vec<&str> = registry
+            .by_tag("dark")
+            .into_iter()
+            .map(ThemePreset::name)
+            .collect();
+        assert_eq!(dark, ["dark", "midnight"]);
+        assert_eq!(registry.by_tag("custom").len(), 1);
+        assert!(registry.by_tag("missing").is_empty());
+
+        assert_eq!(
+            registry.get("midnight").map(|p| p.theme.mode),
+            Some(ThemeVariant::Dark)
+        );
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_register_replaces_same_name() {
+        let mut registry = ThemePresetRegistry::new();
+        registry.register(ThemePreset::new(Theme::new("dark"), "自定义暗色").with_tag("custom"));
+
+        assert_eq!(registry.list().len(), builtin_presets().len());
+        assert_eq!(registry.get("dark").unwrap().description, "自定义暗色");
+    }
+}
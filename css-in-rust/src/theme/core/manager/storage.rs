@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::RwLock;
+
+/// 主题偏好存储
+///
+/// 抽象主题偏好的持久化位置。Web 端使用 `localStorage`，其他环境或测试中可以替换为内存实现。
+pub trait ThemeStorage: Debug + Send + Sync {
+    /// 读取存储键对应的值
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// 写入存储键对应的值
+    fn set(&self, key: &str, value: &str) -> Result<(), String>;
+}
+
+/// 基于内存的主题偏好存储
+///
+/// 非 Web 环境下的默认存储，也可以在测试中代替 `localStorage`。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::manager::{MemoryThemeStorage, ThemeStorage};
+///
+/// let storage = MemoryThemeStorage::new();
+/// storage.set("theme-preference", "dark").unwrap();
+/// assert_eq!(storage.get("theme-preference"), Some("dark".to_string()));
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryThemeStorage {
+    values: RwLock<HashMap<String, String>>,
+}
+
+impl MemoryThemeStorage {
+    /// 创建空的内存存储
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ThemeStorage for MemoryThemeStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.read().ok()?.get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        self.values
+            .write()
+            .map_err(|_| "无法获取存储写锁".to_string())?
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+/// 基于浏览器 `localStorage` 的主题偏好存储
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default)]
+pub struct LocalThemeStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalThemeStorage {
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ThemeStorage for LocalThemeStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        Self::storage()?.get_item(key).ok()?
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        Self::storage()
+            .ok_or_else(|| "localStorage 不可用".to_string())?
+            .set_item(key, value)
+            .map_err(|e| format!("写入 localStorage 失败: {:?}", e))
+    }
+}
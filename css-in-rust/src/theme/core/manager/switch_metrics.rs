@@ -0,0 +1,175 @@
+//! 主题切换性能指标模块
+//!
+//! 记录主题切换耗时，提供平均值、最大值以及基于最近样本的百分位统计，
+//! 用于发现频繁切换主题时的尾部延迟。
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// 默认保留的最近样本数量
+pub const DEFAULT_SWITCH_SAMPLE_CAPACITY: usize = 256;
+
+/// 主题切换性能指标
+///
+/// 平均值和最大值基于全部切换统计；百分位基于固定容量环形缓冲区中的最近样本，
+/// 因此内存占用有上限。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::manager::ThemeSwitchMetrics;
+/// use std::time::Duration;
+///
+/// let mut metrics = ThemeSwitchMetrics::new();
+/// for ms in 1..=100 {
+///     metrics.record_switch(Duration::from_millis(ms));
+/// }
+///
+/// assert_eq!(metrics.p50_switch_time_ms(), 50.0);
+/// assert_eq!(metrics.p95_switch_time_ms(), 95.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeSwitchMetrics {
+    /// 最近的切换耗时样本
+    recent_samples: VecDeque<Duration>,
+    /// 样本容量
+    capacity: usize,
+    /// 切换总次数
+    total_switches: u64,
+    /// 切换总耗时
+    total_time: Duration,
+    /// 最大切换耗时
+    max_time: Duration,
+}
+
+impl Default for ThemeSwitchMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThemeSwitchMetrics {
+    /// 创建使用默认样本容量的指标
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_SWITCH_SAMPLE_CAPACITY)
+    }
+
+    /// 创建指定样本容量的指标
+    ///
+    /// 容量至少为 1。
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            recent_samples: VecDeque::with_capacity(capacity),
+            capacity,
+            total_switches: 0,
+            total_time: Duration::ZERO,
+            max_time: Duration::ZERO,
+        }
+    }
+
+    /// 记录一次主题切换耗时
+    ///
+    /// 样本数量达到容量时丢弃最旧的样本。
+    pub fn record_switch(&mut self, duration: Duration) {
+        if self.recent_samples.len() == self.capacity {
+            self.recent_samples.pop_front();
+        }
+        self.recent_samples.push_back(duration);
+
+        self.total_switches += 1;
+        self.total_time += duration;
+        self.max_time = self.max_time.max(duration);
+    }
+
+    /// 切换总次数
+    pub fn switch_count(&self) -> u64 {
+        self.total_switches
+    }
+
+    /// 当前保留的样本数量
+    pub fn sample_count(&self) -> usize {
+        self.recent_samples.len()
+    }
+
+    /// 平均切换耗时（毫秒）
+    pub fn avg_switch_time_ms(&self) -> f64 {
+        if self.total_switches == 0 {
+            return 0.0;
+        }
+        self.total_time.as_secs_f64() * 1000.0 / self.total_switches as f64
+    }
+
+    /// 最大切换耗时（毫秒）
+    pub fn max_switch_time_ms(&self) -> f64 {
+        self.max_time.as_secs_f64() * 1000.0
+    }
+
+    /// 最近样本的 P50 切换耗时（毫秒）
+    pub fn p50_switch_time_ms(&self) -> f64 {
+        self.percentile_ms(50.0)
+    }
+
+    /// 最近样本的 P95 切换耗时（毫秒）
+    pub fn p95_switch_time_ms(&self) -> f64 {
+        self.percentile_ms(95.0)
+    }
+
+    /// 按最近秩法计算百分位（毫秒），没有样本时返回 0
+    fn percentile_ms(&self, percentile: f64) -> f64 {
+        if self.recent_samples.is_empty() {
+            return 0.0;
+        }
+
+        let mut sorted: Vec<Duration> = self.recent_samples.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+        let index = rank.clamp(1, sorted.len()) - 1;
+        sorted[index].as_secs_f64() * 1000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_for_known_distribution() {
+        let mut metrics = ThemeSwitchMetrics::new();
+        for ms in (1..=100).rev() {
+            metrics.record_switch(Duration::from_millis(ms));
+        }
+
+        assert_eq!(metrics.p50_switch_time_ms(), 50.0);
+        assert_eq!(metrics.p95_switch_time_ms(), 95.0);
+        assert_eq!(metrics.max_switch_time_ms(), 100.0);
+        assert_eq!(metrics.avg_switch_time_ms(), 50.5);
+    }
+
+    #[test]
+    fn test_samples_are_bounded() {
+        let mut metrics = ThemeSwitchMetrics::new();
+        // 较早的慢切换会被挤出百分位窗口，但仍计入最大值
+        for _ in 0..44 {
+            metrics.record_switch(Duration::from_millis(1000));
+        }
+        for ms in 1..=256 {
+            metrics.record_switch(Duration::from_millis(ms));
+        }
+
+        assert_eq!(metrics.switch_count(), 300);
+        assert_eq!(metrics.sample_count(), DEFAULT_SWITCH_SAMPLE_CAPACITY);
+        assert_eq!(metrics.p50_switch_time_ms(), 128.0);
+        assert_eq!(metrics.p95_switch_time_ms(), 244.0);
+        assert_eq!(metrics.max_switch_time_ms(), 1000.0);
+    }
+
+    #[test]
+    fn test_empty_metrics() {
+        let metrics = ThemeSwitchMetrics::with_capacity(0);
+        assert_eq!(metrics.p50_switch_time_ms(), 0.0);
+        assert_eq!(metrics.p95_switch_time_ms(), 0.0);
+        assert_eq!(metrics.avg_switch_time_ms(), 0.0);
+    }
+}
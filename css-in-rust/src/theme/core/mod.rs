@@ -70,7 +70,7 @@ pub use cache::StyleCache;
 pub use manager::ThemeManager;
 
 /// 从 optimize 模块重新导出的类型和函数
-pub use optimize::{OptimizeConfig, StyleOptimizer};
+pub use optimize::{ClassRenameResult, ClassRenamer, OptimizeConfig, StyleOptimizer};
 
 /// 从 transform 模块重新导出的类型和函数
 pub use transform::{
@@ -0,0 +1,263 @@
+//! 类名重命名模块
+//!
+//! 生产构建时将生成的长类名（如 `css-1a2b3c4d`）重映射为短标识符（`a`、`b`、…、`aa`），
+//! 同时改写 CSS 并返回映射表，用于同步改写渲染后的 HTML。
+
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// 不会被分配的短类名
+///
+/// 包括 CSS 全局关键字，以及常被广告拦截器屏蔽的名称
+const RESERVED_CLASS_NAMES: &[&str] = &[
+    "ad", "ads", "adv", "auto", "banner", "default", "inherit", "initial", "none", "revert",
+    "sponsor", "unset",
+];
+
+/// 类名重命名结果
+#[derive(Debug, Clone, Default)]
+pub struct ClassRenameResult {
+    /// 改写后的 CSS
+    pub css: String,
+    /// 原类名到短类名的映射
+    pub mapping: HashMap<String, String>,
+}
+
+impl ClassRenameResult {
+    /// 改写 HTML `class` 属性值
+    ///
+    /// 按空白拆分类名，存在映射的类名替换为短类名，其余保持不变
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::ClassRenamer;
+    ///
+    /// let css = ".css-1a2b3c4d { color: red; }";
+    /// let result = ClassRenamer::new().rename(css, &["css-1a2b3c4d"]);
+    ///
+    /// assert_eq!(result.rewrite_class_attribute("css-1a2b3c4d active"), "a active");
+    /// ```
+    pub fn rewrite_class_attribute(&self, class_attribute: &str) -> String {
+        class_attribute
+            .split_whitespace()
+            .map(|class_name| {
+                self.mapping
+                    .get(class_name)
+                    .map(String::as_str)
+                    .unwrap_or(class_name)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// 类名重命名器
+///
+/// 按给定顺序为类名分配短标识符。分配时跳过保留名称以及 CSS 中已存在且不参与重命名的类名，
+/// 避免与其他样式冲突。
+#[derive(Debug, Clone)]
+pub struct ClassRenamer {
+    /// 额外的保留名称
+    reserved: HashSet<String>,
+}
+
+impl ClassRenamer {
+    /// 创建新的类名重命名器
+    pub fn new() -> Self {
+        Self {
+            reserved: RESERVED_CLASS_NAMES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// 添加不允许分配的类名
+    pub fn reserve(mut self, class_name: &str) -> Self {
+        self.reserved.insert(class_name.to_string());
+        self
+    }
+
+    /// 重命名 CSS 中的类名
+    ///
+    /// 引号字符串、注释和 `url(...)` 内部的内容（如 `url(img.png)` 中的文件名）不会被当作类选择器。
+    ///
+    /// # 参数
+    ///
+    /// * `css` - 要改写的 CSS
+    /// * `classes` - 参与重命名的全部生成类名，按此顺序分配短名称
+    ///
+    /// # 返回值
+    ///
+    /// 改写后的 CSS 及类名映射
+    pub fn rename(&self, css: &str, classes: &[&str]) -> ClassRenameResult {
+        let class_regex = Regex::new(r"\.(-?[_a-zA-Z][\w-]*)").unwrap();
+        let protected = protected_ranges(css);
+        let is_selector = |start: usize| !protected.iter().any(|range| range.contains(&start));
+
+        // CSS 中已存在但不参与重命名的类名也不能被分配
+        let renamed: HashSet<&str> = classes.iter().copied().collect();
+        let mut taken = self.reserved.clone();
+        for cap in class_regex.captures_iter(css) {
+            if is_selector(cap.get(0).unwrap().start()) && !renamed.contains(&cap[1]) {
+                taken.insert(cap[1].to_string());
+            }
+        }
+
+        let mut mapping = HashMap::new();
+        let mut index = 0;
+        for class_name in classes {
+            if mapping.contains_key(*class_name) {
+                continue;
+            }
+
+            let short_name = loop {
+                let candidate = short_class_name(index);
+                index += 1;
+                if !taken.contains(&candidate) {
+                    break candidate;
+                }
+            };
+            mapping.insert(class_name.to_string(), short_name);
+        }
+
+        let css = class_regex
+            .replace_all(css, |caps: &regex::Captures| match mapping.get(&caps[1]) {
+                Some(short_name) if is_selector(caps.get(0).unwrap().start()) => {
+                    format!(".{}", short_name)
+                }
+                _ => caps[0].to_string(),
+            })
+            .into_owned();
+
+        ClassRenameResult { css, mapping }
+    }
+}
+
+impl Default for ClassRenamer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 查找不属于选择器的区间：引号字符串、注释和 `url(...)` 的参数
+fn protected_ranges(css: &str) -> Vec<Range<usize>> {
+    let bytes = css.as_bytes();
+    let mut ranges = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+        match bytes[index] {
+            quote @ (b'"' | b'\'') => {
+                index = skip_string(bytes, index, quote);
+                ranges.push(start..index);
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                index = css[index + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| index + 2 + end + 2);
+                ranges.push(start..index);
+            }
+            b'(' if css[..index].to_ascii_lowercase().ends_with("url") => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != b')' {
+                    index = match bytes[index] {
+                        quote @ (b'"' | b'\'') => skip_string(bytes, index, quote),
+                        _ => index + 1,
+                    };
+                }
+                ranges.push(start..index);
+            }
+            _ => index += 1,
+        }
+    }
+
+    ranges
+}
+
+/// 返回从 `start` 处引号开始的字符串结束后的位置
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b if b == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// 生成第 `index` 个短类名：`a`..`z`、`aa`..`az`、`ba`...
+fn short_class_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'a' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_class_name_sequence() {
+        assert_eq!(short_class_name(0), "a");
+        assert_eq!(short_class_name(25), "z");
+        assert_eq!(short_class_name(26), "aa");
+        assert_eq!(short_class_name(27), "ab");
+        assert_eq!(short_class_name(26 + 26 * 26), "aaa");
+    }
+
+    #[test]
+    fn test_rename_classes_consistently() {
+        let css = r#"
+            .css-1a2b3c4d { color: red; }
+            .css-5e6f7a8b:hover, .css-1a2b3c4d > .css-9c0d1e2f { margin: 0.5em; }
+            .b { padding: 0; }
+        "#;
+        let classes = ["css-1a2b3c4d", "css-5e6f7a8b", "css-9c0d1e2f"];
+
+        let result = ClassRenamer::new().rename(css, &classes);
+
+        // 已存在的 `.b` 不参与重命名，也不会被分配
+        assert_eq!(result.mapping["css-1a2b3c4d"], "a");
+        assert_eq!(result.mapping["css-5e6f7a8b"], "c");
+        assert_eq!(result.mapping["css-9c0d1e2f"], "d");
+        assert!(result.css.contains(".a { color: red; }"));
+        assert!(result.css.contains(".c:hover, .a > .d {"));
+        assert!(result.css.contains(".b { padding: 0; }"));
+        assert!(result.css.contains("0.5em"));
+        assert!(!result.css.contains("css-"));
+
+        let html_class = result.rewrite_class_attribute("css-5e6f7a8b  css-9c0d1e2f b");
+        assert_eq!(html_class, "c d b");
+    }
+
+    #[test]
+    fn test_urls_and_strings_are_not_renamed() {
+        let css = r#".css-1a2b3c4d { background: url(img.png); mask: URL("icons/mask.svg"); content: ".png"; }
+/* .png fallback */
+.png { color: red; }"#;
+
+        let result = ClassRenamer::new().rename(css, &["css-1a2b3c4d", "png", "svg"]);
+
+        assert_eq!(result.mapping["css-1a2b3c4d"], "a");
+        assert_eq!(result.mapping["png"], "b");
+        assert!(result.css.contains("url(img.png)"), "{}", result.css);
+        assert!(result.css.contains(r#"URL("icons/mask.svg")"#));
+        assert!(result.css.contains(r#"content: ".png""#));
+        assert!(result.css.contains("/* .png fallback */"));
+        assert!(result.css.contains(".b { color: red; }"));
+
+        // url() 中的文件名不会占用短类名
+        let result = ClassRenamer::new().rename(".x { background: url(img.a); }", &["x"]);
+        assert_eq!(result.mapping["x"], "a");
+    }
+}
@@ -7,6 +7,8 @@
 //!
 //! - `StyleOptimizer`: 核心优化器，提供各种CSS优化策略
 //! - `OptimizeConfig`: 优化配置，控制优化行为
+//! - `ClassRenamer`: 生产环境类名缩短，输出 CSS 与类名映射
+//! - `BrowserTargets`: 目标浏览器，决定厂商前缀和 CSS 嵌套的处理方式
 //!
 //! # 示例
 //!
@@ -22,6 +24,8 @@
 //!     remove_unused: true,
 //!     merge_rules: true,
 //!     optimize_selectors: true,
+//!     combine_media_queries: true,
+//!     target_browsers: Vec::new(),
 //! };
 //!
 //! let optimizer = StyleOptimizer::new(config);
@@ -38,9 +42,17 @@
 //! let optimized = optimizer.optimize(css);
 //! ```
 
+mod class_renamer;
+mod targets;
+
+pub use class_renamer::*;
+pub use targets::*;
+
 use regex;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
 /// CSS 优化配置
 ///
@@ -60,6 +72,8 @@ use std::collections::HashSet;
 ///     remove_unused: false,
 ///     merge_rules: true,
 ///     optimize_selectors: true,
+///     combine_media_queries: true,
+///     target_browsers: Vec::new(),
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -72,6 +86,14 @@ pub struct OptimizeConfig {
     pub merge_rules: bool,
     /// 是否优化选择器
     pub optimize_selectors: bool,
+    /// 是否合并条件相同的 `@media` 块
+    ///
+    /// 合并后的块位于第一次出现的位置，块内规则保持原有顺序
+    pub combine_media_queries: bool,
+    /// 目标浏览器（browserslist 风格的查询，如 `last 2 versions`）
+    ///
+    /// 用于决定是否保留厂商前缀、是否展开 CSS 嵌套；为空时不做相关处理
+    pub target_browsers: Vec<String>,
 }
 
 impl Default for OptimizeConfig {
@@ -81,8 +103,81 @@ impl Default for OptimizeConfig {
             remove_unused: true,
             merge_rules: true,
             optimize_selectors: true,
+            combine_media_queries: false,
+            target_browsers: Vec::new(),
+        }
+    }
+}
+
+impl OptimizeConfig {
+    /// 开发环境预设
+    ///
+    /// 输出保持可读：不压缩、不删除未使用的样式、不改写选择器，仅合并相同规则以去重。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::OptimizeConfig;
+    ///
+    /// let config = OptimizeConfig::development();
+    /// assert!(!config.minify);
+    /// assert!(config.merge_rules);
+    /// ```
+    pub fn development() -> Self {
+        Self {
+            minify: false,
+            remove_unused: false,
+            merge_rules: true,
+            optimize_selectors: false,
+            combine_media_queries: false,
+            target_browsers: Vec::new(),
         }
     }
+
+    /// 生产环境预设
+    ///
+    /// 启用全部优化，与默认配置相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::OptimizeConfig;
+    ///
+    /// let config = OptimizeConfig::production();
+    /// assert!(config.minify && config.remove_unused);
+    /// ```
+    pub fn production() -> Self {
+        Self::default()
+    }
+}
+
+/// 优化试运行报告
+///
+/// 由 [`StyleOptimizer::dry_run`] 生成，列出优化时会受影响的选择器。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemovalReport {
+    /// 移除未使用样式时会删除的选择器
+    pub removed_selectors: Vec<String>,
+    /// 合并规则时会合并的重复选择器
+    pub merged_selectors: Vec<String>,
+}
+
+impl RemovalReport {
+    /// 检查优化是否不会影响任何选择器
+    pub fn is_empty(&self) -> bool {
+        self.removed_selectors.is_empty() && self.merged_selectors.is_empty()
+    }
+}
+
+/// 增量优化的规则缓存统计
+///
+/// 由 [`StyleOptimizer::rule_cache_stats`] 返回，计数在优化器的整个生命周期内累计。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleCacheStats {
+    /// 直接复用缓存结果的规则数
+    pub hits: usize,
+    /// 重新优化的规则数
+    pub misses: usize,
 }
 
 /// CSS 优化器
@@ -103,6 +198,8 @@ impl Default for OptimizeConfig {
 ///     remove_unused: true,
 ///     merge_rules: true,
 ///     optimize_selectors: true,
+///     combine_media_queries: true,
+///     target_browsers: Vec::new(),
 /// };
 /// let optimizer = StyleOptimizer::new(config);
 ///
@@ -119,6 +216,11 @@ impl Default for OptimizeConfig {
 pub struct StyleOptimizer {
     config: OptimizeConfig,
     used_classes: HashSet<String>,
+    used_ids: HashSet<String>,
+    used_attributes: HashSet<String>,
+    /// 增量优化缓存：规则哈希 -> 优化后的规则
+    rule_cache: HashMap<u64, String>,
+    rule_cache_stats: RuleCacheStats,
 }
 
 impl StyleOptimizer {
@@ -144,6 +246,10 @@ impl StyleOptimizer {
         Self {
             config,
             used_classes: HashSet::new(),
+            used_ids: HashSet::new(),
+            used_attributes: HashSet::new(),
+            rule_cache: HashMap::new(),
+            rule_cache_stats: RuleCacheStats::default(),
         }
     }
 
@@ -166,6 +272,59 @@ impl StyleOptimizer {
     /// ```
     pub fn register_used_class(&mut self, class_name: &str) {
         self.used_classes.insert(class_name.to_string());
+        // 使用情况变化会影响未使用样式的移除结果
+        self.rule_cache.clear();
+    }
+
+    /// 注册使用的 ID
+    ///
+    /// 注册过任何 ID 后，引用未注册 ID 的选择器会在移除未使用样式时被删除。
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - 要注册的 ID（不包含 `#`）
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::StyleOptimizer;
+    ///
+    /// let mut optimizer = StyleOptimizer::default();
+    /// optimizer.register_used_id("header");
+    /// ```
+    pub fn register_used_id(&mut self, id: &str) {
+        self.used_ids.insert(id.to_string());
+        // 使用情况变化会影响未使用样式的移除结果
+        self.rule_cache.clear();
+    }
+
+    /// 注册使用的属性
+    ///
+    /// 注册过任何属性后，引用未注册属性的属性选择器会在移除未使用样式时被删除。
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - 要注册的属性名（如 `data-open`）
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::StyleOptimizer;
+    ///
+    /// let mut optimizer = StyleOptimizer::default();
+    /// optimizer.register_used_attribute("data-open");
+    /// ```
+    pub fn register_used_attribute(&mut self, attribute: &str) {
+        self.used_attributes.insert(attribute.to_string());
+        // 使用情况变化会影响未使用样式的移除结果
+        self.rule_cache.clear();
+    }
+
+    /// 是否注册过任何使用的类名、ID 或属性
+    fn has_registered_usage(&self) -> bool {
+        !self.used_classes.is_empty()
+            || !self.used_ids.is_empty()
+            || !self.used_attributes.is_empty()
     }
 
     /// 优化 CSS 内容
@@ -196,20 +355,117 @@ impl StyleOptimizer {
     /// let optimized = optimizer.optimize(css);
     /// ```
     pub fn optimize(&self, css: &str) -> String {
+        self.optimize_rules(&self.apply_targets(css))
+    }
+
+    /// 增量优化 CSS 内容
+    ///
+    /// 按规则逐条优化，并以规则内容的哈希缓存每条规则的优化结果；再次调用时未变化的规则直接
+    /// 复用缓存，适合热重载时只有少量规则变化的场景。每条规则独立优化，因此不会跨规则合并
+    /// 相同选择器或相同声明。缓存只保留最近一次调用中出现的规则，注册使用的类名、ID 或属性
+    /// 会清空缓存。
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - 要优化的CSS字符串
+    ///
+    /// # Returns
+    ///
+    /// 优化后的CSS字符串
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::StyleOptimizer;
+    ///
+    /// let mut optimizer = StyleOptimizer::default();
+    /// optimizer.optimize_incremental(".a { color: red; }\n.b { color: blue; }");
+    /// optimizer.optimize_incremental(".a { color: red; }\n.b { color: green; }");
+    ///
+    /// let stats = optimizer.rule_cache_stats();
+    /// assert_eq!(stats.hits, 1);
+    /// assert_eq!(stats.misses, 3);
+    /// ```
+    pub fn optimize_incremental(&mut self, css: &str) -> String {
+        let rules = self.parse_css_rules(&self.apply_targets(css));
+        let mut previous = std::mem::take(&mut self.rule_cache);
+        let mut optimized = String::new();
+
+        for rule in rules {
+            let rule_css = self.rules_to_css(std::slice::from_ref(&rule));
+            let mut hasher = DefaultHasher::new();
+            rule_css.hash(&mut hasher);
+            let key = hasher.finish();
+
+            let result = match previous
+                .remove(&key)
+                .or_else(|| self.rule_cache.remove(&key))
+            {
+                Some(cached) => {
+                    self.rule_cache_stats.hits += 1;
+                    cached
+                }
+                None => {
+                    self.rule_cache_stats.misses += 1;
+                    self.optimize_rules(&rule_css)
+                }
+            };
+            optimized.push_str(&result);
+            self.rule_cache.insert(key, result);
+        }
+
+        optimized
+    }
+
+    /// 获取增量优化的规则缓存统计
+    pub fn rule_cache_stats(&self) -> RuleCacheStats {
+        self.rule_cache_stats
+    }
+
+    /// 根据目标浏览器展开嵌套并移除多余的厂商前缀，需在规则合并前完成
+    fn apply_targets(&self, css: &str) -> String {
         let mut optimized = css.to_string();
 
-        // 首先处理规则合并和选择器优化，这样在移除未使用的样式前可以更好地识别
-        if self.config.merge_rules {
-            optimized = self.merge_rules(&optimized);
+        let targets = BrowserTargets::from_queries(&self.config.target_browsers);
+        if !targets.is_empty() {
+            if !targets.supports_nesting() {
+                optimized = flatten_nesting(&optimized);
+            }
+            optimized = targets.strip_unneeded_prefixes(&optimized);
         }
 
-        if self.config.optimize_selectors {
-            optimized = self.optimize_selectors(&optimized);
+        optimized
+    }
+
+    /// 对已处理目标浏览器的 CSS 依次执行规则合并、选择器优化、未使用样式移除和压缩
+    ///
+    /// 规则合并、选择器优化和未使用样式移除只处理普通样式规则：顶层 at-rule 语句（如 `@import`）
+    /// 原样移到最前，带块的 at-rule（如 `@media`、`@font-face`）原样移到样式规则之后。
+    fn optimize_rules(&self, css: &str) -> String {
+        let mut optimized = css.to_string();
+
+        if self.config.merge_rules || self.config.optimize_selectors || self.config.remove_unused {
+            let (statements, mut rules, blocks) = split_top_level_at_rules(&optimized);
+
+            // 首先处理规则合并和选择器优化，这样在移除未使用的样式前可以更好地识别
+            if self.config.merge_rules {
+                rules = self.merge_rules(&rules);
+            }
+
+            if self.config.optimize_selectors {
+                rules = self.optimize_selectors(&rules);
+            }
+
+            // 然后移除未使用的样式
+            if self.config.remove_unused {
+                rules = self.remove_unused_styles(&rules);
+            }
+
+            optimized = statements + &rules + &blocks;
         }
 
-        // 然后移除未使用的样式
-        if self.config.remove_unused {
-            optimized = self.remove_unused_styles(&optimized);
+        if self.config.combine_media_queries {
+            optimized = combine_media_queries(&optimized);
         }
 
         // 最后进行压缩
@@ -220,9 +476,56 @@ impl StyleOptimizer {
         optimized
     }
 
+    /// 试运行优化
+    ///
+    /// 不生成优化后的 CSS，只报告移除未使用样式时会删除的选择器，以及合并规则时会合并的选择器，
+    /// 便于在启用 `remove_unused` 前审查结果。报告与配置中的开关无关。
+    ///
+    /// # Arguments
+    ///
+    /// * `css` - 要检查的CSS字符串
+    ///
+    /// # Returns
+    ///
+    /// 按出现顺序列出选择器的移除报告
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::optimize::StyleOptimizer;
+    ///
+    /// let mut optimizer = StyleOptimizer::default();
+    /// optimizer.register_used_class("button");
+    ///
+    /// let report = optimizer.dry_run(".button { color: red; }\n.unused { color: blue; }");
+    /// assert_eq!(report.removed_selectors, vec![".unused".to_string()]);
+    /// ```
+    pub fn dry_run(&self, css: &str) -> RemovalReport {
+        let rules = self.parse_css_rules(css);
+        let mut report = RemovalReport::default();
+        let mut seen = HashSet::new();
+
+        for (selector, _) in &rules {
+            if !seen.insert(selector.as_str()) && !report.merged_selectors.contains(selector) {
+                report.merged_selectors.push(selector.clone());
+            }
+
+            for part in selector.split(',').map(str::trim) {
+                if !self.is_selector_used(part)
+                    && !report.removed_selectors.iter().any(|s| s == part)
+                {
+                    report.removed_selectors.push(part.to_string());
+                }
+            }
+        }
+
+        report
+    }
+
     /// 压缩 CSS
     ///
     /// 移除注释和多余的空白，减小CSS文件大小。
+    /// 引号字符串、`url()` 以及 `content` 属性的值会原样保留，不折叠其中的空白。
     ///
     /// # Arguments
     ///
@@ -242,9 +545,41 @@ impl StyleOptimizer {
         // 移除多余的空白
         let mut minified = String::new();
         let mut last_char = ' ';
+        let mut quote: Option<char> = None;
+        let mut url_depth = 0usize;
+        let mut in_content_value = false;
+        let mut token = String::new();
 
         for c in without_comments.chars() {
-            if c.is_whitespace() {
+            if let Some(q) = quote {
+                // 字符串内部原样保留
+                minified.push(c);
+                if c == q && last_char != '\\' {
+                    quote = None;
+                }
+                last_char = c;
+                continue;
+            }
+
+            let verbatim = url_depth > 0 || in_content_value;
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '(' if url_depth > 0 || token.eq_ignore_ascii_case("url") => url_depth += 1,
+                ')' if url_depth > 0 => url_depth -= 1,
+                ':' if !verbatim => {
+                    in_content_value = token.eq_ignore_ascii_case("content");
+                }
+                ';' | '}' | '{' => in_content_value = false,
+                _ => {}
+            }
+
+            if c.is_alphanumeric() || c == '-' {
+                token.push(c);
+            } else if !c.is_whitespace() || !verbatim {
+                token.clear();
+            }
+
+            if c.is_whitespace() && !verbatim {
                 if !last_char.is_whitespace() && last_char != '{' && last_char != ';' {
                     minified.push(' ');
                 }
@@ -259,7 +594,7 @@ impl StyleOptimizer {
 
     /// 移除未使用的样式
     ///
-    /// 根据已注册的类名，移除CSS中未使用的样式规则。
+    /// 根据已注册的类名、ID 和属性，移除CSS中未使用的样式规则。
     ///
     /// # Arguments
     ///
@@ -269,7 +604,7 @@ impl StyleOptimizer {
     ///
     /// 移除未使用样式后的CSS字符串
     fn remove_unused_styles(&self, css: &str) -> String {
-        if self.used_classes.is_empty() {
+        if !self.has_registered_usage() {
             return css.to_string();
         }
 
@@ -277,10 +612,6 @@ impl StyleOptimizer {
         let rules = self.parse_css_rules(css);
         let mut filtered_rules = Vec::new();
 
-        // 打印调试信息
-        println!("Used classes: {:?}", self.used_classes);
-        println!("Parsed rules: {:?}", rules);
-
         // 过滤规则
         for (selector, declarations) in rules {
             // 检查选择器是否包含任何已使用的类名
@@ -304,14 +635,13 @@ impl StyleOptimizer {
         }
 
         // 将过滤后的规则转换回 CSS 字符串
-        let result = self.rules_to_css(&filtered_rules);
-        println!("Result CSS: {}", result);
-        result
+        self.rules_to_css(&filtered_rules)
     }
 
     /// 检查选择器是否被使用
     ///
-    /// 判断选择器中是否包含已注册的类名。
+    /// 判断选择器中是否包含已注册的类名、ID 或属性。
+    /// 只有注册过对应类型时才检查该类型，例如未注册任何 ID 时不会因 ID 删除规则。
     ///
     /// # Arguments
     ///
@@ -321,24 +651,34 @@ impl StyleOptimizer {
     ///
     /// 如果选择器被使用则返回true，否则返回false
     fn is_selector_used(&self, selector: &str) -> bool {
-        if self.used_classes.is_empty() {
+        if !self.has_registered_usage() {
             return true; // 如果没有注册任何使用的类，则保留所有选择器
         }
 
-        // 提取选择器中的类名（.className）
-        let class_regex = regex::Regex::new(r"\.([\w-]+)").unwrap();
-        let mut has_class = false;
+        // 提取选择器中的类名（.className）、ID（#id）和属性（[attr]）
+        let checks = [
+            (r"\.([\w-]+)", &self.used_classes),
+            (r"#([\w-]+)", &self.used_ids),
+            (r"\[\s*([\w-]+)", &self.used_attributes),
+        ];
+        let mut has_tracked = false;
 
-        for cap in class_regex.captures_iter(selector) {
-            has_class = true;
-            let class_name = &cap[1];
-            if self.used_classes.contains(class_name) {
-                return true;
+        for (pattern, used) in checks {
+            if used.is_empty() {
+                continue;
+            }
+
+            let regex = regex::Regex::new(pattern).unwrap();
+            for cap in regex.captures_iter(selector) {
+                has_tracked = true;
+                if used.contains(&cap[1]) {
+                    return true;
+                }
             }
         }
 
-        // 如果选择器中没有类名，则保留它（如元素选择器）
-        !has_class
+        // 如果选择器中没有需要检查的类名、ID 或属性，则保留它（如元素选择器）
+        !has_tracked
     }
 
     /// 合并相同的规则
@@ -370,19 +710,18 @@ impl StyleOptimizer {
         // 第二步：对每个选择器的声明进行去重
         let mut merged_rules = Vec::new();
         for (selector, declarations) in selector_map {
-            let mut unique_declarations = HashMap::new();
+            let mut final_declarations: Vec<(String, String)> = Vec::new();
 
-            // 后面的声明会覆盖前面的声明（如果属性相同）
+            // 后面的声明会覆盖前面的同名声明；简写属性还会覆盖前面的对应普通属性，
+            // 如 `background` 会覆盖之前的 `background-image`。保持声明的原有顺序。
             for (property, value) in declarations {
-                unique_declarations.insert(property, value);
+                let longhands = shorthand_longhands(&property);
+                final_declarations.retain(|(existing, _)| {
+                    existing != &property && !longhands.contains(&existing.as_str())
+                });
+                final_declarations.push((property, value));
             }
 
-            // 将 HashMap 转换回 Vec
-            let final_declarations = unique_declarations
-                .into_iter()
-                .map(|(k, v)| (k, v))
-                .collect();
-
             merged_rules.push((selector, final_declarations));
         }
 
@@ -407,88 +746,90 @@ impl StyleOptimizer {
     fn parse_css_rules(&self, css: &str) -> Vec<(String, Vec<(String, String)>)> {
         let mut rules = Vec::new();
 
-        // 简化实现：使用基本的字符串解析
+        // 简化实现：按字符扫描，跳过注释，引号字符串和括号（如 `url()`）内部的 `{`、`}` 不作为规则边界
         // 实际项目中应使用专业的 CSS 解析器
 
-        // 移除注释
-        let css_no_comments = css.replace(regex::Regex::new(r"/\*.*?\*/").unwrap().as_str(), "");
-
-        // 分割规则
         let mut current_selector = String::new();
-        let mut current_declarations = Vec::new();
+        let mut current_body = String::new();
         let mut in_declarations = false;
-
-        // 打印调试信息
-        println!("CSS to parse: {}", css_no_comments);
-
-        // 分割成多行处理
-        for line in css_no_comments.lines() {
-            let trimmed = line.trim();
-
-            if trimmed.is_empty() {
+        let mut depth = 0usize;
+        let mut paren_depth = 0usize;
+        let mut quote: Option<char> = None;
+        let mut chars = css.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let buffer = if in_declarations {
+                &mut current_body
+            } else {
+                &mut current_selector
+            };
+
+            if let Some(q) = quote {
+                buffer.push(c);
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        buffer.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
                 continue;
             }
 
-            if !in_declarations && trimmed.contains('{') {
-                // 开始新规则
-                let selector_part = trimmed.split('{').next().unwrap_or("").trim();
-                current_selector = selector_part.to_string();
-                in_declarations = true;
-                current_declarations = Vec::new();
-
-                // 检查是否在同一行有声明
-                if let Some(decl_part) = trimmed.split('{').nth(1) {
-                    if decl_part.contains('}') {
-                        // 单行规则
-                        let decl_content = decl_part.split('}').next().unwrap_or("").trim();
-                        self.parse_declarations(decl_content, &mut current_declarations);
+            // 移除注释
+            if c == '/' && chars.peek() == Some(&'*') {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                continue;
+            }
 
-                        // 添加规则并重置
-                        if !current_selector.is_empty() {
-                            rules.push((current_selector.clone(), current_declarations.clone()));
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '(' => paren_depth += 1,
+                ')' => paren_depth = paren_depth.saturating_sub(1),
+                '{' if paren_depth == 0 => {
+                    depth += 1;
+                    if !in_declarations {
+                        // 开始新规则
+                        in_declarations = true;
+                        continue;
+                    }
+                }
+                '}' if paren_depth == 0 && in_declarations => {
+                    depth -= 1;
+                    if depth == 0 {
+                        // 结束规则
+                        let selector = current_selector.trim().to_string();
+                        if !selector.is_empty() {
+                            let mut declarations = Vec::new();
+                            self.parse_declarations(&current_body, &mut declarations);
+                            rules.push((selector, declarations));
                         }
 
-                        current_selector = String::new();
-                        current_declarations = Vec::new();
+                        current_selector.clear();
+                        current_body.clear();
                         in_declarations = false;
-                    } else {
-                        // 多行规则，处理第一行声明
-                        self.parse_declarations(decl_part, &mut current_declarations);
+                        continue;
                     }
                 }
-            } else if in_declarations && trimmed.contains('}') {
-                // 结束规则
-                let decl_part = trimmed.split('}').next().unwrap_or("").trim();
-                if !decl_part.is_empty() {
-                    self.parse_declarations(decl_part, &mut current_declarations);
-                }
-
-                // 添加规则
-                if !current_selector.is_empty() {
-                    rules.push((current_selector.clone(), current_declarations.clone()));
-                }
-
-                current_selector = String::new();
-                current_declarations = Vec::new();
-                in_declarations = false;
-            } else if in_declarations {
-                // 处理声明
-                self.parse_declarations(trimmed, &mut current_declarations);
+                _ => {}
             }
-        }
 
-        // 处理可能的未闭合规则
-        if in_declarations && !current_selector.is_empty() {
-            rules.push((current_selector.clone(), current_declarations.clone()));
+            buffer.push(c);
         }
 
-        // 打印解析结果
-        println!("Parsed {} rules", rules.len());
-        for (selector, declarations) in &rules {
-            println!("Selector: {}", selector);
-            for (prop, value) in declarations {
-                println!("  {} = {}", prop, value);
-            }
+        // 处理可能的未闭合规则
+        let selector = current_selector.trim().to_string();
+        if in_declarations && !selector.is_empty() {
+            let mut declarations = Vec::new();
+            self.parse_declarations(&current_body, &mut declarations);
+            rules.push((selector, declarations));
         }
 
         rules
@@ -503,7 +844,7 @@ impl StyleOptimizer {
     /// * `text` - 要解析的声明文本
     /// * `declarations` - 存储解析结果的声明列表
     fn parse_declarations(&self, text: &str, declarations: &mut Vec<(String, String)>) {
-        for decl in text.split(';') {
+        for decl in split_declarations(text) {
             let decl = decl.trim();
             if decl.is_empty() {
                 continue;
@@ -511,7 +852,7 @@ impl StyleOptimizer {
 
             if let Some(colon_pos) = decl.find(':') {
                 let property = decl[..colon_pos].trim().to_string();
-                let value = decl[colon_pos + 1..].trim().to_string();
+                let value = collapse_whitespace(decl[colon_pos + 1..].trim());
 
                 if !property.is_empty() && !value.is_empty() {
                     declarations.push((property, value));
@@ -705,10 +1046,526 @@ impl StyleOptimizer {
     }
 }
 
+/// 按 `;` 拆分声明，忽略引号字符串和括号（如 `url()`）内部的分号
+pub(crate) fn split_declarations(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (index, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
+
+/// 将引号字符串以外的连续空白折叠为一个空格，使跨行书写的值变为单行
+pub(crate) fn collapse_whitespace(value: &str) -> String {
+    let mut collapsed = String::with_capacity(value.len());
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut pending_space = false;
+
+    for c in value.chars() {
+        if let Some(q) = quote {
+            collapsed.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            collapsed.push(' ');
+            pending_space = false;
+        }
+        if c == '"' || c == '\'' {
+            quote = Some(c);
+        }
+        collapsed.push(c);
+    }
+
+    collapsed
+}
+
+/// 将顶层 at-rule 从样式规则中分离
+///
+/// 返回 `(at-rule 语句, 普通样式规则, 带块的 at-rule)`，三部分各自保持原有顺序和内容。
+/// 扫描时跳过注释、引号字符串和括号内部。
+fn split_top_level_at_rules(css: &str) -> (String, String, String) {
+    let mut statements = String::new();
+    let mut rules = String::new();
+    let mut blocks = String::new();
+    let mut depth = 0usize;
+    let mut paren_depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut at_rule: Option<usize> = None;
+    let mut rest_start = 0;
+    let mut chars = css.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if let Some(q) = quote {
+            if c == '\\' {
+                chars.next();
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        if c == '/' && matches!(chars.peek(), Some((_, '*'))) {
+            chars.next();
+            let mut last = ' ';
+            for (_, c) in chars.by_ref() {
+                if last == '*' && c == '/' {
+                    break;
+                }
+                last = c;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => paren_depth += 1,
+            ')' => paren_depth = paren_depth.saturating_sub(1),
+            '@' if depth == 0 && at_rule.is_none() => {
+                rules.push_str(&css[rest_start..index]);
+                at_rule = Some(index);
+            }
+            ';' if depth == 0 && paren_depth == 0 => {
+                if let Some(start) = at_rule.take() {
+                    statements.push_str(css[start..=index].trim());
+                    statements.push('\n');
+                    rest_start = index + 1;
+                }
+            }
+            '{' if paren_depth == 0 => depth += 1,
+            '}' if paren_depth == 0 && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    if let Some(start) = at_rule.take() {
+                        blocks.push_str(css[start..=index].trim());
+                        blocks.push('\n');
+                        rest_start = index + 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // 未闭合的 at-rule 原样保留
+    match at_rule {
+        Some(start) => blocks.push_str(&css[start..]),
+        None => rules.push_str(&css[rest_start..]),
+    }
+
+    (statements, rules, blocks)
+}
+
+/// 合并条件相同的顶层 `@media` 块
+///
+/// 条件按空白归一化后比较；后续块的规则追加到第一次出现的块中，其余内容保持不变。
+fn combine_media_queries(css: &str) -> String {
+    enum Segment {
+        Text(String),
+        Media(String, Vec<String>),
+    }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut first_block: HashMap<String, usize> = HashMap::new();
+    let mut text = String::new();
+    let mut rest = css;
+
+    while let Some(start) = find_top_level_media(rest) {
+        text.push_str(&rest[..start]);
+        let block = &rest[start..];
+        let (Some(open), Some(close)) = (block.find('{'), matching_brace(block)) else {
+            rest = block;
+            break;
+        };
+
+        let condition = block["@media".len()..open]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let body = block[open + 1..close].trim().to_string();
+        rest = &block[close + 1..];
+
+        match first_block.get(&condition) {
+            Some(&index) => {
+                if let Segment::Media(_, bodies) = &mut segments[index] {
+                    bodies.push(body);
+                }
+            }
+            None => {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+                first_block.insert(condition.clone(), segments.len());
+                segments.push(Segment::Media(condition, vec![body]));
+            }
+        }
+    }
+    text.push_str(rest);
+    segments.push(Segment::Text(text));
+
+    segments
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text,
+            Segment::Media(condition, bodies) => {
+                format!("@media {} {{\n{}\n}}", condition, bodies.join("\n"))
+            }
+        })
+        .collect()
+}
+
+/// 查找顶层（不在任何块或字符串内）的 `@media` 的起始位置
+fn find_top_level_media(css: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+
+    for (index, c) in css.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '@' if depth == 0 && css[index..].to_ascii_lowercase().starts_with("@media") => {
+                return Some(index);
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// 返回与第一个 `{` 匹配的 `}` 的位置
+fn matching_brace(block: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+
+    for (index, c) in block.char_indices() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '{' => depth += 1,
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// 获取简写属性会重置的普通属性
+///
+/// 非简写属性返回空列表。
+fn shorthand_longhands(property: &str) -> &'static [&'static str] {
+    match property {
+        "background" => &[
+            "background-color",
+            "background-image",
+            "background-position",
+            "background-size",
+            "background-repeat",
+            "background-attachment",
+            "background-origin",
+            "background-clip",
+        ],
+        "margin" => &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        "padding" => &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        "border" => &[
+            "border-width",
+            "border-style",
+            "border-color",
+            "border-top",
+            "border-right",
+            "border-bottom",
+            "border-left",
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+            "border-image",
+        ],
+        "border-radius" => &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+        "font" => &[
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "font-stretch",
+            "font-size",
+            "line-height",
+            "font-family",
+        ],
+        "flex" => &["flex-grow", "flex-shrink", "flex-basis"],
+        "gap" => &["row-gap", "column-gap"],
+        "inset" => &["top", "right", "bottom", "left"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "outline" => &["outline-width", "outline-style", "outline-color"],
+        "list-style" => &["list-style-type", "list-style-position", "list-style-image"],
+        "transition" => &[
+            "transition-property",
+            "transition-duration",
+            "transition-timing-function",
+            "transition-delay",
+        ],
+        "animation" => &[
+            "animation-name",
+            "animation-duration",
+            "animation-timing-function",
+            "animation-delay",
+            "animation-iteration-count",
+            "animation-direction",
+            "animation-fill-mode",
+            "animation-play-state",
+        ],
+        _ => &[],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_config_presets() {
+        let dev = OptimizeConfig::development();
+        assert!(!dev.minify);
+        assert!(!dev.remove_unused);
+        assert!(dev.merge_rules);
+        assert!(!dev.optimize_selectors);
+
+        let prod = OptimizeConfig::production();
+        assert!(prod.minify);
+        assert!(prod.remove_unused);
+        assert!(prod.merge_rules);
+        assert!(prod.optimize_selectors);
+    }
+
+    #[test]
+    fn test_incremental_optimization_reuses_unchanged_rules() {
+        let mut optimizer = StyleOptimizer::new(OptimizeConfig::development());
+        let original =
+            ".button {\n  color: red;\n}\n.card {\n  padding: 20px;\n}\n.link {\n  color: blue;\n}";
+        let changed = original.replace("padding: 20px", "padding: 24px");
+
+        let first = optimizer.optimize_incremental(original);
+        assert_eq!(
+            optimizer.rule_cache_stats(),
+            RuleCacheStats { hits: 0, misses: 3 }
+        );
+        assert!(first.contains("padding: 20px"));
+
+        let second = optimizer.optimize_incremental(&changed);
+        assert_eq!(
+            optimizer.rule_cache_stats(),
+            RuleCacheStats { hits: 2, misses: 4 }
+        );
+        assert!(second.contains("padding: 24px"));
+        assert!(!second.contains("padding: 20px"));
+        assert_eq!(
+            second,
+            StyleOptimizer::new(OptimizeConfig::development()).optimize_incremental(&changed)
+        );
+
+        // 注册使用情况后缓存失效
+        optimizer.register_used_class("button");
+        assert_eq!(optimizer.optimize_incremental(&changed), second);
+        assert_eq!(optimizer.rule_cache_stats().misses, 7);
+    }
+
+    #[test]
+    fn test_combine_identical_media_queries() {
+        let css = "@media (min-width: 768px) {\n  .a { color: red; }\n}\n.b { color: blue; }\n@media  (min-width:  768px) {\n  .c { margin: 0; }\n}\n@media (max-width: 480px) {\n  .d { padding: 0; }\n}\n";
+        let optimizer = StyleOptimizer::new(OptimizeConfig {
+            minify: false,
+            remove_unused: false,
+            merge_rules: false,
+            optimize_selectors: false,
+            combine_media_queries: true,
+            target_browsers: Vec::new(),
+        });
+
+        let combined = optimizer.optimize(css);
+        assert_eq!(combined.matches("@media (min-width: 768px)").count(), 1);
+        assert_eq!(combined.matches("@media").count(), 2);
+        let a = combined.find(".a {").unwrap();
+        let c = combined.find(".c {").unwrap();
+        let b = combined.find(".b {").unwrap();
+        assert!(a < c && c < b, "{combined}");
+        assert!(combined.contains("@media (max-width: 480px)"));
+
+        // 默认关闭，保持原样
+        let untouched = StyleOptimizer::new(OptimizeConfig {
+            combine_media_queries: false,
+            ..optimizer.config.clone()
+        })
+        .optimize(css);
+        assert_eq!(untouched, css);
+
+        // 未闭合的块原样保留
+        assert_eq!(
+            combine_media_queries(".a { color: red; } @media (min-width: 1px) { .b"),
+            ".a { color: red; } @media (min-width: 1px) { .b"
+        );
+    }
+
+    #[test]
+    fn test_combine_media_queries_after_merging_rules() {
+        let css = "@import url(\"base.css\");\n@media (min-width: 768px) {\n  .a { color: red; }\n}\n.b { color: blue; }\n.b { margin: 0; }\n@media (min-width: 768px) {\n  .c { margin: 0; }\n}\n@font-face { font-family: \"Icons\"; src: url(icons.woff2); }\n";
+        let optimizer = StyleOptimizer::new(OptimizeConfig {
+            combine_media_queries: true,
+            ..OptimizeConfig::default()
+        });
+
+        let combined = optimizer.optimize(css);
+        assert_eq!(
+            combined.matches('{').count(),
+            combined.matches('}').count(),
+            "{combined}"
+        );
+        assert!(
+            combined.starts_with("@import url(\"base.css\");"),
+            "{combined}"
+        );
+        assert_eq!(combined.matches("@media").count(), 1, "{combined}");
+        assert!(
+            combined.contains("@media (min-width: 768px) {.a {color: red;}.c {margin: 0;}}"),
+            "{combined}"
+        );
+        assert!(
+            combined.contains(".b {color: blue;margin: 0;}"),
+            "{combined}"
+        );
+        assert!(
+            combined.contains("@font-face {font-family: \"Icons\";src: url(icons.woff2);}"),
+            "{combined}"
+        );
+    }
+
+    #[test]
+    fn test_parse_data_url_values() {
+        let optimizer = StyleOptimizer::default();
+        let css = r#".icon {
+            background: url(data:image/svg+xml;base64,PHN2Zz48L3N2Zz4=) no-repeat;
+            mask: url("data:image/svg+xml;utf8,<svg a='1'>{}</svg>");
+            color: red;
+        }"#;
+
+        let rules = optimizer.parse_css_rules(css);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules[0].1,
+            vec![
+                (
+                    "background".to_string(),
+                    "url(data:image/svg+xml;base64,PHN2Zz48L3N2Zz4=) no-repeat".to_string()
+                ),
+                (
+                    "mask".to_string(),
+                    r#"url("data:image/svg+xml;utf8,<svg a='1'>{}</svg>")"#.to_string()
+                ),
+                ("color".to_string(), "red".to_string()),
+            ]
+        );
+
+        let optimized = StyleOptimizer::new(OptimizeConfig {
+            remove_unused: false,
+            ..OptimizeConfig::default()
+        })
+        .optimize(css);
+        assert!(optimized.contains("url(data:image/svg+xml;base64,PHN2Zz48L3N2Zz4=) no-repeat"));
+        assert!(optimized.contains("<svg a='1'>{}</svg>"));
+    }
+
+    #[test]
+    fn test_parse_grid_template_with_slashes() {
+        let optimizer = StyleOptimizer::default();
+        let css = ".grid {\n  grid-template:\n    \"a  a\" 40px\n    \"b c\" 1fr / auto 1fr; /* areas */\n  grid-area: 1 / 2 / 3 / 4;\n}";
+
+        let rules = optimizer.parse_css_rules(css);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules[0].1,
+            vec![
+                (
+                    "grid-template".to_string(),
+                    "\"a  a\" 40px \"b c\" 1fr / auto 1fr".to_string()
+                ),
+                ("grid-area".to_string(), "1 / 2 / 3 / 4".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_css_minification() {
         let optimizer = StyleOptimizer::default();
@@ -729,6 +1586,83 @@ mod tests {
         assert!(!minified.contains("\n"));
     }
 
+    #[test]
+    fn test_minify_preserves_significant_whitespace() {
+        let optimizer = StyleOptimizer::default();
+
+        let minified =
+            optimizer.minify(".a::before {\n  content: \"a  b\";\n  margin:   0    auto;\n}");
+        assert!(minified.contains("content: \"a  b\";"));
+        assert!(minified.contains("margin: 0 auto;"));
+
+        let minified =
+            optimizer.minify(".b { background: url( \"x  y.png\" ); content:  counter(c)  '  '; }");
+        assert!(minified.contains("url( \"x  y.png\" )"));
+        assert!(minified.contains("content:  counter(c)  '  ';"));
+
+        let mut declarations = Vec::new();
+        optimizer.parse_declarations(
+            "content: \"a; b\"; background: url(data:image/png;base64,AA); color: red",
+            &mut declarations,
+        );
+        assert_eq!(
+            declarations,
+            vec![
+                ("content".to_string(), "\"a; b\"".to_string()),
+                (
+                    "background".to_string(),
+                    "url(data:image/png;base64,AA)".to_string()
+                ),
+                ("color".to_string(), "red".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_reports_without_changing_css() {
+        let mut optimizer = StyleOptimizer::default();
+        optimizer.register_used_class("button");
+
+        let css = r#"
+            .button { color: blue; }
+            .card { padding: 20px; }
+            .button { margin: 0; }
+        "#;
+        let report = optimizer.dry_run(css);
+
+        assert_eq!(report.removed_selectors, vec![".card".to_string()]);
+        assert_eq!(report.merged_selectors, vec![".button".to_string()]);
+        assert!(!report.is_empty());
+
+        let unused_free = optimizer.dry_run(".button { color: blue; }");
+        assert!(unused_free.is_empty());
+    }
+
+    #[test]
+    fn test_unused_id_and_attribute_removal() {
+        let mut optimizer = StyleOptimizer::default();
+        optimizer.register_used_id("bar");
+        optimizer.register_used_attribute("data-open");
+
+        let css = r#"
+            #foo { color: red; }
+            #bar { color: blue; }
+            [data-open="true"] { display: block; }
+            [data-hidden] { display: none; }
+            p { margin: 0; }
+            .card { padding: 8px; }
+        "#;
+        let optimized = optimizer.remove_unused_styles(css);
+
+        assert!(!optimized.contains("#foo"));
+        assert!(optimized.contains("#bar"));
+        assert!(optimized.contains("[data-open"));
+        assert!(!optimized.contains("[data-hidden]"));
+        // 元素选择器和未注册类型（类名）的选择器保持不变
+        assert!(optimized.contains("p {") || optimized.contains("p{"));
+        assert!(optimized.contains(".card"));
+    }
+
     #[test]
     fn test_unused_styles_removal() {
         let mut optimizer = StyleOptimizer::default();
@@ -775,6 +1709,37 @@ mod tests {
         assert_eq!(button_count, 1);
     }
 
+    #[test]
+    fn test_merge_rules_shorthand_resets_longhands() {
+        let optimizer = StyleOptimizer::default();
+        let css = r#"
+            .hero {
+                background-image: url(x.png);
+                color: red;
+            }
+
+            .hero {
+                background: blue;
+                margin-top: 4px;
+            }
+
+            .hero {
+                background-size: cover;
+            }
+        "#;
+
+        let merged = optimizer.merge_rules(css);
+
+        assert!(!merged.contains("background-image"));
+        assert!(merged.contains("background: blue"));
+        assert!(merged.contains("margin-top: 4px"));
+        // 简写之后的普通属性仍然保留，并位于简写之后
+        let shorthand = merged.find("background: blue").unwrap();
+        let longhand = merged.find("background-size: cover").unwrap();
+        assert!(shorthand < longhand);
+        assert!(merged.find("color: red").unwrap() < shorthand);
+    }
+
     #[test]
     fn test_optimize_selectors() {
         let optimizer = StyleOptimizer::default();
@@ -875,4 +1840,39 @@ mod tests {
             standalone_box_count
         );
     }
+
+    #[test]
+    fn test_target_browsers_prefixes() {
+        let css = ".box { -webkit-transform: scale(2); transform: scale(2); }";
+        let optimizer_for = |target: &str| {
+            StyleOptimizer::new(OptimizeConfig {
+                minify: false,
+                remove_unused: false,
+                target_browsers: vec![target.to_string()],
+                ..OptimizeConfig::default()
+            })
+        };
+
+        let legacy = optimizer_for("safari 8").optimize(css);
+        assert!(legacy.contains("-webkit-transform: scale(2)"));
+
+        let modern = optimizer_for("last 2 versions").optimize(css);
+        assert!(!modern.contains("-webkit-transform"));
+        assert!(modern.contains("transform: scale(2)"));
+    }
+
+    #[test]
+    fn test_target_browsers_nesting() {
+        let css = ".card { color: red; &:hover { color: blue; } }";
+        let optimizer = StyleOptimizer::new(OptimizeConfig {
+            minify: false,
+            remove_unused: false,
+            target_browsers: vec!["chrome >= 100".to_string()],
+            ..OptimizeConfig::default()
+        });
+
+        let optimized = optimizer.optimize(css);
+        assert!(optimized.contains(".card:hover"));
+        assert!(!optimized.contains('&'));
+    }
 }
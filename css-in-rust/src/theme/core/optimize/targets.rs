@@ -0,0 +1,573 @@
+//! 浏览器目标模块
+//!
+//! 将 browserslist 风格的查询（如 `last 2 versions`、`safari >= 12`）解析为各浏览器的最低版本，
+//! 并据此决定是否保留厂商前缀、是否需要展开 CSS 嵌套。
+//! 内置的版本矩阵只覆盖主流浏览器，未识别的查询会被忽略。
+
+use regex::Regex;
+use std::collections::HashMap;
+
+/// 从未支持时使用的版本号
+const NEVER: f32 = f32::INFINITY;
+
+/// 各浏览器最近的版本，按从新到旧排列
+const RECENT_VERSIONS: &[(&str, &[f32])] = &[
+    ("chrome", &[141.0, 140.0, 139.0, 138.0]),
+    ("edge", &[141.0, 140.0, 139.0, 138.0]),
+    ("firefox", &[143.0, 142.0, 141.0, 140.0]),
+    ("safari", &[26.0, 18.6, 18.5, 18.4]),
+    ("ios_saf", &[26.0, 18.6, 18.5, 18.4]),
+    ("opera", &[122.0, 121.0, 120.0, 119.0]),
+    ("samsung", &[28.0, 27.0, 26.0, 25.0]),
+];
+
+/// 各厂商前缀对应的浏览器
+const VENDOR_BROWSERS: &[(&str, &[&str])] = &[
+    (
+        "webkit",
+        &["chrome", "edge", "safari", "ios_saf", "opera", "samsung"],
+    ),
+    ("moz", &["firefox"]),
+    ("ms", &["ie", "edge"]),
+    ("o", &["opera"]),
+];
+
+/// 属性开始支持无前缀写法的浏览器版本
+///
+/// 属性名按前缀匹配，`animation` 同时覆盖 `animation-name` 等子属性。
+/// 未列出的浏览器视为无需前缀。
+const UNPREFIXED_SINCE: &[(&str, &[(&str, f32)])] = &[
+    (
+        "transform",
+        &[
+            ("chrome", 36.0),
+            ("safari", 9.0),
+            ("ios_saf", 9.0),
+            ("opera", 23.0),
+            ("samsung", 3.0),
+            ("firefox", 16.0),
+            ("ie", 10.0),
+        ],
+    ),
+    (
+        "transition",
+        &[
+            ("chrome", 26.0),
+            ("safari", 7.0),
+            ("ios_saf", 7.0),
+            ("opera", 12.1),
+            ("firefox", 16.0),
+            ("ie", 10.0),
+        ],
+    ),
+    (
+        "animation",
+        &[
+            ("chrome", 43.0),
+            ("safari", 9.0),
+            ("ios_saf", 9.0),
+            ("opera", 30.0),
+            ("samsung", 4.0),
+            ("firefox", 16.0),
+            ("ie", 10.0),
+        ],
+    ),
+    (
+        "box-shadow",
+        &[
+            ("chrome", 10.0),
+            ("safari", 5.1),
+            ("ios_saf", 5.0),
+            ("firefox", 4.0),
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            ("chrome", 5.0),
+            ("safari", 5.0),
+            ("ios_saf", 4.0),
+            ("firefox", 4.0),
+        ],
+    ),
+    (
+        "box-sizing",
+        &[
+            ("chrome", 10.0),
+            ("safari", 5.1),
+            ("ios_saf", 6.0),
+            ("firefox", 29.0),
+        ],
+    ),
+    (
+        "flex",
+        &[
+            ("chrome", 29.0),
+            ("safari", 9.0),
+            ("ios_saf", 9.0),
+            ("opera", 17.0),
+            ("firefox", 28.0),
+            ("ie", 11.0),
+        ],
+    ),
+    (
+        "user-select",
+        &[
+            ("chrome", 54.0),
+            ("edge", 79.0),
+            ("opera", 41.0),
+            ("samsung", 6.0),
+            ("safari", NEVER),
+            ("ios_saf", NEVER),
+            ("firefox", 69.0),
+            ("ie", NEVER),
+        ],
+    ),
+    (
+        "appearance",
+        &[
+            ("chrome", 84.0),
+            ("edge", 84.0),
+            ("opera", 70.0),
+            ("samsung", 14.0),
+            ("safari", 15.4),
+            ("ios_saf", 15.4),
+            ("firefox", 80.0),
+        ],
+    ),
+    (
+        "backdrop-filter",
+        &[
+            ("chrome", 76.0),
+            ("edge", 79.0),
+            ("opera", 63.0),
+            ("samsung", 12.0),
+            ("safari", 18.0),
+            ("ios_saf", 18.0),
+            ("firefox", 103.0),
+        ],
+    ),
+    (
+        "mask",
+        &[
+            ("chrome", 120.0),
+            ("edge", 120.0),
+            ("opera", 106.0),
+            ("samsung", 25.0),
+            ("safari", 15.4),
+            ("ios_saf", 15.4),
+            ("firefox", 53.0),
+        ],
+    ),
+];
+
+/// 原生 CSS 嵌套开始支持的浏览器版本
+const NESTING_SINCE: &[(&str, f32)] = &[
+    ("chrome", 112.0),
+    ("edge", 112.0),
+    ("firefox", 117.0),
+    ("safari", 16.5),
+    ("ios_saf", 16.5),
+    ("opera", 98.0),
+    ("samsung", 23.0),
+    ("ie", NEVER),
+];
+
+/// 浏览器目标
+///
+/// 记录每个目标浏览器需要支持的最低版本。
+///
+/// # Examples
+///
+/// ```
+/// use css_in_rust::theme::core::optimize::BrowserTargets;
+///
+/// let modern = BrowserTargets::from_queries(&["last 2 versions".to_string()]);
+/// assert!(!modern.needs_prefix("-webkit-transform"));
+/// assert!(modern.supports_nesting());
+///
+/// let legacy = BrowserTargets::from_queries(&["safari >= 8".to_string()]);
+/// assert!(legacy.needs_prefix("-webkit-transform"));
+/// assert!(!legacy.supports_nesting());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BrowserTargets {
+    /// 浏览器到最低版本的映射
+    minimum_versions: HashMap<String, f32>,
+}
+
+impl BrowserTargets {
+    /// 解析 browserslist 风格的查询
+    ///
+    /// 支持 `defaults`、`last N versions`、`last N <browser> versions`、
+    /// `<browser> >= V` 以及 `<browser> V`。多个查询取并集，未识别的查询会被忽略。
+    pub fn from_queries(queries: &[String]) -> Self {
+        let mut targets = Self::default();
+        let last_versions = Regex::new(r"^last\s+(\d+)\s+(?:(\w+)\s+)?versions?$").unwrap();
+        let browser_version = Regex::new(r"^(\w+)\s*(>=)?\s*(\d+(?:\.\d+)?)$").unwrap();
+
+        for query in queries {
+            let query = query.trim().to_lowercase();
+            let query = if query == "defaults" {
+                "last 2 versions".to_string()
+            } else {
+                query
+            };
+
+            if let Some(caps) = last_versions.captures(&query) {
+                let count: usize = caps[1].parse().unwrap_or(1);
+                let only = caps.get(2).map(|m| normalize_browser(m.as_str()));
+                for (browser, versions) in RECENT_VERSIONS {
+                    if only.is_some_and(|only| only != *browser) {
+                        continue;
+                    }
+                    let index = count.clamp(1, versions.len()) - 1;
+                    targets.include(browser, versions[index]);
+                }
+            } else if let Some(caps) = browser_version.captures(&query) {
+                if let Ok(version) = caps[3].parse() {
+                    targets.include(normalize_browser(&caps[1]), version);
+                }
+            }
+        }
+
+        targets
+    }
+
+    /// 是否没有任何目标浏览器
+    pub fn is_empty(&self) -> bool {
+        self.minimum_versions.is_empty()
+    }
+
+    /// 判断带厂商前缀的属性是否仍需保留
+    ///
+    /// 只要有一个使用该前缀的目标浏览器尚不支持无前缀写法就需要保留。
+    /// 不带前缀或未收录的属性总是保留。
+    pub fn needs_prefix(&self, prefixed_property: &str) -> bool {
+        let Some((vendor, property)) = split_vendor_prefix(prefixed_property) else {
+            return true;
+        };
+        let Some((_, since)) = UNPREFIXED_SINCE
+            .iter()
+            .find(|(name, _)| property.starts_with(name))
+        else {
+            return true;
+        };
+        let browsers = VENDOR_BROWSERS
+            .iter()
+            .find(|(name, _)| *name == vendor)
+            .map(|(_, browsers)| *browsers)
+            .unwrap_or(&[]);
+
+        browsers.iter().any(|browser| {
+            let Some(minimum) = self.minimum_versions.get(*browser) else {
+                return false;
+            };
+            since
+                .iter()
+                .find(|(name, _)| name == browser)
+                .is_some_and(|(_, version)| minimum < version)
+        })
+    }
+
+    /// 判断所有目标浏览器是否都支持原生 CSS 嵌套
+    pub fn supports_nesting(&self) -> bool {
+        self.minimum_versions.iter().all(|(browser, minimum)| {
+            NESTING_SINCE
+                .iter()
+                .find(|(name, _)| name == browser)
+                .is_none_or(|(_, version)| minimum >= version)
+        })
+    }
+
+    /// 移除目标浏览器不再需要的厂商前缀声明
+    pub fn strip_unneeded_prefixes(&self, css: &str) -> String {
+        let declaration =
+            Regex::new(r"(^|[{;\s])(-(?:webkit|moz|ms|o)-[a-zA-Z-]+)\s*:[^;{}]*;?").unwrap();
+
+        declaration
+            .replace_all(css, |caps: &regex::Captures| {
+                if self.needs_prefix(&caps[2]) {
+                    caps[0].to_string()
+                } else {
+                    caps[1].to_string()
+                }
+            })
+            .into_owned()
+    }
+
+    /// 记录浏览器需要支持的版本，保留最低值
+    fn include(&mut self, browser: &str, version: f32) {
+        let minimum = self
+            .minimum_versions
+            .entry(browser.to_string())
+            .or_insert(version);
+        *minimum = minimum.min(version);
+    }
+}
+
+/// 统一浏览器名称
+fn normalize_browser(name: &str) -> &str {
+    match name {
+        "ios" | "ios_safari" | "iossafari" => "ios_saf",
+        "samsunginternet" => "samsung",
+        "explorer" => "ie",
+        other => other,
+    }
+}
+
+/// 拆分厂商前缀：`-webkit-transform` → (`webkit`, `transform`)
+fn split_vendor_prefix(property: &str) -> Option<(&str, &str)> {
+    let rest = property.strip_prefix('-')?;
+    let dash = rest.find('-')?;
+    Some((&rest[..dash], &rest[dash + 1..]))
+}
+
+/// 可包含嵌套规则、需要递归展开的条件规则
+const GROUPING_AT_RULES: &[&str] = &["@media", "@supports", "@container", "@layer", "@document"];
+
+/// 块内的一项内容
+enum CssItem {
+    /// 以 `;` 结尾的语句，如声明或 `@import`
+    Statement(String),
+    /// 带 `{}` 的块（前导、内容）
+    Block(String, String),
+}
+
+/// 将嵌套的 CSS 规则展开为平铺规则
+///
+/// `&` 替换为父选择器，不含 `&` 的子选择器视为后代选择器；
+/// 规则内嵌套的 `@media` 等条件规则会包裹展开后的父规则。
+/// 只展开样式规则，`@import`、`@font-face`、`@keyframes` 等其他语句和 at 规则原样保留。
+pub(crate) fn flatten_nesting(css: &str) -> String {
+    let mut output = String::new();
+
+    for item in split_items(css) {
+        match item {
+            CssItem::Statement(statement) => {
+                output.push_str(&statement);
+                output.push('\n');
+            }
+            CssItem::Block(prelude, body) if is_grouping_at_rule(&prelude) => {
+                output.push_str(&format!("{} {{ {} }}\n", prelude, flatten_nesting(&body)));
+            }
+            CssItem::Block(prelude, body) if prelude.starts_with('@') => {
+                output.push_str(&format!("{} {{ {} }}\n", prelude, body));
+            }
+            CssItem::Block(prelude, body) => flatten_rule(&prelude, &body, &mut output),
+        }
+    }
+
+    output
+}
+
+/// 判断 at 规则是否为可嵌套样式规则的条件规则
+fn is_grouping_at_rule(prelude: &str) -> bool {
+    let name = prelude
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .unwrap_or_default();
+    GROUPING_AT_RULES
+        .iter()
+        .any(|rule| name.eq_ignore_ascii_case(rule))
+}
+
+/// 展开单条规则，写入输出
+fn flatten_rule(selector: &str, body: &str, output: &mut String) {
+    let mut declarations = Vec::new();
+    let mut nested = Vec::new();
+    for item in split_items(body) {
+        match item {
+            CssItem::Statement(declaration) => declarations.push(declaration),
+            CssItem::Block(prelude, nested_body) => nested.push((prelude, nested_body)),
+        }
+    }
+
+    if !declarations.is_empty() {
+        output.push_str(&format!("{} {{ {} }}\n", selector, declarations.join(" ")));
+    }
+
+    for (prelude, nested_body) in nested {
+        if prelude.starts_with('@') {
+            let mut inner = String::new();
+            flatten_rule(selector, &nested_body, &mut inner);
+            output.push_str(&format!("{} {{ {} }}\n", prelude, inner.trim()));
+        } else {
+            flatten_rule(
+                &resolve_nested_selector(selector, &prelude),
+                &nested_body,
+                output,
+            );
+        }
+    }
+}
+
+/// 将嵌套选择器与父选择器组合
+fn resolve_nested_selector(parent: &str, nested: &str) -> String {
+    let mut selectors = Vec::new();
+    for parent_part in parent.split(',').map(str::trim) {
+        for nested_part in nested.split(',').map(str::trim) {
+            if nested_part.contains('&') {
+                selectors.push(nested_part.replace('&', parent_part));
+            } else {
+                selectors.push(format!("{} {}", parent_part, nested_part));
+            }
+        }
+    }
+    selectors.join(", ")
+}
+
+/// 按顺序拆分块内容为语句和子块
+///
+/// 引号和括号内的 `{`、`}`、`;` 不作为分隔符，如 `content: "{;}"` 或 `url(data:...;base64,...)`。
+fn split_items(css: &str) -> Vec<CssItem> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut parens = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut prelude = String::new();
+
+    for c in css.chars() {
+        if let Some(q) = quote {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            '(' => {
+                parens += 1;
+                current.push(c);
+            }
+            ')' => {
+                parens = parens.saturating_sub(1);
+                current.push(c);
+            }
+            '{' if parens == 0 => {
+                if depth == 0 {
+                    prelude = current.trim().to_string();
+                    current.clear();
+                } else {
+                    current.push(c);
+                }
+                depth += 1;
+            }
+            '}' if parens == 0 && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    items.push(CssItem::Block(
+                        std::mem::take(&mut prelude),
+                        current.trim().to_string(),
+                    ));
+                    current.clear();
+                } else {
+                    current.push(c);
+                }
+            }
+            ';' if depth == 0 && parens == 0 => {
+                let statement = current.trim();
+                if !statement.is_empty() {
+                    items.push(CssItem::Statement(format!("{};", statement)));
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    let rest = current.trim();
+    if depth == 0 && !rest.is_empty() {
+        items.push(CssItem::Statement(format!("{};", rest)));
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets(queries: &[&str]) -> BrowserTargets {
+        BrowserTargets::from_queries(&queries.iter().map(|q| q.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_queries() {
+        let modern = targets(&["last 2 versions"]);
+        assert_eq!(modern.minimum_versions.get("chrome"), Some(&140.0));
+        assert_eq!(modern.minimum_versions.get("safari"), Some(&18.6));
+        assert!(!modern.minimum_versions.contains_key("ie"));
+
+        let mixed = targets(&["defaults", "Safari >= 12", "ie 11", "> 0.5%"]);
+        assert_eq!(mixed.minimum_versions.get("safari"), Some(&12.0));
+        assert_eq!(mixed.minimum_versions.get("ie"), Some(&11.0));
+    }
+
+    #[test]
+    fn test_prefix_decisions() {
+        let modern = targets(&["last 2 versions"]);
+        assert!(!modern.needs_prefix("-webkit-transform"));
+        assert!(!modern.needs_prefix("-moz-box-shadow"));
+        // Safari 仍需要 -webkit-user-select
+        assert!(modern.needs_prefix("-webkit-user-select"));
+        // 未收录的属性保留
+        assert!(modern.needs_prefix("-webkit-text-stroke"));
+
+        let legacy = targets(&["chrome 30"]);
+        assert!(legacy.needs_prefix("-webkit-transform"));
+        assert!(!legacy.needs_prefix("-moz-transform"));
+    }
+
+    #[test]
+    fn test_flatten_nesting() {
+        let css = ".card { color: red; &:hover { color: blue; } .title { margin: 0; } @media (min-width: 600px) { padding: 8px; } }";
+        let flattened = flatten_nesting(css);
+
+        assert!(flattened.contains(".card { color: red; }"));
+        assert!(flattened.contains(".card:hover { color: blue; }"));
+        assert!(flattened.contains(".card .title { margin: 0; }"));
+        assert!(flattened.contains("@media (min-width: 600px) { .card { padding: 8px; } }"));
+    }
+
+    #[test]
+    fn test_flatten_nesting_keeps_font_face() {
+        let css = "@font-face { font-family: \"Brand\"; src: url(brand.woff2) format(\"woff2\"); } .a { & b { color: red; } }";
+        let flattened = flatten_nesting(css);
+
+        assert!(flattened.contains(
+            "@font-face { font-family: \"Brand\"; src: url(brand.woff2) format(\"woff2\"); }"
+        ));
+        assert!(flattened.contains(".a b { color: red; }"));
+    }
+
+    #[test]
+    fn test_flatten_nesting_keeps_top_level_import() {
+        let css = "@charset \"utf-8\"; @import url(\"theme.css\"); .a { color: red; }";
+        let flattened = flatten_nesting(css);
+
+        assert!(flattened.starts_with("@charset \"utf-8\";\n@import url(\"theme.css\");\n"));
+        assert!(flattened.contains(".a { color: red; }"));
+    }
+
+    #[test]
+    fn test_flatten_nesting_ignores_delimiters_in_strings() {
+        let css = ".icon { content: \"{;}\"; background: url(data:image/png;base64,AAAA); &:hover { color: blue; } }";
+        let flattened = flatten_nesting(css);
+
+        assert!(flattened
+            .contains(".icon { content: \"{;}\"; background: url(data:image/png;base64,AAAA); }"));
+        assert!(flattened.contains(".icon:hover { color: blue; }"));
+    }
+}
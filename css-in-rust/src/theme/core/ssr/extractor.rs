@@ -1,7 +1,10 @@
 use crate::theme::core::cache::cache_entity::{CacheValue, STYLE_PREFIX};
-use crate::theme::core::cache::CacheManager;
+use crate::theme::core::cache::{
+    CacheManager, CachedComponentStyle, ComponentCacheKey, ComponentStyleCache,
+};
 use crate::theme::core::ssr::{ServerStyleSheet, StyleSheetManager};
 use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 
 /// 样式提取器
 ///
@@ -32,6 +35,10 @@ pub struct StyleExtractor {
     sheet_manager: StyleSheetManager,
     /// 样式优先级映射
     style_order: HashMap<String, i32>,
+    /// 按组件注册的样式
+    component_styles: ComponentStyleCache,
+    /// 组件集合CSS缓存，键为组件列表的哈希
+    route_css_cache: Mutex<HashMap<String, String>>,
 }
 
 impl StyleExtractor {
@@ -60,9 +67,110 @@ impl StyleExtractor {
             extracted_styles: HashSet::new(),
             sheet_manager: StyleSheetManager::new(),
             style_order: HashMap::new(),
+            component_styles: ComponentStyleCache::new(),
+            route_css_cache: Mutex::new(HashMap::new()),
         }
     }
 
+    /// 注册组件样式
+    ///
+    /// 样式按 `ComponentCacheKey` 中的组件名称归类，供 `extract_for_components` 按路由提取。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 组件样式缓存键
+    /// * `style` - 组件样式
+    pub fn register_component_style(
+        &mut self,
+        key: ComponentCacheKey,
+        style: CachedComponentStyle,
+    ) {
+        self.component_styles.set(key, style);
+        self.route_css_cache.lock().unwrap().clear();
+    }
+
+    /// 提取一组组件的关键CSS
+    ///
+    /// 按组件顺序拼接已注册的组件样式，同一类名只输出一次，
+    /// 用于只内联某个路由实际渲染的组件样式。
+    /// 结果按组件列表的哈希缓存，相同组件集合的重复请求直接返回缓存结果，
+    /// 注册新的组件样式时缓存失效。
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - 路由渲染的组件名称列表
+    ///
+    /// # Returns
+    ///
+    /// 拼接后的CSS，没有匹配的样式时返回空字符串
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::cache::{CacheManager, CachedComponentStyle, ComponentCacheKey};
+    /// use css_in_rust::theme::core::ssr::StyleExtractor;
+    ///
+    /// let mut extractor = StyleExtractor::new(CacheManager::new("app"));
+    /// extractor.register_component_style(
+    ///     ComponentCacheKey {
+    ///         component: "Button".to_string(),
+    ///         props_hash: 1,
+    ///         theme_hash: 1,
+    ///     },
+    ///     CachedComponentStyle {
+    ///         class_name: "btn".to_string(),
+    ///         css: ".btn { color: blue; }".to_string(),
+    ///         variables: Vec::new(),
+    ///         timestamp: 0,
+    ///         usage_count: 0,
+    ///         style_hash: 0,
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(extractor.extract_for_components(&["Button"]), ".btn { color: blue; }");
+    /// ```
+    pub fn extract_for_components(&self, components: &[&str]) -> String {
+        let cache_key = Self::components_hash(components);
+        if let Some(css) = self.route_css_cache.lock().unwrap().get(&cache_key) {
+            return css.clone();
+        }
+
+        let css = self.combine_component_styles(components);
+        self.route_css_cache
+            .lock()
+            .unwrap()
+            .insert(cache_key, css.clone());
+        css
+    }
+
+    /// 拼接组件样式，同一类名只输出一次
+    fn combine_component_styles(&self, components: &[&str]) -> String {
+        let mut seen = HashSet::new();
+        let mut css = Vec::new();
+
+        for component in components {
+            for style in self.component_styles.styles_for_component(component) {
+                if seen.insert(style.class_name.as_str()) {
+                    css.push(style.css.as_str());
+                }
+            }
+        }
+
+        css.join("\n")
+    }
+
+    /// 计算组件列表的稳定哈希
+    fn components_hash(components: &[&str]) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        for component in components {
+            hasher.update(component.as_bytes());
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
     /// 提取所有样式
     ///
     /// 从缓存中提取所有样式，并将其转换为服务端渲染的样式表。
@@ -282,3 +390,83 @@ impl StyleExtractor {
         self.style_order.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component_style(class_name: &str, css: &str) -> CachedComponentStyle {
+        CachedComponentStyle {
+            class_name: class_name.to_string(),
+            css: css.to_string(),
+            variables: Vec::new(),
+            timestamp: 0,
+            usage_count: 0,
+            style_hash: 0,
+        }
+    }
+
+    fn component_key(component: &str, props_hash: u64) -> ComponentCacheKey {
+        ComponentCacheKey {
+            component: component.to_string(),
+            props_hash,
+            theme_hash: 0,
+        }
+    }
+
+    #[test]
+    fn test_extract_for_components_returns_route_styles_only() {
+        let mut extractor = StyleExtractor::new(CacheManager::new("app"));
+        extractor.register_component_style(
+            component_key("Button", 1),
+            component_style("btn-primary", ".btn-primary { color: blue; }"),
+        );
+        extractor.register_component_style(
+            component_key("Button", 2),
+            component_style("btn-default", ".btn-default { color: black; }"),
+        );
+        extractor.register_component_style(
+            component_key("Table", 1),
+            component_style("table", ".table { width: 100%; }"),
+        );
+
+        let button_route = extractor.extract_for_components(&["Button"]);
+        assert_eq!(
+            button_route,
+            ".btn-default { color: black; }\n.btn-primary { color: blue; }"
+        );
+        assert!(!button_route.contains(".table"));
+
+        let table_route = extractor.extract_for_components(&["Table", "Table"]);
+        assert_eq!(table_route, ".table { width: 100%; }");
+
+        assert!(extractor.extract_for_components(&["Modal"]).is_empty());
+    }
+
+    #[test]
+    fn test_extract_for_components_reuses_cached_result() {
+        let mut extractor = StyleExtractor::new(CacheManager::new("app"));
+        extractor.register_component_style(
+            component_key("Button", 1),
+            component_style("btn", ".btn { color: blue; }"),
+        );
+
+        let first = extractor.extract_for_components(&["Button"]);
+
+        // 绕过 register_component_style 直接修改组件缓存，命中缓存时结果保持不变
+        extractor.component_styles.set(
+            component_key("Button", 2),
+            component_style("btn-link", ".btn-link { color: gray; }"),
+        );
+        assert_eq!(extractor.extract_for_components(&["Button"]), first);
+
+        // 通过注册接口添加样式会使缓存失效
+        extractor.register_component_style(
+            component_key("Button", 3),
+            component_style("btn-text", ".btn-text { color: black; }"),
+        );
+        let refreshed = extractor.extract_for_components(&["Button"]);
+        assert!(refreshed.contains(".btn-link"));
+        assert!(refreshed.contains(".btn-text"));
+    }
+}
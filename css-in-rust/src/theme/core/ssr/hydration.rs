@@ -3,6 +3,9 @@ use std::collections::{HashMap, HashSet};
 /// 样式水合配置
 ///
 /// 控制客户端水合过程中的行为，包括样式去重、服务端样式移除和懒加载。
+/// 启用懒加载时为部分水合模式：只有 `eager_ids` 中的样式立即水合，其余样式记录为延迟水合。
+/// 延迟的样式不会自动完成水合，需要调用 [`StyleHydration::schedule_deferred`] 在浏览器空闲时完成，
+/// 或在交互时自行调用 [`StyleHydration::hydrate_deferred`]。
 ///
 /// # Examples
 ///
@@ -17,6 +20,7 @@ use std::collections::{HashMap, HashSet};
 ///     deduplication: true,
 ///     remove_server_styles: false,
 ///     lazy_load: true,
+///     eager_ids: vec!["app-styles".to_string()],
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -27,6 +31,8 @@ pub struct HydrationConfig {
     pub remove_server_styles: bool,
     /// 是否启用懒加载
     pub lazy_load: bool,
+    /// 部分水合模式下立即水合的样式ID
+    pub eager_ids: Vec<String>,
 }
 
 impl Default for HydrationConfig {
@@ -35,6 +41,7 @@ impl Default for HydrationConfig {
             deduplication: true,
             remove_server_styles: true,
             lazy_load: false,
+            eager_ids: Vec::new(),
         }
     }
 }
@@ -65,6 +72,19 @@ pub struct StyleHydration {
     hydrated_styles: HashSet<String>,
     /// 样式哈希映射
     style_hashes: HashMap<String, String>,
+    /// 延迟水合的样式，按发现顺序保存 (样式ID, 哈希)
+    deferred_styles: Vec<(String, String)>,
+}
+
+/// 水合结果
+///
+/// 记录一次水合中立即水合和延迟水合的样式ID。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HydrationResult {
+    /// 立即水合的样式ID
+    pub eager: Vec<String>,
+    /// 延迟水合的样式ID
+    pub deferred: Vec<String>,
 }
 
 impl StyleHydration {
@@ -87,6 +107,7 @@ impl StyleHydration {
     ///     deduplication: true,
     ///     remove_server_styles: false,
     ///     lazy_load: true,
+    ///     eager_ids: Vec::new(),
     /// };
     ///
     /// let hydration = StyleHydration::new(config);
@@ -96,6 +117,7 @@ impl StyleHydration {
             config,
             hydrated_styles: HashSet::new(),
             style_hashes: HashMap::new(),
+            deferred_styles: Vec::new(),
         }
     }
 
@@ -119,6 +141,8 @@ impl StyleHydration {
     /// 水合样式
     ///
     /// 在客户端执行样式水合过程，包括收集服务端样式、去重和管理样式生命周期。
+    /// 启用懒加载时，`eager_ids` 以外的样式只被记录为延迟水合，需另行调用
+    /// [`StyleHydration::schedule_deferred`] 或 [`StyleHydration::hydrate_deferred`] 完成。
     /// 该方法只在WebAssembly目标上可用。
     ///
     /// # Returns
@@ -181,6 +205,7 @@ impl StyleHydration {
         use wasm_bindgen::JsCast;
 
         let style_elements = document.get_elements_by_tag_name("style");
+        let mut server_styles = Vec::new();
 
         for i in 0..style_elements.length() {
             if let Some(element) = style_elements.item(i) {
@@ -188,14 +213,15 @@ impl StyleHydration {
                     let id = style_element.id();
                     if !id.is_empty() {
                         if let Some(hash) = style_element.get_attribute("data-hash") {
-                            self.style_hashes.insert(id.clone(), hash);
-                            self.hydrated_styles.insert(id);
+                            server_styles.push((id, hash));
                         }
                     }
                 }
             }
         }
 
+        self.hydrate_styles(&server_styles);
+
         Ok(())
     }
 
@@ -345,6 +371,136 @@ impl StyleHydration {
             .insert(style_id.to_string(), hash.to_string());
     }
 
+    /// 水合服务端样式
+    ///
+    /// 未启用懒加载时立即水合全部样式；启用懒加载（部分水合模式）时只立即水合 `eager_ids` 中的样式，
+    /// 其余样式记录为延迟水合，由调用方在空闲或交互时调用 `hydrate_deferred`（或通过 `schedule_deferred`）完成。
+    ///
+    /// # Arguments
+    ///
+    /// * `styles` - 服务端样式列表，元素为 (样式ID, 哈希)
+    ///
+    /// # Returns
+    ///
+    /// 本次立即水合和延迟水合的样式ID
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::ssr::{HydrationConfig, StyleHydration};
+    ///
+    /// let mut hydration = StyleHydration::new(HydrationConfig {
+    ///     lazy_load: true,
+    ///     eager_ids: vec!["header".to_string()],
+    ///     ..HydrationConfig::default()
+    /// });
+    ///
+    /// let result = hydration.hydrate_styles(&[
+    ///     ("header".to_string(), "h1".to_string()),
+    ///     ("footer".to_string(), "h2".to_string()),
+    /// ]);
+    /// assert_eq!(result.eager, ["header"]);
+    /// assert_eq!(result.deferred, ["footer"]);
+    /// assert!(!hydration.is_hydrated("footer"));
+    /// ```
+    pub fn hydrate_styles(&mut self, styles: &[(String, String)]) -> HydrationResult {
+        let mut result = HydrationResult::default();
+
+        for (style_id, hash) in styles {
+            if self.config.lazy_load && !self.config.eager_ids.contains(style_id) {
+                if !self.deferred_styles.iter().any(|(id, _)| id == style_id) {
+                    self.deferred_styles.push((style_id.clone(), hash.clone()));
+                }
+                result.deferred.push(style_id.clone());
+            } else {
+                self.mark_as_hydrated(style_id, hash);
+                result.eager.push(style_id.clone());
+            }
+        }
+
+        result
+    }
+
+    /// 水合所有延迟的样式
+    ///
+    /// # Returns
+    ///
+    /// 本次水合的样式ID，按发现顺序排列
+    pub fn hydrate_deferred(&mut self) -> Vec<String> {
+        let deferred = std::mem::take(&mut self.deferred_styles);
+        deferred
+            .into_iter()
+            .map(|(style_id, hash)| {
+                self.mark_as_hydrated(&style_id, &hash);
+                style_id
+            })
+            .collect()
+    }
+
+    /// 在浏览器空闲时水合延迟的样式
+    ///
+    /// 通过 `requestIdleCallback` 调度 [`StyleHydration::hydrate_deferred`]，不支持该 API 的浏览器
+    /// 退化为 `setTimeout`。回调需要可变访问水合器，因此以 `Rc<RefCell<_>>` 共享。
+    /// 没有延迟样式时不做任何调度。该方法只在WebAssembly目标上可用。
+    ///
+    /// # Returns
+    ///
+    /// 成功时返回`Ok(())`，无法调度时返回包含错误信息的`Err`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use css_in_rust::theme::core::ssr::{HydrationConfig, StyleHydration};
+    ///
+    /// let hydration = Rc::new(RefCell::new(StyleHydration::new(HydrationConfig {
+    ///     lazy_load: true,
+    ///     ..HydrationConfig::default()
+    /// })));
+    ///
+    /// #[cfg(target_arch = "wasm32")]
+    /// {
+    ///     hydration.borrow_mut().hydrate().expect("样式水合失败");
+    ///     StyleHydration::schedule_deferred(hydration.clone()).expect("无法调度延迟水合");
+    /// }
+    /// ```
+    #[cfg(target_arch = "wasm32")]
+    pub fn schedule_deferred(
+        hydration: std::rc::Rc<std::cell::RefCell<Self>>,
+    ) -> Result<(), String> {
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        if hydration.borrow().deferred_styles.is_empty() {
+            return Ok(());
+        }
+
+        let window = web_sys::window().ok_or_else(|| "无法获取window对象".to_string())?;
+        let callback = Closure::once_into_js(move || {
+            hydration.borrow_mut().hydrate_deferred();
+        });
+        let callback = callback.unchecked_ref::<js_sys::Function>();
+
+        let supports_idle =
+            js_sys::Reflect::has(&window, &"requestIdleCallback".into()).unwrap_or(false);
+        let scheduled = if supports_idle {
+            window.request_idle_callback(callback).map(|_| ())
+        } else {
+            window.set_timeout_with_callback(callback).map(|_| ())
+        };
+
+        scheduled.map_err(|e| format!("无法调度延迟水合: {:?}", e))
+    }
+
+    /// 获取等待水合的样式ID
+    pub fn deferred_style_ids(&self) -> Vec<&str> {
+        self.deferred_styles
+            .iter()
+            .map(|(style_id, _)| style_id.as_str())
+            .collect()
+    }
+
     /// 清空水合状态
     ///
     /// 清除所有已水合的样式记录和哈希映射。
@@ -363,5 +519,54 @@ impl StyleHydration {
     pub fn clear(&mut self) {
         self.hydrated_styles.clear();
         self.style_hashes.clear();
+        self.deferred_styles.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_styles(ids: &[&str]) -> Vec<(String, String)> {
+        ids.iter()
+            .map(|id| (id.to_string(), format!("hash-{}", id)))
+            .collect()
+    }
+
+    #[test]
+    fn test_partial_mode_hydrates_eager_ids_and_defers_rest() {
+        let mut hydration = StyleHydration::new(HydrationConfig {
+            lazy_load: true,
+            eager_ids: vec!["header".to_string(), "layout".to_string()],
+            ..HydrationConfig::default()
+        });
+
+        let result =
+            hydration.hydrate_styles(&server_styles(&["header", "table", "layout", "modal"]));
+        assert_eq!(result.eager, ["header", "layout"]);
+        assert_eq!(result.deferred, ["table", "modal"]);
+        assert!(hydration.is_hydrated("header"));
+        assert!(!hydration.is_hydrated("table"));
+        assert_eq!(hydration.deferred_style_ids(), ["table", "modal"]);
+
+        assert_eq!(hydration.hydrate_deferred(), ["table", "modal"]);
+        assert!(hydration.is_hydrated("modal"));
+        assert_eq!(
+            hydration.get_style_hash("table").map(String::as_str),
+            Some("hash-table")
+        );
+        assert!(hydration.deferred_style_ids().is_empty());
+    }
+
+    #[test]
+    fn test_full_mode_hydrates_everything_eagerly() {
+        let mut hydration = StyleHydration::new(HydrationConfig {
+            eager_ids: vec!["header".to_string()],
+            ..HydrationConfig::default()
+        });
+
+        let result = hydration.hydrate_styles(&server_styles(&["header", "table"]));
+        assert_eq!(result.eager, ["header", "table"]);
+        assert!(result.deferred.is_empty());
     }
 }
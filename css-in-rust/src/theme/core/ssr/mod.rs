@@ -30,7 +30,7 @@ mod extractor;
 mod hydration;
 
 pub use extractor::StyleExtractor;
-pub use hydration::StyleHydration;
+pub use hydration::{HydrationConfig, HydrationResult, StyleHydration};
 
 /// 服务端渲染的样式表
 ///
@@ -168,6 +168,30 @@ impl ServerStyleSheet {
             }
         )
     }
+
+    /// 生成预加载链接标签
+    ///
+    /// 用于延迟加载的样式表，让浏览器提前下载CSS而不阻塞渲染。
+    ///
+    /// # Arguments
+    ///
+    /// * `href` - 样式表URL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::ssr::ServerStyleSheet;
+    ///
+    /// let sheet = ServerStyleSheet::new("table-styles", ".table { width: 100%; }", false);
+    /// let html = sheet.to_preload_tag("/styles/table.css");
+    /// assert!(html.contains(r#"rel="preload" as="style""#));
+    /// ```
+    pub fn to_preload_tag(&self, href: &str) -> String {
+        format!(
+            r#"<link rel="preload" as="style" id="{}-preload" href="{}" data-hash="{}">"#,
+            self.id, href, self.hash
+        )
+    }
 }
 
 /// 样式表管理器
@@ -353,6 +377,35 @@ impl StyleSheetManager {
         tags
     }
 
+    /// 生成延迟样式的预加载链接
+    ///
+    /// 为非关键样式表生成 `<link rel="preload" as="style">` 标签，与只内联关键样式的
+    /// `to_critical_style_tags` 配合使用。
+    ///
+    /// # Arguments
+    ///
+    /// * `href_for` - 根据样式表返回其外部CSS地址
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::ssr::{ServerStyleSheet, StyleSheetManager};
+    ///
+    /// let mut manager = StyleSheetManager::new();
+    /// manager.add_sheet(ServerStyleSheet::new("app-styles", "body { color: #333; }", true));
+    /// manager.add_sheet(ServerStyleSheet::new("table-styles", ".table { width: 100%; }", false));
+    ///
+    /// let links = manager.to_preload_links(|sheet| format!("/styles/{}.css", sheet.id));
+    /// assert!(links.contains("/styles/table-styles.css"));
+    /// assert!(!links.contains("app-styles"));
+    /// ```
+    pub fn to_preload_links(&self, href_for: impl Fn(&ServerStyleSheet) -> String) -> String {
+        self.normal_sheets
+            .iter()
+            .map(|sheet| sheet.to_preload_tag(&href_for(sheet)))
+            .collect()
+    }
+
     /// 合并样式表
     ///
     /// 将另一个样式表管理器的样式表合并到当前管理器。
@@ -400,3 +453,55 @@ impl StyleSheetManager {
         self.normal_sheets.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_hash_is_stable_across_instances() {
+        let css = "body { color: #333; }";
+        let server = ServerStyleSheet::new("app-styles", css, true);
+        let client = ServerStyleSheet::new("app-styles-copy", css, false);
+
+        assert_eq!(server.hash, client.hash);
+        // 固定值确保跨构建、跨平台一致，客户端水合可按 data-hash 匹配
+        assert_eq!(
+            server.hash,
+            "76456d98f0751ade3235db7535beef70b85cfb4a5e179d12006e4ef97eb6aeee"
+        );
+        assert!(server
+            .to_style_tag()
+            .contains(&format!("data-hash=\"{}\"", server.hash)));
+        assert_ne!(
+            server.hash,
+            ServerStyleSheet::new("app-styles", "body { color: #000; }", true).hash
+        );
+    }
+
+    #[test]
+    fn test_preload_links_for_deferred_sheets() {
+        let mut manager = StyleSheetManager::new();
+        manager.add_sheet(ServerStyleSheet::new("app", "body { margin: 0; }", true));
+        manager.add_sheet(ServerStyleSheet::new(
+            "table",
+            ".table { width: 100%; }",
+            false,
+        ));
+        manager.add_sheet(ServerStyleSheet::new(
+            "modal",
+            ".modal { z-index: 10; }",
+            false,
+        ));
+
+        let links = manager.to_preload_links(|sheet| format!("/css/{}.css", sheet.id));
+
+        assert_eq!(links.matches(r#"rel="preload" as="style""#).count(), 2);
+        assert!(links.contains(r#"href="/css/table.css""#));
+        assert!(links.contains(r#"href="/css/modal.css""#));
+        assert!(!links.contains("/css/app.css"));
+        assert!(StyleSheetManager::new()
+            .to_preload_links(|sheet| sheet.id.clone())
+            .is_empty());
+    }
+}
@@ -278,6 +278,9 @@ pub enum DimensionUnit {
     Auto,
 }
 
+/// 相对单位换算时使用的默认根字号（px）
+pub const DEFAULT_ROOT_FONT_SIZE: f64 = 16.0;
+
 impl std::fmt::Display for DimensionUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -867,6 +870,30 @@ impl Default for ThemeVariant {
     }
 }
 
+impl ThemeVariant {
+    /// 解析为具体的主题变体
+    ///
+    /// `Auto` 根据传入的系统偏好解析为 `Dark` 或 `Light`，其他变体原样返回。
+    /// 系统偏好由调用方检测（如 `prefers-color-scheme` 媒体查询），解析本身与平台无关。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::ThemeVariant;
+    ///
+    /// assert_eq!(ThemeVariant::Auto.resolve(true), ThemeVariant::Dark);
+    /// assert_eq!(ThemeVariant::Auto.resolve(false), ThemeVariant::Light);
+    /// assert_eq!(ThemeVariant::Light.resolve(true), ThemeVariant::Light);
+    /// ```
+    pub fn resolve(self, system_is_dark: bool) -> ThemeVariant {
+        match self {
+            ThemeVariant::Auto if system_is_dark => ThemeVariant::Dark,
+            ThemeVariant::Auto => ThemeVariant::Light,
+            variant => variant,
+        }
+    }
+}
+
 impl fmt::Display for ThemeVariant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -971,6 +998,50 @@ impl ColorValue {
         result
     }
 
+    /// 生成指定不透明度的 rgba 颜色
+    ///
+    /// 支持 `#rgb`、`#rrggbb`、`rgb()`/`rgba()` 和 `oklch()` 输入，不透明度限制在 `[0, 1]`。
+    /// 无法解析的颜色只更新透明度。`with_alpha` 是按十六进制创建颜色的构造函数，
+    /// 已有颜色派生透明变体时使用本方法。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::ColorValue;
+    ///
+    /// let primary_bg = ColorValue::new("#1677ff".to_string()).with_opacity(0.1);
+    /// assert_eq!(primary_bg.to_css_string(), "rgba(22, 119, 255, 0.1)");
+    /// ```
+    pub fn with_opacity(&self, alpha: f64) -> ColorValue {
+        let mut result = self.clone();
+        if let Some((r, g, b)) = self.to_rgb() {
+            result.rgb = Some((r, g, b));
+            result.hex = format!("#{:02x}{:02x}{:02x}", r, g, b);
+        }
+        result.alpha = Some(alpha.clamp(0.0, 1.0) as f32);
+        result
+    }
+
+    /// 解析颜色的 RGB 分量
+    ///
+    /// 优先使用 `rgb` 字段，否则解析 `hex` 字段中的 `#rgb`、`#rrggbb`、`rgb()`/`rgba()` 或 `oklch()`。
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        if let Some(rgb) = self.rgb {
+            return Some(rgb);
+        }
+
+        let value = self.hex.trim().to_ascii_lowercase();
+        if let Some(hex) = value.strip_prefix('#') {
+            parse_hex_rgb(hex)
+        } else if let Some(args) = function_args(&value, &["rgb", "rgba"]) {
+            parse_rgb_args(args)
+        } else if let Some(args) = function_args(&value, &["oklch"]) {
+            parse_oklch_args(args)
+        } else {
+            None
+        }
+    }
+
     /// 将颜色转换为HSL格式
     fn to_hsl(&self) -> (f32, f32, f32) {
         // 如果已经有HSL值，直接返回
@@ -1099,6 +1170,99 @@ impl ColorValue {
     }
 }
 
+/// 解析 `rgb` 或 `rrggbb` 十六进制颜色
+fn parse_hex_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        3 | 4 => {
+            let expand = |i: usize| channel(&hex[i..i + 1].repeat(2));
+            Some((expand(0)?, expand(1)?, expand(2)?))
+        }
+        6 | 8 => Some((
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        )),
+        _ => None,
+    }
+}
+
+/// 提取 `name(...)` 形式的函数参数
+fn function_args<'a>(value: &'a str, names: &[&str]) -> Option<&'a str> {
+    let (name, rest) = value.split_once('(')?;
+    if !names.contains(&name.trim()) {
+        return None;
+    }
+    rest.strip_suffix(')')
+}
+
+/// 拆分颜色函数参数，支持逗号和空格分隔，忽略 `/` 之后的透明度
+fn split_color_args(args: &str) -> Vec<&str> {
+    let channels = args.split('/').next().unwrap_or("");
+    channels
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// 解析 `rgb()`/`rgba()` 参数，支持数字和百分比
+fn parse_rgb_args(args: &str) -> Option<(u8, u8, u8)> {
+    let parts = split_color_args(args);
+    let channel = |part: &str| -> Option<u8> {
+        let value = match part.strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().ok()? * 2.55,
+            None => part.parse::<f64>().ok()?,
+        };
+        Some(value.round().clamp(0.0, 255.0) as u8)
+    };
+    Some((
+        channel(parts.first()?)?,
+        channel(parts.get(1)?)?,
+        channel(parts.get(2)?)?,
+    ))
+}
+
+/// 解析 `oklch()` 参数并转换为 sRGB
+fn parse_oklch_args(args: &str) -> Option<(u8, u8, u8)> {
+    let parts = split_color_args(args);
+    let lightness = match parts.first()?.strip_suffix('%') {
+        Some(percent) => percent.parse::<f64>().ok()? / 100.0,
+        None => parts.first()?.parse::<f64>().ok()?,
+    };
+    let chroma = parts.get(1)?.parse::<f64>().ok()?;
+    let hue = parts.get(2)?.trim_end_matches("deg").parse::<f64>().ok()?;
+
+    // OKLCH -> OKLab -> 线性 sRGB
+    let (a, b) = (
+        chroma * hue.to_radians().cos(),
+        chroma * hue.to_radians().sin(),
+    );
+    let l = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s = (lightness - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+    let linear = [
+        4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+        -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+        -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+    ];
+    let encode = |c: f64| -> u8 {
+        let c = c.clamp(0.0, 1.0);
+        let srgb = if c <= 0.003_130_8 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        };
+        (srgb * 255.0).round() as u8
+    };
+
+    Some((encode(linear[0]), encode(linear[1]), encode(linear[2])))
+}
+
 impl DimensionValue {
     /// 创建新的尺寸值
     pub fn create(value: f64, unit: DimensionUnit) -> Self {
@@ -1129,6 +1293,65 @@ impl DimensionValue {
     pub fn scale(&self, factor: f32) -> Self {
         Self::create(self.value * factor as f64, self.unit.clone())
     }
+
+    /// 相加两个尺寸值
+    ///
+    /// 单位相同时直接相加；`px`、`rem`、`em` 之间按 16px 的根字号换算后相加，结果使用 `self` 的单位。
+    /// 其他单位组合（如 `px + %`）无法换算，返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::DimensionValue;
+    ///
+    /// let sum = DimensionValue::px(4.0).add(&DimensionValue::px(8.0)).unwrap();
+    /// assert_eq!(sum, DimensionValue::px(12.0));
+    ///
+    /// let mixed = DimensionValue::rem(1.0).add(&DimensionValue::px(8.0)).unwrap();
+    /// assert_eq!(mixed, DimensionValue::rem(1.5));
+    ///
+    /// assert!(DimensionValue::px(10.0).add(&DimensionValue::percent(5.0)).is_err());
+    /// ```
+    pub fn add(&self, other: &DimensionValue) -> Result<DimensionValue, String> {
+        if self.unit == other.unit && self.unit != DimensionUnit::Auto {
+            return Ok(Self::create(self.value + other.value, self.unit.clone()));
+        }
+
+        let incompatible = || format!("Cannot add {} to {}", other.unit, self.unit);
+        let self_px = self
+            .to_px(DEFAULT_ROOT_FONT_SIZE)
+            .map_err(|_| incompatible())?;
+        let other_px = other
+            .to_px(DEFAULT_ROOT_FONT_SIZE)
+            .map_err(|_| incompatible())?;
+        let total = self_px.value + other_px.value;
+
+        let value = match self.unit {
+            DimensionUnit::Px => total,
+            _ => total / DEFAULT_ROOT_FONT_SIZE,
+        };
+        Ok(Self::create(value, self.unit.clone()))
+    }
+
+    /// 转换为像素值
+    ///
+    /// `rem` 和 `em` 按给定的根字号换算；百分比、视口单位和 `auto` 依赖上下文，无法换算，返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::DimensionValue;
+    ///
+    /// assert_eq!(DimensionValue::rem(2.0).to_px(10.0).unwrap(), DimensionValue::px(20.0));
+    /// assert!(DimensionValue::percent(50.0).to_px(16.0).is_err());
+    /// ```
+    pub fn to_px(&self, root_size: f64) -> Result<DimensionValue, String> {
+        match self.unit {
+            DimensionUnit::Px => Ok(self.clone()),
+            DimensionUnit::Rem | DimensionUnit::Em => Ok(Self::px(self.value * root_size)),
+            _ => Err(format!("Cannot convert {} to px", self.unit)),
+        }
+    }
 }
 
 impl TypographyValue {
@@ -1258,6 +1481,41 @@ impl TokenReference {
         }
     }
 
+    /// 从 `{color.primary.500}` 形式的别名创建令牌引用
+    ///
+    /// 去除花括号和空白，并规范化路径中多余的 `.`。不带花括号的路径按原样解析。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::TokenReference;
+    ///
+    /// let reference = TokenReference::from_alias("{ color..primary.500 }");
+    /// assert_eq!(reference.get_reference(), "color.primary.500");
+    /// ```
+    pub fn from_alias(alias: &str) -> Self {
+        let trimmed = alias.trim();
+        let inner = trimmed
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .unwrap_or(trimmed);
+
+        let reference = inner
+            .split('.')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        Self::create(reference)
+    }
+
+    /// 判断字符串是否为 `{dotted.path}` 形式的别名
+    pub fn is_alias(value: &str) -> bool {
+        let trimmed = value.trim();
+        trimmed.len() > 2 && trimmed.starts_with('{') && trimmed.ends_with('}')
+    }
+
     /// 获取引用路径
     pub fn get_reference(&self) -> &str {
         &self.reference
@@ -1281,8 +1539,8 @@ pub struct TokenMetadata {
     pub description: Option<String>,
     /// 令牌类型（如 color, size, font）
     pub token_type: String,
-    /// 是否已弃用
-    pub deprecated: bool,
+    /// 弃用说明，`Some` 表示令牌已弃用（如 "请改用 font_size.base"）
+    pub deprecated: Option<String>,
     /// 别名（其他可用的名称）
     pub aliases: Vec<String>,
     /// 标签（用于分类和搜索）
@@ -1294,7 +1552,7 @@ impl Default for TokenMetadata {
         Self {
             description: None,
             token_type: "unknown".to_string(),
-            deprecated: false,
+            deprecated: None,
             aliases: Vec::new(),
             tags: Vec::new(),
         }
@@ -1425,3 +1683,86 @@ impl From<&str> for TokenValidationError {
         TokenValidationError::InvalidValue(error.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_opacity_from_hex_rgb_and_oklch() {
+        let primary = ColorValue::new("#1677ff".to_string());
+        assert_eq!(
+            primary.with_opacity(0.1).to_css_string(),
+            "rgba(22, 119, 255, 0.1)"
+        );
+
+        let short = ColorValue::new("#fff".to_string()).with_opacity(0.5);
+        assert_eq!(short.to_css_string(), "rgba(255, 255, 255, 0.5)");
+
+        let rgb = ColorValue::new("rgb(22, 119, 255)".to_string()).with_opacity(0.25);
+        assert_eq!(rgb.to_css_string(), "rgba(22, 119, 255, 0.25)");
+        assert_eq!(rgb.hex, "#1677ff");
+
+        let oklch = ColorValue::new("oklch(1 0 0)".to_string()).with_opacity(0.2);
+        assert_eq!(oklch.to_css_string(), "rgba(255, 255, 255, 0.2)");
+        let oklch_black = ColorValue::new("oklch(0% 0 0deg)".to_string()).with_opacity(0.2);
+        assert_eq!(oklch_black.rgb, Some((0, 0, 0)));
+
+        // 透明度限制在 [0, 1]
+        assert_eq!(primary.with_opacity(1.5).alpha, Some(1.0));
+        assert_eq!(primary.with_opacity(-1.0).alpha, Some(0.0));
+        assert_eq!(primary.with_opacity(1.5).to_css_string(), "#1677ff");
+
+        assert_eq!(ColorValue::new("currentColor".to_string()).to_rgb(), None);
+    }
+
+    #[test]
+    fn test_invalid_hex_colors_do_not_panic() {
+        assert_eq!(ColorValue::new("#aébcd".to_string()).to_rgb(), None);
+        assert_eq!(ColorValue::new("#é".to_string()).to_rgb(), None);
+        assert_eq!(ColorValue::new("#+1+2+3".to_string()).to_rgb(), None);
+        assert_eq!(ColorValue::new("#gggggg".to_string()).to_rgb(), None);
+        assert_eq!(
+            ColorValue::new("#aabbcc".to_string()).to_rgb(),
+            Some((0xaa, 0xbb, 0xcc))
+        );
+    }
+
+    #[test]
+    fn test_auto_variant_follows_system_preference() {
+        assert_eq!(ThemeVariant::Auto.resolve(true), ThemeVariant::Dark);
+        assert_eq!(ThemeVariant::Auto.resolve(false), ThemeVariant::Light);
+        assert_eq!(ThemeVariant::Light.resolve(true), ThemeVariant::Light);
+        assert_eq!(ThemeVariant::Dark.resolve(false), ThemeVariant::Dark);
+    }
+
+    #[test]
+    fn test_dimension_arithmetic() {
+        let sum = DimensionValue::px(4.0)
+            .add(&DimensionValue::px(8.0))
+            .unwrap();
+        assert_eq!(sum, DimensionValue::px(12.0));
+        assert_eq!(sum.to_string(), "12px");
+
+        assert_eq!(
+            DimensionValue::rem(1.0).scale(2.0),
+            DimensionValue::rem(2.0)
+        );
+
+        let err = DimensionValue::px(10.0)
+            .add(&DimensionValue::percent(5.0))
+            .unwrap_err();
+        assert!(err.contains('%'));
+
+        assert_eq!(
+            DimensionValue::px(8.0)
+                .add(&DimensionValue::rem(0.5))
+                .unwrap(),
+            DimensionValue::px(16.0)
+        );
+        assert_eq!(
+            DimensionValue::em(1.5).to_px(20.0).unwrap(),
+            DimensionValue::px(30.0)
+        );
+    }
+}
@@ -4,7 +4,7 @@
 //! 职责：令牌解析逻辑、引用处理、值计算
 
 use super::{
-    definitions::{ThemeVariant, TokenPath, TokenValidationError, TokenValue},
+    definitions::{ThemeVariant, TokenPath, TokenReference, TokenValidationError, TokenValue},
     values::DesignTokens,
 };
 use std::collections::{HashMap, HashSet};
@@ -161,6 +161,106 @@ impl TokenResolver {
         }
     }
 
+    /// 解析 `{dotted.path}` 形式的令牌别名
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - 令牌别名，如 `{color.primary.500}`
+    /// * `theme` - 主题变体
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::resolver::TokenResolver;
+    /// use css_in_rust::theme::core::token::values::DesignTokens;
+    /// use css_in_rust::theme::core::token::definitions::{ThemeVariant, TokenValue};
+    ///
+    /// let mut store = DesignTokens::new();
+    /// store.set_value("size.base".to_string(), ThemeVariant::Light, TokenValue::Number(16.0));
+    ///
+    /// let resolver = TokenResolver::new(store);
+    /// let value = resolver.resolve_alias("{size.base}", ThemeVariant::Light).unwrap();
+    /// assert_eq!(value, TokenValue::Number(16.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// 别名指向的令牌不存在时返回 `MissingReference`
+    pub fn resolve_alias(
+        &self,
+        alias: &str,
+        theme: ThemeVariant,
+    ) -> Result<TokenValue, TokenValidationError> {
+        let reference = TokenReference::from_alias(alias);
+        let path = reference.get_reference();
+
+        if path.is_empty() || self.store.get_value(path, theme).is_none() {
+            return Err(TokenValidationError::MissingReference(alias.to_string()));
+        }
+
+        self.resolve_token(path, theme)
+    }
+
+    /// 解析令牌并收集弃用警告
+    ///
+    /// 沿引用链解析令牌，链上每个在元数据中标记为弃用的令牌都会产生一条警告，
+    /// 警告包含元数据中的弃用说明。令牌不存在或引用链成环时返回 `None`，已收集的警告仍会返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::resolver::TokenResolver;
+    /// use css_in_rust::theme::core::token::values::DesignTokens;
+    /// use css_in_rust::theme::core::token::definitions::{ThemeVariant, TokenMetadata, TokenValue};
+    ///
+    /// let mut store = DesignTokens::new();
+    /// store.set_value("size.base".to_string(), ThemeVariant::Light, TokenValue::Number(16.0));
+    /// store.set_metadata(
+    ///     "size.base".to_string(),
+    ///     TokenMetadata {
+    ///         deprecated: Some("use size.md instead".to_string()),
+    ///         ..TokenMetadata::default()
+    ///     },
+    /// );
+    ///
+    /// let resolver = TokenResolver::new(store);
+    /// let (value, warnings) = resolver.resolve_with_warnings("size.base", ThemeVariant::Light);
+    /// assert_eq!(value, Some(TokenValue::Number(16.0)));
+    /// assert_eq!(warnings, vec!["token `size.base` is deprecated: use size.md instead"]);
+    /// ```
+    pub fn resolve_with_warnings(
+        &self,
+        path: &str,
+        theme: ThemeVariant,
+    ) -> (Option<TokenValue>, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = path.to_string();
+
+        loop {
+            if !visited.insert(current.clone()) {
+                return (None, warnings);
+            }
+
+            if let Some(note) = self
+                .store
+                .get_metadata(&current)
+                .and_then(|metadata| metadata.deprecated.as_ref())
+            {
+                warnings.push(format!("token `{}` is deprecated: {}", current, note));
+            }
+
+            match self.store.get_value(&current, theme) {
+                Some(TokenValue::Reference(ref_path)) => current = ref_path.clone(),
+                Some(TokenValue::TokenReference(token_ref)) => {
+                    current = token_ref.get_reference().to_string()
+                }
+                Some(value) => return (Some(value.clone()), warnings),
+                None => return (None, warnings),
+            }
+        }
+    }
+
     /// 获取令牌元数据
     ///
     /// 根据路径获取令牌的元数据信息。
@@ -615,7 +715,9 @@ impl TokenResolver {
 
 #[cfg(test)]
 mod tests {
-    use super::super::definitions::{ColorValue, DimensionUnit, DimensionValue, TokenPath};
+    use super::super::definitions::{
+        ColorValue, DimensionUnit, DimensionValue, TokenMetadata, TokenPath,
+    };
     use super::*;
 
     fn create_test_resolver() -> TokenResolver {
@@ -705,6 +807,45 @@ mod tests {
         assert_eq!(value.unwrap(), TokenValue::Number(16.0));
     }
 
+    #[test]
+    fn test_token_reference_from_alias() {
+        let reference = TokenReference::from_alias("{color.primary.500}");
+        assert_eq!(reference.get_reference(), "color.primary.500");
+        assert_eq!(reference.to_css_var(), "var(--color-primary-500)");
+
+        let reference = TokenReference::from_alias(" { .color..primary. } ");
+        assert_eq!(reference.get_reference(), "color.primary");
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let resolver = create_test_resolver();
+
+        let value = resolver.resolve_alias("{color.primary}", ThemeVariant::Light);
+        assert_eq!(
+            value.unwrap(),
+            TokenValue::Color(ColorValue::new("#1890ff".to_string()))
+        );
+
+        // 别名指向引用令牌时继续解析到具体值
+        let value = resolver.resolve_alias("{size.medium}", ThemeVariant::Light);
+        assert_eq!(value.unwrap(), TokenValue::Number(16.0));
+    }
+
+    #[test]
+    fn test_resolve_missing_alias() {
+        let resolver = create_test_resolver();
+
+        let result = resolver.resolve_alias("{color.missing}", ThemeVariant::Light);
+        assert_eq!(
+            result,
+            Err(TokenValidationError::MissingReference(
+                "{color.missing}".to_string()
+            ))
+        );
+        assert!(resolver.resolve_alias("{}", ThemeVariant::Light).is_err());
+    }
+
     #[test]
     fn test_compute_expression_addition() {
         let resolver = create_test_resolver();
@@ -795,6 +936,31 @@ mod tests {
         assert!(has_circular_error);
     }
 
+    #[test]
+    fn test_resolve_with_warnings_follows_reference_chain() {
+        let mut resolver = create_test_resolver();
+        resolver.store.set_metadata(
+            "size.large".to_string(),
+            TokenMetadata {
+                deprecated: Some("use size.base instead".to_string()),
+                ..TokenMetadata::default()
+            },
+        );
+
+        let (value, warnings) = resolver.resolve_with_warnings("size.xl", ThemeVariant::Light);
+        assert_eq!(value, Some(TokenValue::Number(24.0)));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("size.large"));
+        assert!(warnings[0].contains("use size.base instead"));
+
+        let (value, warnings) = resolver.resolve_with_warnings("size.medium", ThemeVariant::Light);
+        assert_eq!(value, Some(TokenValue::Number(16.0)));
+        assert!(warnings.is_empty());
+
+        let (value, _) = resolver.resolve_with_warnings("test.circular1", ThemeVariant::Light);
+        assert_eq!(value, None);
+    }
+
     #[test]
     fn test_get_token_metadata() {
         let resolver = create_test_resolver();
@@ -11,7 +11,10 @@
 //! - 各种具体的令牌值类型：颜色、间距、排版等
 
 // 移除对design_tokens的依赖，将相关类型定义在本文件中
-use super::definitions::{DimensionUnit, DimensionValue, ThemeVariant, TokenMetadata, TokenValue};
+use super::definitions::{
+    ColorValue, DimensionUnit, DimensionValue, ThemeVariant, TokenMetadata, TokenReference,
+    TokenValue,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -607,11 +610,12 @@ impl ColorTokens {
 #[derive(Debug, Default, Clone)]
 pub struct DesignTokens {
     values: HashMap<String, HashMap<ThemeVariant, TokenValue>>,
+    metadata: HashMap<String, TokenMetadata>,
 }
 
 impl PartialEq for DesignTokens {
     fn eq(&self, other: &Self) -> bool {
-        self.values == other.values
+        self.values == other.values && self.metadata == other.metadata
     }
 }
 
@@ -620,6 +624,7 @@ impl DesignTokens {
     pub fn new() -> Self {
         Self {
             values: HashMap::new(),
+            metadata: HashMap::new(),
         }
     }
 
@@ -643,6 +648,16 @@ impl DesignTokens {
             .insert(theme, value);
     }
 
+    /// 获取令牌元数据（与主题变体无关）
+    pub fn get_metadata(&self, path: &str) -> Option<&TokenMetadata> {
+        self.metadata.get(path)
+    }
+
+    /// 设置令牌元数据
+    pub fn set_metadata(&mut self, path: String, metadata: TokenMetadata) {
+        self.metadata.insert(path, metadata);
+    }
+
     /// 获取所有令牌路径
     pub fn get_all_paths(&self) -> Vec<String> {
         self.values.keys().cloned().collect()
@@ -676,6 +691,242 @@ impl DesignTokens {
             theme_values.remove(&theme);
         }
     }
+
+    /// 从 W3C Design Tokens 格式的 JSON 导入令牌
+    ///
+    /// 令牌按分组嵌套，叶子节点包含 `$value` 和可选的 `$type`（未声明时继承所在分组的 `$type`）。
+    /// `{color.primary}` 形式的别名导入为 `TokenValue::TokenReference`。
+    /// 导入的令牌存放在默认主题变体下。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::{ThemeVariant, TokenValue};
+    /// use css_in_rust::theme::core::token::values::DesignTokens;
+    ///
+    /// let json = r##"{
+    ///     "color": { "$type": "color", "primary": { "$value": "#1890ff" } },
+    ///     "spacing": { "md": { "$type": "dimension", "$value": "16px" } }
+    /// }"##;
+    ///
+    /// let tokens = DesignTokens::from_w3c_json(json).unwrap();
+    /// let primary = tokens.get_value("color.primary", ThemeVariant::Light);
+    /// assert!(matches!(primary, Some(TokenValue::Color(_))));
+    /// ```
+    pub fn from_w3c_json(json: &str) -> Result<DesignTokens, String> {
+        let root: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid design tokens JSON: {}", e))?;
+        let group = root
+            .as_object()
+            .ok_or_else(|| "Design tokens JSON root must be an object".to_string())?;
+
+        let mut tokens = DesignTokens::new();
+        tokens.import_w3c_group(group, "", None)?;
+        Ok(tokens)
+    }
+
+    /// 导出为 W3C Design Tokens 格式的 JSON
+    ///
+    /// 导出默认主题变体下的令牌，令牌路径按 `.` 拆分为嵌套分组
+    pub fn to_w3c_json(&self) -> String {
+        let theme = ThemeVariant::default();
+        let mut root = serde_json::Map::new();
+
+        let mut paths = self.get_all_paths();
+        paths.sort();
+        for path in paths {
+            if let Some(value) = self.get_value(&path, theme) {
+                let segments: Vec<&str> = path.split('.').collect();
+                insert_w3c_token(&mut root, &segments, w3c_token_json(value));
+            }
+        }
+
+        serde_json::to_string_pretty(&serde_json::Value::Object(root)).unwrap_or_default()
+    }
+
+    /// 递归导入 W3C 令牌分组
+    fn import_w3c_group(
+        &mut self,
+        group: &serde_json::Map<String, serde_json::Value>,
+        prefix: &str,
+        inherited_type: Option<&str>,
+    ) -> Result<(), String> {
+        let group_type = group
+            .get("$type")
+            .and_then(|t| t.as_str())
+            .or(inherited_type);
+
+        for (name, node) in group {
+            // `$type`、`$description` 等为分组属性
+            if name.starts_with('$') {
+                continue;
+            }
+
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            let node = node
+                .as_object()
+                .ok_or_else(|| format!("Token '{}' must be an object", path))?;
+
+            match node.get("$value") {
+                Some(value) => {
+                    let token_type = node.get("$type").and_then(|t| t.as_str()).or(group_type);
+                    let token_value = w3c_token_value(value, token_type)
+                        .map_err(|e| format!("Token '{}': {}", path, e))?;
+                    self.set_value(path, ThemeVariant::default(), token_value);
+                }
+                None => self.import_w3c_group(node, &path, group_type)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 将 W3C `$value` 转换为令牌值
+fn w3c_token_value(
+    value: &serde_json::Value,
+    token_type: Option<&str>,
+) -> Result<TokenValue, String> {
+    if let Some(alias) = value.as_str().filter(|s| TokenReference::is_alias(s)) {
+        return Ok(TokenValue::TokenReference(TokenReference::from_alias(
+            alias,
+        )));
+    }
+
+    match (token_type, value) {
+        (Some("color"), serde_json::Value::String(color)) => {
+            Ok(TokenValue::Color(ColorValue::new(color.clone())))
+        }
+        (Some("dimension"), serde_json::Value::String(dimension)) => {
+            Ok(parse_w3c_dimension(dimension)
+                .map(TokenValue::Dimension)
+                .unwrap_or_else(|| TokenValue::String(dimension.clone())))
+        }
+        (Some("dimension"), serde_json::Value::Object(obj)) => {
+            let amount = obj
+                .get("value")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| "dimension object requires a numeric 'value'".to_string())?;
+            let unit = obj.get("unit").and_then(|u| u.as_str()).unwrap_or("px");
+            parse_w3c_dimension(&format!("{}{}", amount, unit))
+                .map(TokenValue::Dimension)
+                .ok_or_else(|| format!("unsupported dimension unit '{}'", unit))
+        }
+        (_, serde_json::Value::String(s)) => Ok(TokenValue::String(s.clone())),
+        (_, serde_json::Value::Number(n)) => n
+            .as_f64()
+            .map(TokenValue::Number)
+            .ok_or_else(|| format!("invalid number '{}'", n)),
+        (_, serde_json::Value::Bool(b)) => Ok(TokenValue::Boolean(*b)),
+        (_, serde_json::Value::Array(items)) => items
+            .iter()
+            .map(|item| w3c_token_value(item, None))
+            .collect::<Result<Vec<_>, _>>()
+            .map(TokenValue::Array),
+        (_, serde_json::Value::Object(obj)) => obj
+            .iter()
+            .map(|(key, item)| w3c_token_value(item, None).map(|v| (key.clone(), v)))
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map(TokenValue::Object),
+        (_, serde_json::Value::Null) => Ok(TokenValue::Null),
+    }
+}
+
+/// 解析 `16px`、`1.5rem` 等尺寸字符串
+fn parse_w3c_dimension(value: &str) -> Option<DimensionValue> {
+    let value = value.trim();
+    if value == "auto" {
+        return Some(DimensionValue::new(0.0, DimensionUnit::Auto));
+    }
+
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: f64 = amount.parse().ok()?;
+    let unit = match unit {
+        "px" => DimensionUnit::Px,
+        "rem" => DimensionUnit::Rem,
+        "em" => DimensionUnit::Em,
+        "%" => DimensionUnit::Percent,
+        "vh" => DimensionUnit::Vh,
+        "vw" => DimensionUnit::Vw,
+        _ => return None,
+    };
+
+    Some(DimensionValue::new(amount, unit))
+}
+
+/// 将令牌值转换为 W3C 令牌节点
+fn w3c_token_json(value: &TokenValue) -> serde_json::Value {
+    let (token_type, token_value) = match value {
+        TokenValue::TokenReference(reference) => (
+            None,
+            serde_json::Value::String(format!("{{{}}}", reference.get_reference())),
+        ),
+        TokenValue::Color(color) => (
+            Some("color"),
+            serde_json::Value::String(color.to_css_string()),
+        ),
+        TokenValue::Dimension(dimension) => (
+            Some("dimension"),
+            serde_json::Value::String(dimension.to_string()),
+        ),
+        TokenValue::Number(n) => (Some("number"), serde_json::json!(n)),
+        other => (None, w3c_plain_json(other)),
+    };
+
+    let mut node = serde_json::Map::new();
+    if let Some(token_type) = token_type {
+        node.insert("$type".to_string(), serde_json::json!(token_type));
+    }
+    node.insert("$value".to_string(), token_value);
+    serde_json::Value::Object(node)
+}
+
+/// 将令牌值转换为不带类型信息的 JSON 值
+fn w3c_plain_json(value: &TokenValue) -> serde_json::Value {
+    match value {
+        TokenValue::Number(n) => serde_json::json!(n),
+        TokenValue::Boolean(b) => serde_json::json!(b),
+        TokenValue::Array(items) => {
+            serde_json::Value::Array(items.iter().map(w3c_plain_json).collect())
+        }
+        TokenValue::Object(obj) => serde_json::Value::Object(
+            obj.iter()
+                .map(|(key, item)| (key.clone(), w3c_plain_json(item)))
+                .collect(),
+        ),
+        TokenValue::Null => serde_json::Value::Null,
+        other => serde_json::Value::String(other.to_string()),
+    }
+}
+
+/// 按路径段将令牌节点插入到嵌套分组中
+fn insert_w3c_token(
+    group: &mut serde_json::Map<String, serde_json::Value>,
+    segments: &[&str],
+    token: serde_json::Value,
+) {
+    match segments {
+        [] => {}
+        [name] => {
+            group.insert(name.to_string(), token);
+        }
+        [name, rest @ ..] => {
+            let child = group
+                .entry(name.to_string())
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            // 已是令牌叶子节点时不能再作为分组
+            if let Some(child) = child.as_object_mut().filter(|c| !c.contains_key("$value")) {
+                insert_w3c_token(child, rest, token);
+            }
+        }
+    }
 }
 
 /// 响应式断点配置
@@ -1519,3 +1770,60 @@ impl TokenValues for TokenValuesImpl {
         "token_store"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const W3C_TOKENS: &str = r##"{
+        "color": {
+            "$type": "color",
+            "primary": { "$value": "#1890ff", "$description": "Brand color" },
+            "link": { "$value": "{color.primary}" }
+        },
+        "spacing": {
+            "md": { "$type": "dimension", "$value": "16px" }
+        }
+    }"##;
+
+    #[test]
+    fn test_w3c_json_import() {
+        let tokens = DesignTokens::from_w3c_json(W3C_TOKENS).unwrap();
+        let theme = ThemeVariant::default();
+
+        assert_eq!(
+            tokens.get_value("color.primary", theme),
+            Some(&TokenValue::Color(ColorValue::new("#1890ff".to_string())))
+        );
+        assert_eq!(
+            tokens.get_value("spacing.md", theme),
+            Some(&TokenValue::Dimension(DimensionValue::new(
+                16.0,
+                DimensionUnit::Px
+            )))
+        );
+        assert_eq!(
+            tokens.get_value("color.link", theme),
+            Some(&TokenValue::TokenReference(TokenReference::create(
+                "color.primary".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_w3c_json_round_trip() {
+        let tokens = DesignTokens::from_w3c_json(W3C_TOKENS).unwrap();
+        let exported = tokens.to_w3c_json();
+        let reimported = DesignTokens::from_w3c_json(&exported).unwrap();
+
+        assert_eq!(tokens, reimported);
+        assert!(exported.contains("\"{color.primary}\""));
+        assert!(exported.contains("\"16px\""));
+    }
+
+    #[test]
+    fn test_w3c_json_invalid_input() {
+        assert!(DesignTokens::from_w3c_json("[]").is_err());
+        assert!(DesignTokens::from_w3c_json("{ \"color\": 1 }").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::theme::core::css::{CssObject, CssValue};
+use crate::theme::core::optimize::{split_declarations, BrowserTargets};
+use crate::theme::core::transform::Transformer;
+use std::collections::HashSet;
+
+/// 需要厂商前缀的属性及其可能使用的前缀
+const PREFIXED_PROPERTIES: &[(&str, &[&str])] = &[
+    ("user-select", &["webkit", "moz", "ms"]),
+    ("backdrop-filter", &["webkit"]),
+    ("appearance", &["webkit", "moz"]),
+    ("mask", &["webkit"]),
+    ("mask-image", &["webkit"]),
+    ("mask-size", &["webkit"]),
+    ("mask-position", &["webkit"]),
+    ("mask-repeat", &["webkit"]),
+    ("mask-clip", &["webkit"]),
+    ("mask-origin", &["webkit"]),
+    ("transform", &["webkit", "ms"]),
+    ("transition", &["webkit"]),
+    ("animation", &["webkit"]),
+    ("text-size-adjust", &["webkit", "moz", "ms"]),
+    ("box-decoration-break", &["webkit"]),
+    ("hyphens", &["webkit", "ms"]),
+];
+
+/// 厂商前缀转换器
+///
+/// 类似 autoprefixer，为已知属性补充目标浏览器仍需要的厂商前缀声明。
+/// 是否需要前缀由 [`BrowserTargets`] 决定，默认目标为 `defaults`（主流浏览器最近两个版本）。
+///
+/// 转换是幂等的：已经存在的带前缀声明不会被重复添加，也不会被覆盖。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::theme::core::css::CssObject;
+/// use css_in_rust::theme::core::transform::{Transformer, AutoprefixTransformer};
+///
+/// let mut css = CssObject::new();
+/// css.set("user-select", "none");
+///
+/// let transformer = AutoprefixTransformer::default();
+/// transformer.visit(&mut css).unwrap();
+///
+/// assert_eq!(css.get("-webkit-user-select").unwrap().as_str(), Some("none"));
+/// ```
+pub struct AutoprefixTransformer {
+    targets: BrowserTargets,
+}
+
+impl AutoprefixTransformer {
+    /// 创建新的厂商前缀转换器
+    ///
+    /// # 参数
+    ///
+    /// * `target_browsers` - browserslist 风格的目标浏览器查询，如 `last 2 versions`、`safari >= 12`
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::transform::AutoprefixTransformer;
+    ///
+    /// let transformer = AutoprefixTransformer::new(&["safari >= 12".to_string()]);
+    /// ```
+    pub fn new(target_browsers: &[String]) -> Self {
+        Self {
+            targets: BrowserTargets::from_queries(target_browsers),
+        }
+    }
+
+    /// 为 CSS 字符串补充厂商前缀
+    ///
+    /// 带前缀的声明插入在对应的无前缀声明之前，其余内容和声明顺序保持不变。
+    /// 引号字符串和括号（如 `url(data:...)`）内部的 `{`、`}`、`;` 不作为分隔符。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::transform::AutoprefixTransformer;
+    ///
+    /// let transformer = AutoprefixTransformer::default();
+    /// let css = transformer.prefix_css(".a { color: red; user-select: none; }");
+    ///
+    /// assert_eq!(css, ".a { color: red; -webkit-user-select: none; user-select: none; }");
+    /// ```
+    pub fn prefix_css(&self, css: &str) -> String {
+        let mut output = String::with_capacity(css.len());
+        let mut segment = String::new();
+        let mut quote: Option<char> = None;
+        let mut escaped = false;
+        let mut paren_depth = 0usize;
+
+        for c in css.chars() {
+            if let Some(q) = quote {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+                segment.push(c);
+                continue;
+            }
+
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '(' => paren_depth += 1,
+                ')' => paren_depth = paren_depth.saturating_sub(1),
+                _ => {}
+            }
+
+            if (c == '{' || c == '}') && paren_depth == 0 {
+                // `{` 之前的最后一段是选择器，不是声明
+                output.push_str(&self.prefix_segment(&segment, c == '{'));
+                output.push(c);
+                segment.clear();
+            } else {
+                segment.push(c);
+            }
+        }
+        output.push_str(&self.prefix_segment(&segment, false));
+
+        output
+    }
+
+    /// 为一段声明列表补充厂商前缀
+    fn prefix_segment(&self, segment: &str, ends_with_selector: bool) -> String {
+        let parts = split_declarations(segment);
+        let existing: HashSet<String> = parts
+            .iter()
+            .filter_map(|part| part.split_once(':'))
+            .map(|(name, _)| name.trim().to_lowercase())
+            .collect();
+
+        let mut output = String::with_capacity(segment.len());
+        for (index, part) in parts.iter().enumerate() {
+            let is_last = index == parts.len() - 1;
+
+            if !(is_last && ends_with_selector) {
+                if let Some((name, value)) = part.split_once(':') {
+                    let indent = &part[..part.len() - part.trim_start().len()];
+                    for prefixed in self.prefixed_names(name.trim()) {
+                        if !existing.contains(&prefixed) {
+                            output.push_str(&format!("{}{}:{};", indent, prefixed, value));
+                        }
+                    }
+                }
+            }
+
+            output.push_str(part);
+            if !is_last {
+                output.push(';');
+            }
+        }
+
+        output
+    }
+
+    /// 获取属性在目标浏览器下需要的带前缀属性名（短横线形式）
+    fn prefixed_names(&self, property: &str) -> Vec<String> {
+        let property = property.to_lowercase();
+        if property.starts_with('-') {
+            return Vec::new();
+        }
+
+        PREFIXED_PROPERTIES
+            .iter()
+            .find(|(name, _)| *name == property)
+            .map(|(_, vendors)| {
+                vendors
+                    .iter()
+                    .map(|vendor| format!("-{}-{}", vendor, property))
+                    .filter(|prefixed| self.targets.needs_prefix(prefixed))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Default for AutoprefixTransformer {
+    fn default() -> Self {
+        Self::new(&["defaults".to_string()])
+    }
+}
+
+impl Transformer for AutoprefixTransformer {
+    /// 为 CSS 对象中的已知属性补充厂商前缀
+    ///
+    /// 同时支持短横线（`user-select`）和驼峰（`userSelect`）形式的属性名，
+    /// 驼峰形式会生成 `WebkitUserSelect` 这样的属性名（`ms` 前缀按惯例保持小写，如 `msUserSelect`）。
+    /// 嵌套对象会被递归处理。
+    fn visit(&self, css_obj: &mut CssObject) -> Result<(), String> {
+        let mut additions = Vec::new();
+
+        for (key, value) in css_obj.properties.iter_mut() {
+            if let CssValue::Object(obj) = value {
+                self.visit(obj)?;
+                continue;
+            }
+
+            let camel_case = key.chars().any(|c| c.is_ascii_uppercase());
+            let property = if camel_case {
+                camel_to_kebab(key)
+            } else {
+                key.clone()
+            };
+
+            for prefixed in self.prefixed_names(&property) {
+                let prefixed = if camel_case {
+                    kebab_to_camel(prefixed.trim_start_matches('-'))
+                } else {
+                    prefixed
+                };
+                additions.push((prefixed, value.clone()));
+            }
+        }
+
+        for (key, value) in additions {
+            css_obj.properties.entry(key).or_insert(value);
+        }
+
+        Ok(())
+    }
+}
+
+/// `userSelect` → `user-select`
+fn camel_to_kebab(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            kebab.push('-');
+            kebab.push(c.to_ascii_lowercase());
+        } else {
+            kebab.push(c);
+        }
+    }
+    kebab
+}
+
+/// `webkit-user-select` → `WebkitUserSelect`，`ms-user-select` → `msUserSelect`
+fn kebab_to_camel(name: &str) -> String {
+    name.split('-')
+        .enumerate()
+        .map(|(index, word)| {
+            if index == 0 && word == "ms" {
+                return word.to_string();
+            }
+
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_select_gains_webkit_prefix() {
+        let transformer = AutoprefixTransformer::default();
+
+        let mut css = CssObject::new();
+        css.set("user-select", "none");
+        css.set("userSelect", "none");
+        transformer.visit(&mut css).unwrap();
+
+        assert_eq!(
+            css.get("-webkit-user-select").unwrap().as_str(),
+            Some("none")
+        );
+        assert_eq!(css.get("WebkitUserSelect").unwrap().as_str(), Some("none"));
+
+        let prefixed = transformer.prefix_css(".a { user-select: none; }");
+        assert_eq!(
+            prefixed,
+            ".a { -webkit-user-select: none; user-select: none; }"
+        );
+    }
+
+    #[test]
+    fn test_prefixing_is_idempotent() {
+        let transformer = AutoprefixTransformer::default();
+
+        let css = ".a {\n  color: red;\n  user-select: none;\n  padding: 0\n}";
+        let once = transformer.prefix_css(css);
+        let twice = transformer.prefix_css(&once);
+
+        assert_eq!(once, twice);
+        assert_eq!(once.matches("-webkit-user-select").count(), 1);
+        assert_eq!(
+            once,
+            ".a {\n  color: red;\n  -webkit-user-select: none;\n  user-select: none;\n  padding: 0\n}"
+        );
+
+        let mut object = CssObject::new();
+        object.set("user-select", "none");
+        transformer.visit(&mut object).unwrap();
+        transformer.visit(&mut object).unwrap();
+        assert_eq!(object.len(), 2);
+    }
+
+    #[test]
+    fn test_prefixes_follow_targets() {
+        let css = ".a { backdrop-filter: blur(4px); a:hover { mask: url(m.svg); } }";
+
+        let modern = AutoprefixTransformer::default().prefix_css(css);
+        assert_eq!(modern, css);
+
+        let legacy = AutoprefixTransformer::new(&["safari >= 14".to_string()]).prefix_css(css);
+        assert!(legacy.contains("-webkit-backdrop-filter: blur(4px); backdrop-filter"));
+        assert!(legacy.contains("a:hover { -webkit-mask: url(m.svg); mask"));
+    }
+
+    #[test]
+    fn test_values_with_urls_and_strings_are_preserved() {
+        let transformer = AutoprefixTransformer::new(&["safari >= 14".to_string()]);
+
+        let css = ".a { mask: url(data:image/png;base64,iVBO=); content: \"{;}\"; mask-image: url(\"data:image/svg+xml;utf8,<svg>{}</svg>\"); }";
+        assert_eq!(
+            transformer.prefix_css(css),
+            ".a { -webkit-mask: url(data:image/png;base64,iVBO=); mask: url(data:image/png;base64,iVBO=); content: \"{;}\"; -webkit-mask-image: url(\"data:image/svg+xml;utf8,<svg>{}</svg>\"); mask-image: url(\"data:image/svg+xml;utf8,<svg>{}</svg>\"); }"
+        );
+    }
+
+    #[test]
+    fn test_camel_case_ms_prefix_is_lowercase() {
+        let transformer = AutoprefixTransformer::new(&["ie 11".to_string()]);
+
+        let mut css = CssObject::new();
+        css.set("userSelect", "none");
+        transformer.visit(&mut css).unwrap();
+
+        assert_eq!(css.get("msUserSelect").unwrap().as_str(), Some("none"));
+        assert!(css.get("MsUserSelect").is_none());
+        assert_eq!(kebab_to_camel("webkit-user-select"), "WebkitUserSelect");
+    }
+}
@@ -3,10 +3,13 @@
 /// 提供对 CSS 对象的转换和处理功能，包括：
 /// - 逻辑属性转换器：将逻辑属性转换为物理属性
 /// - px2rem 转换器：将 px 单位转换为 rem 单位
+/// - 厂商前缀转换器：根据目标浏览器补充厂商前缀
+mod autoprefixer;
 mod interface;
 mod logical_properties;
 mod px2rem;
 
+pub use autoprefixer::*;
 pub use interface::*;
 pub use logical_properties::*;
 pub use px2rem::*;
@@ -24,11 +24,13 @@
 //! ```
 
 pub mod core;
+pub mod reset;
 pub mod systems;
 pub mod theme_types;
 
 // Re-exports
 pub use core::ThemeVariant;
+pub use reset::{generate_reset_css, ResetStyle};
 pub use theme_types::Theme;
 
 // Dioxus集成导出
@@ -0,0 +1,129 @@
+//! # 样式重置模块
+//!
+//! 提供可复用的 CSS 重置样式表，作为应用注入主题和组件样式之前的一致基线。
+
+/// 样式重置级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResetStyle {
+    /// 最小重置：统一盒模型并清除 `body` 的默认边距
+    #[default]
+    Minimal,
+    /// 标准化：在最小重置的基础上统一常见元素在各浏览器中的默认样式
+    Normalize,
+}
+
+/// 最小重置样式
+const MINIMAL_RESET: &str = r#"*,
+*::before,
+*::after {
+    box-sizing: border-box;
+}
+
+body {
+    margin: 0;
+    padding: 0;
+}
+"#;
+
+/// 标准化样式（追加在最小重置之后）
+const NORMALIZE_RESET: &str = r#"html {
+    line-height: 1.15;
+    -webkit-text-size-adjust: 100%;
+}
+
+h1, h2, h3, h4, h5, h6, p, blockquote, figure, dl, dd {
+    margin: 0;
+}
+
+ul, ol {
+    margin: 0;
+    padding: 0;
+}
+
+a {
+    background-color: transparent;
+    color: inherit;
+    text-decoration: inherit;
+}
+
+b, strong {
+    font-weight: bolder;
+}
+
+code, kbd, samp, pre {
+    font-family: monospace, monospace;
+    font-size: 1em;
+}
+
+img, svg, video, canvas {
+    display: block;
+    max-width: 100%;
+}
+
+button, input, optgroup, select, textarea {
+    margin: 0;
+    font-family: inherit;
+    font-size: 100%;
+    line-height: inherit;
+    color: inherit;
+}
+
+button, [type="button"], [type="reset"], [type="submit"] {
+    -webkit-appearance: button;
+    cursor: pointer;
+}
+
+table {
+    border-collapse: collapse;
+    border-spacing: 0;
+}
+
+[hidden] {
+    display: none;
+}
+"#;
+
+/// 生成样式重置表
+///
+/// 返回所选级别的重置样式表，可直接通过样式注入器注入为全局样式。
+///
+/// # 参数
+///
+/// * `style` - 重置级别
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::theme::{generate_reset_css, ResetStyle};
+///
+/// let css = generate_reset_css(ResetStyle::Minimal);
+/// assert!(css.contains("box-sizing: border-box"));
+/// ```
+pub fn generate_reset_css(style: ResetStyle) -> String {
+    match style {
+        ResetStyle::Minimal => MINIMAL_RESET.to_string(),
+        ResetStyle::Normalize => format!("{}\n{}", MINIMAL_RESET, NORMALIZE_RESET),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_reset() {
+        let css = generate_reset_css(ResetStyle::Minimal);
+        assert!(css.contains("box-sizing: border-box"));
+        assert!(css.contains("body {"));
+        assert!(!css.contains("button"));
+    }
+
+    #[test]
+    fn test_normalize_reset() {
+        let css = generate_reset_css(ResetStyle::Normalize);
+        assert!(css.contains("box-sizing: border-box"));
+        for selector in ["html {", "ul, ol {", "button, input", "table {", "img, svg"] {
+            assert!(css.contains(selector), "missing `{}`", selector);
+        }
+    }
+}
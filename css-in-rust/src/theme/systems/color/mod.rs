@@ -2,7 +2,9 @@
 //!
 //! 提供通用的颜色管理功能，包括颜色调色板、语义颜色映射等。
 
-use crate::theme::core::token::definitions::{ColorValue, ThemeVariant, TokenReference};
+use crate::theme::core::token::definitions::{
+    ColorValue, ThemeVariant, TokenReference, TokenTransform,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -202,6 +204,151 @@ impl ColorSystem {
             _ => Err(format!("Invalid color path: {}", path)),
         }
     }
+
+    /// 从颜色调色板创建颜色系统
+    ///
+    /// 主色调和中性色按色阶直接复制；调色板中的成功、警告、错误和信息色
+    /// 以 `success.500` 这样的键写入功能色。颜色值使用其 CSS 字符串形式。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::ColorSystem;
+    /// use css_in_rust::theme::systems::color::ColorPalette;
+    ///
+    /// let palette = ColorPalette::from_color_system(&ColorSystem::new());
+    /// let color_system = ColorSystem::from_palette(&palette);
+    ///
+    /// assert_eq!(
+    ///     color_system.get_color("functional.success.500"),
+    ///     Some(&"#52c41a".to_string())
+    /// );
+    /// ```
+    pub fn from_palette(palette: &ColorPalette) -> Self {
+        let to_strings = |colors: &BTreeMap<String, ColorValue>| {
+            colors
+                .iter()
+                .map(|(level, color)| (level.clone(), color.to_css_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut functional = BTreeMap::new();
+        for (name, colors) in palette.functional_groups() {
+            for (level, color) in colors {
+                functional.insert(format!("{}.{}", name, level), color.to_css_string());
+            }
+        }
+
+        Self {
+            primary: to_strings(&palette.primary),
+            neutral: to_strings(&palette.neutral),
+            functional,
+            extended: BTreeMap::new(),
+        }
+    }
+
+    /// 创建 Ant Design 亮色主题的颜色系统
+    ///
+    /// 主色调和功能色使用 Ant Design 的色值，文本、背景、边框和填充等语义色放在扩展色中。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::ColorSystem;
+    ///
+    /// let color_system = ColorSystem::ant_design();
+    /// assert_eq!(color_system.get_color("primary.500"), Some(&"#1677ff".to_string()));
+    /// ```
+    pub fn ant_design() -> Self {
+        let mut color_system = Self::default();
+
+        for (level, color) in [("400", "#4096ff"), ("500", "#1677ff"), ("600", "#0958d9")] {
+            color_system
+                .primary
+                .insert(level.to_string(), color.to_string());
+        }
+
+        for (path, color) in [
+            ("success.500", "#52c41a"),
+            ("warning.500", "#faad14"),
+            ("error.400", "#ff7875"),
+            ("error.500", "#ff4d4f"),
+            ("error.600", "#d9363e"),
+            ("info.500", "#1677ff"),
+        ] {
+            color_system
+                .functional
+                .insert(path.to_string(), color.to_string());
+        }
+
+        for (name, color) in [
+            ("text", "rgba(0, 0, 0, 0.88)"),
+            ("text-secondary", "rgba(0, 0, 0, 0.65)"),
+            ("text-tertiary", "rgba(0, 0, 0, 0.45)"),
+            ("text-quaternary", "rgba(0, 0, 0, 0.25)"),
+            ("text-heading", "rgba(0, 0, 0, 0.88)"),
+            ("bg-container", "#ffffff"),
+            ("bg-layout", "#f5f5f5"),
+            ("bg-spotlight", "#ffffff"),
+            ("border", "#d9d9d9"),
+            ("border-secondary", "#f0f0f0"),
+            ("fill", "rgba(0, 0, 0, 0.15)"),
+            ("fill-secondary", "rgba(0, 0, 0, 0.06)"),
+            ("fill-tertiary", "rgba(0, 0, 0, 0.04)"),
+            ("fill-quaternary", "rgba(0, 0, 0, 0.02)"),
+            ("white", "#ffffff"),
+        ] {
+            color_system
+                .extended
+                .insert(name.to_string(), color.to_string());
+        }
+
+        color_system
+    }
+
+    /// 生成 Ant Design 风格的颜色变量
+    ///
+    /// 主色调 `400/500/600` 对应 `hover/默认/active`，功能色同理；扩展色按名称输出为
+    /// `--ant-color-{name}`。缺失的颜色会被跳过。
+    pub fn to_ant_design_variables(&self) -> BTreeMap<String, String> {
+        let mut variables = BTreeMap::new();
+
+        for (name, path) in [
+            ("primary", "primary.500"),
+            ("primary-hover", "primary.400"),
+            ("primary-active", "primary.600"),
+            ("success", "functional.success.500"),
+            ("warning", "functional.warning.500"),
+            ("error", "functional.error.500"),
+            ("error-hover", "functional.error.400"),
+            ("error-active", "functional.error.600"),
+            ("info", "functional.info.500"),
+            ("link", "primary.500"),
+            ("link-hover", "primary.400"),
+            ("link-active", "primary.600"),
+        ] {
+            if let Some(color) = self.get_color(path) {
+                variables.insert(format!("--ant-color-{}", name), color.clone());
+            }
+        }
+
+        // 背景色为基础色的 10% 透明度
+        for (name, path) in [
+            ("primary-bg", "primary.500"),
+            ("error-bg", "functional.error.500"),
+        ] {
+            if let Some(color) = self.get_color(path) {
+                let background = ColorValue::with_alpha(color.clone(), 0.1).to_css_string();
+                variables.insert(format!("--ant-color-{}", name), background);
+            }
+        }
+
+        for (name, color) in &self.extended {
+            variables.insert(format!("--ant-color-{}", name), color.clone());
+        }
+
+        variables
+    }
 }
 
 /// 颜色调色板
@@ -320,6 +467,104 @@ pub struct StatusColors {
     pub info: TokenReference,
 }
 
+/// 解析后的语义颜色
+///
+/// 由 [`SemanticColors::resolve`] 生成，所有令牌引用都已替换为具体的 CSS 颜色值。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedSemanticColors {
+    /// 文本颜色
+    pub text: ResolvedTextColors,
+    /// 背景颜色
+    pub background: ResolvedBackgroundColors,
+    /// 边框颜色
+    pub border: ResolvedBorderColors,
+    /// 状态颜色
+    pub status: ResolvedStatusColors,
+}
+
+/// 解析后的文本颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedTextColors {
+    /// 主要文本
+    pub primary: String,
+    /// 次要文本
+    pub secondary: String,
+    /// 禁用文本
+    pub disabled: String,
+    /// 占位符文本
+    pub placeholder: String,
+}
+
+/// 解析后的背景颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedBackgroundColors {
+    /// 主要背景
+    pub primary: String,
+    /// 次要背景
+    pub secondary: String,
+    /// 悬停背景
+    pub hover: String,
+    /// 激活背景
+    pub active: String,
+}
+
+/// 解析后的边框颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedBorderColors {
+    /// 默认边框
+    pub default: String,
+    /// 悬停边框
+    pub hover: String,
+    /// 焦点边框
+    pub focus: String,
+    /// 错误边框
+    pub error: String,
+}
+
+/// 解析后的状态颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedStatusColors {
+    /// 成功状态
+    pub success: String,
+    /// 警告状态
+    pub warning: String,
+    /// 错误状态
+    pub error: String,
+    /// 信息状态
+    pub info: String,
+}
+
+impl ResolvedSemanticColors {
+    /// 生成 CSS 变量声明
+    ///
+    /// 每个颜色生成一行 `--{分组}-{名称}: {值};`，如 `--text-primary: #262626;`。
+    pub fn to_css_variables(&self) -> String {
+        let entries = [
+            ("text-primary", &self.text.primary),
+            ("text-secondary", &self.text.secondary),
+            ("text-disabled", &self.text.disabled),
+            ("text-placeholder", &self.text.placeholder),
+            ("background-primary", &self.background.primary),
+            ("background-secondary", &self.background.secondary),
+            ("background-hover", &self.background.hover),
+            ("background-active", &self.background.active),
+            ("border-default", &self.border.default),
+            ("border-hover", &self.border.hover),
+            ("border-focus", &self.border.focus),
+            ("border-error", &self.border.error),
+            ("status-success", &self.status.success),
+            ("status-warning", &self.status.warning),
+            ("status-error", &self.status.error),
+            ("status-info", &self.status.info),
+        ];
+
+        entries
+            .iter()
+            .map(|(name, value)| format!("--{}: {};\n", name, value))
+            .collect()
+    }
+}
+
 impl Default for ColorPalette {
     fn default() -> Self {
         Self {
@@ -390,6 +635,114 @@ impl Default for StatusColors {
 
 /// 颜色系统工具函数
 impl ColorPalette {
+    /// 从颜色系统创建颜色调色板
+    ///
+    /// 主色调和中性色按色阶直接复制；功能色中 `success.*`、`warning.*`、`error.*`
+    /// 和 `info.*` 分别写入对应的调色板字段，其余功能色和扩展色在调色板中没有对应位置，会被忽略。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::ColorSystem;
+    /// use css_in_rust::theme::systems::color::ColorPalette;
+    ///
+    /// let palette = ColorPalette::from_color_system(&ColorSystem::new());
+    ///
+    /// assert_eq!(palette.primary["500"].hex, "#0066cc");
+    /// assert_eq!(palette.error["500"].hex, "#ff4d4f");
+    /// ```
+    pub fn from_color_system(color_system: &ColorSystem) -> Self {
+        let to_colors = |colors: &BTreeMap<String, String>| {
+            colors
+                .iter()
+                .map(|(level, value)| (level.clone(), ColorValue::new(value.clone())))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut palette = Self {
+            primary: to_colors(&color_system.primary),
+            neutral: to_colors(&color_system.neutral),
+            ..Self::default()
+        };
+
+        for (key, value) in &color_system.functional {
+            let Some((name, level)) = key.split_once('.') else {
+                continue;
+            };
+            let target = match name {
+                "success" => &mut palette.success,
+                "warning" => &mut palette.warning,
+                "error" => &mut palette.error,
+                "info" => &mut palette.info,
+                _ => continue,
+            };
+            target.insert(level.to_string(), ColorValue::new(value.clone()));
+        }
+
+        palette
+    }
+
+    /// 获取背景色上的可读前景色
+    ///
+    /// 按 WCAG 对比度在黑色和白色之间选择对比度更高的一个。无法解析的背景色返回黑色。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::ColorValue;
+    /// use css_in_rust::theme::systems::color::ColorPalette;
+    ///
+    /// let palette = ColorPalette::default();
+    /// let text = palette.on_color(&ColorValue::new("#001429".to_string()));
+    ///
+    /// assert_eq!(text.hex, "#ffffff");
+    /// ```
+    pub fn on_color(&self, bg: &ColorValue) -> ColorValue {
+        let black = ColorValue::new("#000000".to_string());
+        let white = ColorValue::new("#ffffff".to_string());
+        let Some(bg_rgb) = bg.to_rgb() else {
+            return black;
+        };
+
+        if contrast_ratio(bg_rgb, (255, 255, 255)) > contrast_ratio(bg_rgb, (0, 0, 0)) {
+            white
+        } else {
+            black
+        }
+    }
+
+    /// 从中性色阶中选择背景色上对比度最高的文本颜色
+    ///
+    /// 对比度相同时取较低的色阶。中性色为空或背景色无法解析时回退到 [`ColorPalette::on_color`]。
+    pub fn best_text_shade(&self, bg: &ColorValue) -> ColorValue {
+        let Some(bg_rgb) = bg.to_rgb() else {
+            return self.on_color(bg);
+        };
+
+        self.neutral
+            .values()
+            .filter_map(|shade| Some((shade, contrast_ratio(bg_rgb, shade.to_rgb()?))))
+            .fold(
+                None,
+                |best: Option<(&ColorValue, f64)>, (shade, ratio)| match best {
+                    Some((_, best_ratio)) if best_ratio >= ratio => best,
+                    _ => Some((shade, ratio)),
+                },
+            )
+            .map(|(shade, _)| shade.clone())
+            .unwrap_or_else(|| self.on_color(bg))
+    }
+
+    /// 功能色分组（名称和色阶）
+    fn functional_groups(&self) -> [(&str, &BTreeMap<String, ColorValue>); 4] {
+        [
+            ("success", &self.success),
+            ("warning", &self.warning),
+            ("error", &self.error),
+            ("info", &self.info),
+        ]
+    }
+
     /// 应用浅色主题颜色
     ///
     /// 配置调色板以使用浅色主题的颜色值。这将设置主色调和中性色的所有色阶。
@@ -512,7 +865,124 @@ impl ColorPalette {
     }
 }
 
+/// 计算 WCAG 相对亮度
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let linear = |channel: u8| {
+        let c = channel as f64 / 255.0;
+        if c <= 0.039_28 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// 计算两个颜色之间的 WCAG 对比度（1 到 21）
+fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
 impl SemanticColors {
+    /// 按指定主题模式解析语义颜色
+    ///
+    /// 在副本上应用 [`SemanticColors::update_for_theme`] 的映射后再解析，不会修改 `self`，
+    /// 便于从同一份语义颜色一次性预计算浅色和深色两套颜色值。
+    ///
+    /// # 参数
+    ///
+    /// * `palette` - 用于查找颜色值的调色板
+    /// * `mode` - 主题模式
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::core::token::definitions::ThemeVariant;
+    /// use css_in_rust::theme::systems::color::{ColorPalette, SemanticColors};
+    /// use css_in_rust::theme::systems::ColorSystem;
+    ///
+    /// let mut color_system = ColorSystem::new();
+    /// color_system.set_color("functional.info.500", "#1677ff".to_string()).unwrap();
+    /// let palette = ColorPalette::from_color_system(&color_system);
+    ///
+    /// let semantic = SemanticColors::default();
+    /// let light = semantic.resolve_for_mode(&palette, ThemeVariant::Light).unwrap();
+    /// let dark = semantic.resolve_for_mode(&palette, ThemeVariant::Dark).unwrap();
+    ///
+    /// assert_ne!(light.text.primary, dark.text.primary);
+    /// ```
+    pub fn resolve_for_mode(
+        &self,
+        palette: &ColorPalette,
+        mode: ThemeVariant,
+    ) -> Result<ResolvedSemanticColors, String> {
+        let mut semantic = self.clone();
+        semantic.update_for_theme(mode);
+        semantic.resolve(palette)
+    }
+
+    /// 将语义颜色解析为具体的颜色值
+    ///
+    /// 令牌引用的格式为 `global.color_palette.{分组}.{色阶}`（`global.color_palette.` 前缀可省略），
+    /// 分组为调色板中的 `primary`、`neutral`、`success`、`warning`、`error` 或 `info`。
+    /// 目前仅支持 `Alpha` 变换。
+    ///
+    /// # 参数
+    ///
+    /// * `palette` - 用于查找颜色值的调色板
+    ///
+    /// # 返回值
+    ///
+    /// 所有引用都能解析时返回 `Ok(ResolvedSemanticColors)`，否则返回第一个无法解析的引用对应的错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::color::{ColorPalette, SemanticColors};
+    /// use css_in_rust::theme::systems::ColorSystem;
+    ///
+    /// let mut color_system = ColorSystem::new();
+    /// color_system.set_color("functional.info.500", "#1677ff".to_string()).unwrap();
+    /// let palette = ColorPalette::from_color_system(&color_system);
+    ///
+    /// let resolved = SemanticColors::default().resolve(&palette).unwrap();
+    /// assert!(resolved.to_css_variables().contains("--text-primary: #1f1f1f;"));
+    ///
+    /// // 空调色板中找不到任何引用
+    /// assert!(SemanticColors::default().resolve(&ColorPalette::default()).is_err());
+    /// ```
+    pub fn resolve(&self, palette: &ColorPalette) -> Result<ResolvedSemanticColors, String> {
+        let resolve = |reference: &TokenReference| resolve_palette_reference(reference, palette);
+
+        Ok(ResolvedSemanticColors {
+            text: ResolvedTextColors {
+                primary: resolve(&self.text.primary)?,
+                secondary: resolve(&self.text.secondary)?,
+                disabled: resolve(&self.text.disabled)?,
+                placeholder: resolve(&self.text.placeholder)?,
+            },
+            background: ResolvedBackgroundColors {
+                primary: resolve(&self.background.primary)?,
+                secondary: resolve(&self.background.secondary)?,
+                hover: resolve(&self.background.hover)?,
+                active: resolve(&self.background.active)?,
+            },
+            border: ResolvedBorderColors {
+                default: resolve(&self.border.default)?,
+                hover: resolve(&self.border.hover)?,
+                focus: resolve(&self.border.focus)?,
+                error: resolve(&self.border.error)?,
+            },
+            status: ResolvedStatusColors {
+                success: resolve(&self.status.success)?,
+                warning: resolve(&self.status.warning)?,
+                error: resolve(&self.status.error)?,
+                info: resolve(&self.status.info)?,
+            },
+        })
+    }
+
     /// 更新语义颜色以适应指定主题
     ///
     /// 根据提供的主题变体（浅色、深色或自动），更新语义颜色的引用，
@@ -540,8 +1010,37 @@ impl SemanticColors {
     /// semantic_colors.update_for_theme(ThemeVariant::Auto);
     /// ```
     pub fn update_for_theme(&mut self, theme: ThemeVariant) {
-        match theme {
-            ThemeVariant::Light => {
+        // 未提供系统偏好时 Auto 按浅色处理
+        self.update_for_system_theme(theme, false);
+    }
+
+    /// 按系统偏好更新语义颜色
+    ///
+    /// `Auto` 通过 [`ThemeVariant::resolve`] 解析为具体变体后再应用对应的映射。
+    ///
+    /// # 参数
+    ///
+    /// * `theme` - 主题变体
+    /// * `system_is_dark` - 系统是否偏好深色
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::color::SemanticColors;
+    /// use css_in_rust::theme::core::token::definitions::ThemeVariant;
+    ///
+    /// let mut auto = SemanticColors::default();
+    /// auto.update_for_system_theme(ThemeVariant::Auto, true);
+    ///
+    /// let mut dark = SemanticColors::default();
+    /// dark.update_for_theme(ThemeVariant::Dark);
+    ///
+    /// assert_eq!(auto.text.primary.reference, dark.text.primary.reference);
+    /// ```
+    pub fn update_for_system_theme(&mut self, theme: ThemeVariant, system_is_dark: bool) {
+        match theme.resolve(system_is_dark) {
+            // resolve 之后不会再出现 Auto
+            ThemeVariant::Light | ThemeVariant::Auto => {
                 self.text.primary =
                     TokenReference::create("global.color_palette.neutral.900".to_string());
                 self.text.secondary =
@@ -557,13 +1056,182 @@ impl SemanticColors {
                 self.background.primary =
                     TokenReference::create("global.color_palette.neutral.900".to_string());
             }
-            ThemeVariant::Auto => {
-                // Auto模式使用浅色主题设置
-                self.text.primary =
-                    TokenReference::create("global.color_palette.neutral.900".to_string());
-                self.background.primary =
-                    TokenReference::create("global.color_palette.neutral.50".to_string());
-            }
         }
     }
 }
+
+/// 在调色板中解析单个令牌引用
+fn resolve_palette_reference(
+    reference: &TokenReference,
+    palette: &ColorPalette,
+) -> Result<String, String> {
+    let path = reference.reference.as_str();
+    let path = path.strip_prefix("global.").unwrap_or(path);
+    let path = path.strip_prefix("color_palette.").unwrap_or(path);
+
+    let (group, level) = path
+        .split_once('.')
+        .ok_or_else(|| format!("Invalid color reference: {}", reference.reference))?;
+    let colors = match group {
+        "primary" => &palette.primary,
+        "neutral" => &palette.neutral,
+        "success" => &palette.success,
+        "warning" => &palette.warning,
+        "error" => &palette.error,
+        "info" => &palette.info,
+        _ => return Err(format!("Unknown color group: {}", reference.reference)),
+    };
+    let color = colors
+        .get(level)
+        .ok_or_else(|| format!("Unresolved color reference: {}", reference.reference))?;
+
+    match &reference.transform {
+        None => Ok(color.to_css_string()),
+        Some(TokenTransform::Alpha(alpha)) => {
+            Ok(ColorValue::with_alpha(color.hex.clone(), *alpha).to_css_string())
+        }
+        Some(transform) => Err(format!(
+            "Unsupported transform {:?} for color reference: {}",
+            transform, reference.reference
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_color_picks_higher_contrast() {
+        let palette = ColorPalette::default();
+
+        let dark = ColorValue::new("#1f1f1f".to_string());
+        assert_eq!(palette.on_color(&dark).hex, "#ffffff");
+
+        let light = ColorValue::new("#fafafa".to_string());
+        assert_eq!(palette.on_color(&light).hex, "#000000");
+
+        assert!((contrast_ratio((0, 0, 0), (255, 255, 255)) - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_best_text_shade_uses_neutral_scale() {
+        let mut palette = ColorPalette::default();
+        let dark = ColorValue::new("#001429".to_string());
+        let light = ColorValue::new("#ffffff".to_string());
+
+        // 中性色为空时回退到黑白
+        assert_eq!(palette.best_text_shade(&dark).hex, "#ffffff");
+
+        palette.apply_light_theme();
+        assert_eq!(palette.best_text_shade(&dark).hex, "#fafafa");
+        assert_eq!(palette.best_text_shade(&light).hex, "#262626");
+    }
+
+    #[test]
+    fn test_color_system_palette_round_trip() {
+        let mut color_system = ColorSystem::new();
+        color_system
+            .set_color("functional.info.500", "#1677ff".to_string())
+            .unwrap();
+
+        let palette = ColorPalette::from_color_system(&color_system);
+        assert_eq!(palette.primary["500"].hex, "#0066cc");
+        assert_eq!(palette.success["600"].hex, "#389e0d");
+        assert_eq!(palette.warning["500"].hex, "#faad14");
+        assert_eq!(palette.info["500"].hex, "#1677ff");
+
+        let round_trip = ColorSystem::from_palette(&palette);
+        assert_eq!(round_trip.primary, color_system.primary);
+        assert_eq!(round_trip.neutral, color_system.neutral);
+        assert_eq!(round_trip.functional, color_system.functional);
+    }
+
+    #[test]
+    fn test_resolve_semantic_colors() {
+        let mut palette = ColorPalette::default();
+        palette.apply_light_theme();
+        for group in [
+            &mut palette.success,
+            &mut palette.warning,
+            &mut palette.error,
+            &mut palette.info,
+        ] {
+            group.insert("500".to_string(), ColorValue::new("#123456".to_string()));
+        }
+
+        let mut semantic = SemanticColors::default();
+        semantic.border.focus = TokenReference::create_with_transform(
+            "global.color_palette.primary.500".to_string(),
+            TokenTransform::Alpha(0.5),
+        );
+
+        let resolved = semantic.resolve(&palette).unwrap();
+        assert_eq!(resolved.text.primary, palette.neutral["900"].hex);
+        assert_eq!(resolved.status.info, "#123456");
+        assert_eq!(resolved.border.focus, "rgba(0, 102, 204, 0.5)");
+        assert!(resolved
+            .to_css_variables()
+            .contains("--text-primary: #262626;\n"));
+
+        palette.info.clear();
+        let err = semantic.resolve(&palette).unwrap_err();
+        assert!(err.contains("global.color_palette.info.500"));
+    }
+
+    #[test]
+    fn test_resolve_for_mode() {
+        let palette = ColorPalette::from_color_system(&{
+            let mut color_system = ColorSystem::new();
+            color_system
+                .set_color("functional.info.500", "#1677ff".to_string())
+                .unwrap();
+            color_system
+        });
+        let semantic = SemanticColors::default();
+
+        let light = semantic
+            .resolve_for_mode(&palette, ThemeVariant::Light)
+            .unwrap();
+        let dark = semantic
+            .resolve_for_mode(&palette, ThemeVariant::Dark)
+            .unwrap();
+
+        assert_eq!(light.text.primary, "#1f1f1f");
+        assert_eq!(dark.text.primary, "#f5f5f5");
+        assert_eq!(dark.background.primary, "#1f1f1f");
+        // 原始引用保持不变
+        assert_eq!(
+            semantic.text.primary.reference,
+            "global.color_palette.neutral.900"
+        );
+    }
+
+    #[test]
+    fn test_auto_mode_follows_system_preference() {
+        let mut dark = SemanticColors::default();
+        dark.update_for_theme(ThemeVariant::Dark);
+
+        let mut auto_dark = SemanticColors::default();
+        auto_dark.update_for_system_theme(ThemeVariant::Auto, true);
+        assert_eq!(
+            auto_dark.text.primary.reference,
+            dark.text.primary.reference
+        );
+        assert_eq!(
+            auto_dark.background.primary.reference,
+            dark.background.primary.reference
+        );
+
+        let mut auto_light = SemanticColors::default();
+        auto_light.update_for_system_theme(ThemeVariant::Auto, false);
+        assert_eq!(
+            auto_light.text.primary.reference,
+            "global.color_palette.neutral.900"
+        );
+        assert_eq!(
+            auto_light.text.secondary.reference,
+            "global.color_palette.neutral.600"
+        );
+    }
+}
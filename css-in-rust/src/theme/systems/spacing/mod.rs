@@ -225,4 +225,44 @@ impl SpacingSystem {
     pub fn set_semantic_spacing(&mut self, semantic: SemanticSpacing) {
         self.semantic = semantic;
     }
+
+    /// 生成 Ant Design 风格的间距变量
+    ///
+    /// 将间距比例映射为 `--ant-padding-*`、`--ant-margin-*` 和 `--ant-control-height-*`，
+    /// 默认比例下与 Ant Design 的取值一致（如 `--ant-padding: 16px`）。缺失的比例会被跳过。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::SpacingSystem;
+    ///
+    /// let variables = SpacingSystem::new().to_ant_design_variables();
+    /// assert_eq!(variables["--ant-padding"], "16px");
+    /// ```
+    pub fn to_ant_design_variables(&self) -> BTreeMap<String, String> {
+        let mut variables = BTreeMap::new();
+
+        let sizes = [
+            ("-xs", "2"),
+            ("-sm", "3"),
+            ("", "4"),
+            ("-lg", "6"),
+            ("-xl", "8"),
+        ];
+        for prefix in ["padding", "margin"] {
+            for (suffix, key) in sizes {
+                if let Some(value) = self.get_spacing(key) {
+                    variables.insert(format!("--ant-{}{}", prefix, suffix), value.to_string());
+                }
+            }
+        }
+
+        for (suffix, key) in [("", "8"), ("-lg", "10"), ("-sm", "6")] {
+            if let Some(value) = self.get_spacing(key) {
+                variables.insert(format!("--ant-control-height{}", suffix), value.to_string());
+            }
+        }
+
+        variables
+    }
 }
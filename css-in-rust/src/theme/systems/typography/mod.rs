@@ -21,6 +21,191 @@ pub struct FontSystem {
     pub line_heights: BTreeMap<String, DimensionValue>,
     /// 字间距
     pub letter_spacings: BTreeMap<String, DimensionValue>,
+    /// OpenType 特性（`font-feature-settings`），键为特性标签，如 `liga`
+    #[serde(default)]
+    pub font_features: BTreeMap<String, String>,
+    /// 可变字体轴（`font-variation-settings`），键为轴标签，如 `wght`
+    #[serde(default)]
+    pub font_variations: BTreeMap<String, String>,
+    /// 令牌元数据（描述、弃用说明等），键为令牌路径，如 `font_size.md`
+    #[serde(default)]
+    pub metadata: BTreeMap<String, TokenMetadata>,
+}
+
+impl FontSystem {
+    /// 获取 OpenType 特性值
+    pub fn get_font_feature(&self, tag: &str) -> Option<&String> {
+        self.font_features.get(tag)
+    }
+
+    /// 设置 OpenType 特性值，如 `set_font_feature("liga", "1")`
+    pub fn set_font_feature(&mut self, tag: &str, value: &str) {
+        self.font_features
+            .insert(tag.to_string(), value.to_string());
+    }
+
+    /// 获取可变字体轴的值
+    pub fn get_font_variation(&self, axis: &str) -> Option<&String> {
+        self.font_variations.get(axis)
+    }
+
+    /// 设置可变字体轴的值，如 `set_font_variation("wght", "450")`
+    pub fn set_font_variation(&mut self, axis: &str, value: &str) {
+        self.font_variations
+            .insert(axis.to_string(), value.to_string());
+    }
+
+    /// 设置令牌元数据，如 `set_metadata("font_size.md", metadata)`
+    pub fn set_metadata(&mut self, path: &str, metadata: TokenMetadata) {
+        self.metadata.insert(path.to_string(), metadata);
+    }
+
+    /// 生成基础排版 CSS
+    ///
+    /// 使用 `sans` 字体族、`md` 字号、`normal` 字重、行高和字间距生成规则，
+    /// 并在设置了 OpenType 特性或可变字体轴时输出 `font-feature-settings`、`font-variation-settings`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::FontSystem;
+    ///
+    /// let mut fonts = FontSystem::default();
+    /// fonts.set_font_feature("liga", "1");
+    ///
+    /// let css = fonts.to_css("body");
+    /// assert!(css.contains("font-feature-settings: \"liga\" 1;"));
+    /// ```
+    pub fn to_css(&self, selector: &str) -> String {
+        let mut declarations = Vec::new();
+
+        if let Some(family) = self.font_families.get("sans") {
+            declarations.push(format!("font-family: {};", family));
+        }
+        if let Some(size) = self.font_sizes.get("md") {
+            declarations.push(format!("font-size: {};", size));
+        }
+        if let Some(weight) = self.font_weights.get("normal") {
+            declarations.push(format!("font-weight: {};", weight));
+        }
+        if let Some(line_height) = self.line_heights.get("normal") {
+            declarations.push(format!("line-height: {};", line_height));
+        }
+        if let Some(spacing) = self.letter_spacings.get("normal") {
+            declarations.push(format!("letter-spacing: {};", spacing));
+        }
+        if !self.font_features.is_empty() {
+            declarations.push(format!(
+                "font-feature-settings: {};",
+                format_font_settings(&self.font_features)
+            ));
+        }
+        if !self.font_variations.is_empty() {
+            declarations.push(format!(
+                "font-variation-settings: {};",
+                format_font_settings(&self.font_variations)
+            ));
+        }
+
+        format!("{} {{\n  {}\n}}\n", selector, declarations.join("\n  "))
+    }
+
+    /// 创建 Ant Design 排版的字体系统
+    ///
+    /// 字号使用 px，标题字号存放在 `heading-1` 到 `heading-5` 中。
+    pub fn ant_design() -> Self {
+        let mut fonts = Self::default();
+
+        fonts.font_families.insert(
+            "sans".to_string(),
+            "-apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, 'Helvetica Neue', Arial, 'Noto Sans', sans-serif".to_string(),
+        );
+        fonts.font_families.insert(
+            "mono".to_string(),
+            "'SFMono-Regular', Consolas, 'Liberation Mono', Menlo, Courier, monospace".to_string(),
+        );
+
+        for (name, size) in [
+            ("sm", 12.0),
+            ("md", 14.0),
+            ("lg", 16.0),
+            ("xl", 20.0),
+            ("heading-1", 38.0),
+            ("heading-2", 30.0),
+            ("heading-3", 24.0),
+            ("heading-4", 20.0),
+            ("heading-5", 16.0),
+        ] {
+            fonts.font_sizes.insert(
+                name.to_string(),
+                DimensionValue::create(size, DimensionUnit::Px),
+            );
+        }
+
+        // Ant Design 正文行高为 22px / 14px
+        fonts.line_heights.insert(
+            "normal".to_string(),
+            DimensionValue::create(22.0 / 14.0, DimensionUnit::Em),
+        );
+
+        fonts
+    }
+
+    /// 生成 Ant Design 风格的排版变量
+    ///
+    /// 行高输出为无单位数值；缺失的字体族、字号或字重会被跳过。
+    pub fn to_ant_design_variables(&self) -> BTreeMap<String, String> {
+        let mut variables = BTreeMap::new();
+
+        for (name, key) in [("font-family", "sans"), ("font-family-code", "mono")] {
+            if let Some(family) = self.font_families.get(key) {
+                variables.insert(format!("--ant-{}", name), family.clone());
+            }
+        }
+
+        for (name, key) in [
+            ("font-size", "md"),
+            ("font-size-sm", "sm"),
+            ("font-size-lg", "lg"),
+            ("font-size-xl", "xl"),
+            ("font-size-heading-1", "heading-1"),
+            ("font-size-heading-2", "heading-2"),
+            ("font-size-heading-3", "heading-3"),
+            ("font-size-heading-4", "heading-4"),
+            ("font-size-heading-5", "heading-5"),
+        ] {
+            if let Some(size) = self.font_sizes.get(key) {
+                variables.insert(format!("--ant-{}", name), size.to_string());
+            }
+        }
+
+        for (name, key) in [
+            ("font-weight", "normal"),
+            ("font-weight-strong", "semibold"),
+        ] {
+            if let Some(weight) = self.font_weights.get(key) {
+                variables.insert(format!("--ant-{}", name), weight.to_string());
+            }
+        }
+
+        if let Some(line_height) = self.line_heights.get("normal") {
+            variables.insert(
+                "--ant-line-height".to_string(),
+                line_height.value.to_string(),
+            );
+        }
+
+        variables
+    }
+}
+
+/// 格式化为 `"liga" 1, "kern" 1` 形式
+fn format_font_settings(settings: &BTreeMap<String, String>) -> String {
+    settings
+        .iter()
+        .map(|(tag, value)| format!("\"{}\" {}", tag, value))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// 语义字体
@@ -93,6 +278,7 @@ pub struct TypographySystem {
     line_heights: Vec<DimensionValue>,
     font_weights: Vec<i32>,
     font_families: Vec<String>,
+    metadata: BTreeMap<String, TokenMetadata>,
 }
 
 impl TypographySystem {
@@ -103,9 +289,15 @@ impl TypographySystem {
             line_heights: Vec::new(),
             font_weights: Vec::new(),
             font_families: Vec::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
+    /// 设置令牌元数据，如 `set_metadata("font_size.0", metadata)`
+    pub fn set_metadata(&mut self, path: &str, metadata: TokenMetadata) {
+        self.metadata.insert(path.to_string(), metadata);
+    }
+
     /// 获取字体大小
     pub fn get_font_size(&self, path: &str) -> Option<String> {
         let index = path.parse::<usize>().ok()?;
@@ -166,6 +358,40 @@ impl TypographySystem {
     pub fn add_font_family(&mut self, family: String) {
         self.font_families.push(family);
     }
+
+    /// 生成多行截断 CSS
+    ///
+    /// 超过 `lines` 行的文本被截断并显示省略号。`lines` 为 0 时按 1 行处理。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::typography::TypographySystem;
+    ///
+    /// let css = TypographySystem::line_clamp_css(3);
+    /// assert!(css.contains("-webkit-line-clamp: 3;"));
+    /// ```
+    pub fn line_clamp_css(lines: u32) -> String {
+        let lines = lines.max(1);
+        format!(
+            "display: -webkit-box; -webkit-line-clamp: {lines}; line-clamp: {lines}; \
+             -webkit-box-orient: vertical; overflow: hidden;"
+        )
+    }
+
+    /// 生成单行截断 CSS，超出部分显示省略号
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::theme::systems::typography::TypographySystem;
+    ///
+    /// let css = TypographySystem::truncate_css();
+    /// assert!(css.contains("text-overflow: ellipsis;"));
+    /// ```
+    pub fn truncate_css() -> String {
+        "overflow: hidden; text-overflow: ellipsis; white-space: nowrap;".to_string()
+    }
 }
 
 impl TokenDefinitions for TypographySystem {
@@ -201,8 +427,8 @@ impl TokenDefinitions for TypographySystem {
         }
     }
 
-    fn get_metadata(&self, _path: &str) -> Option<TokenMetadata> {
-        None
+    fn get_metadata(&self, path: &str) -> Option<TokenMetadata> {
+        self.metadata.get(path).cloned()
     }
 }
 
@@ -325,6 +551,9 @@ impl Default for FontSystem {
             font_weights,
             line_heights,
             letter_spacings,
+            font_features: BTreeMap::new(),
+            font_variations: BTreeMap::new(),
+            metadata: BTreeMap::new(),
         }
     }
 }
@@ -569,6 +798,14 @@ impl TokenDefinitions for FontSystem {
                 .letter_spacings
                 .get(parts[1])
                 .map(|v| TokenValue::Dimension(v.clone())),
+            "font_feature" => self
+                .font_features
+                .get(parts[1])
+                .map(|s| TokenValue::String(s.clone())),
+            "font_variation" => self
+                .font_variations
+                .get(parts[1])
+                .map(|s| TokenValue::String(s.clone())),
             _ => None,
         }
     }
@@ -595,11 +832,91 @@ impl TokenDefinitions for FontSystem {
             ("letter_spacing", TokenValue::Dimension(v)) => {
                 self.letter_spacings.insert(parts[1].to_string(), v);
             }
+            ("font_feature", TokenValue::String(s)) => {
+                self.font_features.insert(parts[1].to_string(), s);
+            }
+            ("font_variation", TokenValue::String(s)) => {
+                self.font_variations.insert(parts[1].to_string(), s);
+            }
             _ => {}
         }
     }
 
-    fn get_metadata(&self, _path: &str) -> Option<TokenMetadata> {
-        None
+    fn get_metadata(&self, path: &str) -> Option<TokenMetadata> {
+        self.metadata.get(path).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_metadata_round_trip() {
+        let mut fonts = FontSystem::default();
+        assert!(fonts.get_metadata("font_size.md").is_none());
+
+        fonts.set_metadata(
+            "font_size.md",
+            TokenMetadata {
+                description: Some("正文默认字号".to_string()),
+                token_type: "dimension".to_string(),
+                ..TokenMetadata::default()
+            },
+        );
+        fonts.set_metadata(
+            "font_size.xs",
+            TokenMetadata {
+                deprecated: Some("请改用 font_size.sm".to_string()),
+                ..TokenMetadata::default()
+            },
+        );
+
+        let metadata = fonts.get_metadata("font_size.md").unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("正文默认字号"));
+        assert_eq!(metadata.deprecated, None);
+        assert_eq!(
+            fonts
+                .get_metadata("font_size.xs")
+                .unwrap()
+                .deprecated
+                .as_deref(),
+            Some("请改用 font_size.sm")
+        );
+
+        let mut typography = TypographySystem::new();
+        typography.set_metadata("font_size.0", metadata.clone());
+        assert_eq!(typography.get_metadata("font_size.0"), Some(metadata));
+    }
+
+    #[test]
+    fn test_font_features_in_css() {
+        let mut fonts = FontSystem::default();
+        assert!(!fonts.to_css("body").contains("font-feature-settings"));
+
+        fonts.set_font_feature("liga", "1");
+        fonts.set_font_feature("kern", "1");
+        fonts.set_font_variation("wght", "450");
+        assert_eq!(fonts.get_font_feature("liga"), Some(&"1".to_string()));
+
+        let css = fonts.to_css("body");
+        assert!(css.starts_with("body {"));
+        assert!(css.contains("font-size: 1rem;"));
+        assert!(css.contains("font-feature-settings: \"kern\" 1, \"liga\" 1;"));
+        assert!(css.contains("font-variation-settings: \"wght\" 450;"));
+    }
+
+    #[test]
+    fn test_text_truncation_css() {
+        let clamp = TypographySystem::line_clamp_css(2);
+        assert!(clamp.contains("display: -webkit-box;"));
+        assert!(clamp.contains("-webkit-line-clamp: 2;"));
+        assert!(clamp.contains("-webkit-box-orient: vertical;"));
+        assert!(clamp.contains("overflow: hidden;"));
+        assert!(TypographySystem::line_clamp_css(0).contains("-webkit-line-clamp: 1;"));
+
+        let truncate = TypographySystem::truncate_css();
+        assert!(truncate.contains("text-overflow: ellipsis;"));
+        assert!(truncate.contains("white-space: nowrap;"));
     }
 }
@@ -1,8 +1,29 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::theme::core::token::definitions::ThemeVariant;
 use crate::theme::core::token::simple_system::TokenSystem;
+use crate::theme::systems::{ColorSystem, FontSystem, SpacingSystem};
+
+/// Ant Design 中不属于颜色、排版和间距系统的令牌
+const ANT_DESIGN_MISC_VARIABLES: &[(&str, &str)] = &[
+    ("--ant-border-radius", "6px"),
+    ("--ant-border-radius-sm", "4px"),
+    ("--ant-border-radius-lg", "8px"),
+    ("--ant-line-width", "1px"),
+    ("--ant-line-type", "solid"),
+    (
+        "--ant-box-shadow",
+        "0 6px 16px 0 rgba(0, 0, 0, 0.08), 0 3px 6px -4px rgba(0, 0, 0, 0.12), 0 9px 28px 8px rgba(0, 0, 0, 0.05)",
+    ),
+    (
+        "--ant-box-shadow-secondary",
+        "0 6px 16px 0 rgba(0, 0, 0, 0.08), 0 3px 6px -4px rgba(0, 0, 0, 0.12), 0 9px 28px 8px rgba(0, 0, 0, 0.05)",
+    ),
+    ("--ant-opacity-disabled", "0.25"),
+    ("--ant-z-index-base", "0"),
+    ("--ant-z-index-popup", "1000"),
+];
 
 /// 主题定义
 ///
@@ -83,6 +104,41 @@ impl Theme {
         }
     }
 
+    /// 创建完整的 Ant Design 主题
+    ///
+    /// 由颜色、排版和间距系统的 Ant Design 预设生成全部 `--ant-*` 变量，
+    /// 无需再手写 `:root` 变量字符串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::theme_types::Theme;
+    ///
+    /// let mut theme = Theme::ant_design_full();
+    /// let css = theme.to_css_variables();
+    /// assert!(css.contains("--ant-color-primary: #1677ff;"));
+    /// ```
+    pub fn ant_design_full() -> Self {
+        let mut theme = Self::new("ant-design");
+
+        theme
+            .custom_variables
+            .extend(ColorSystem::ant_design().to_ant_design_variables());
+        theme
+            .custom_variables
+            .extend(FontSystem::ant_design().to_ant_design_variables());
+        theme
+            .custom_variables
+            .extend(SpacingSystem::new().to_ant_design_variables());
+        theme.custom_variables.extend(
+            ANT_DESIGN_MISC_VARIABLES
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+        );
+
+        theme
+    }
+
     /// 设置主题模式
     ///
     /// # Arguments
@@ -174,6 +230,81 @@ impl Theme {
             .insert(format!("--color-{}", name), value.to_string());
     }
 
+    /// 合并主题
+    ///
+    /// 以当前主题为基础，深度合并覆盖主题的令牌和自定义变量，叶子级别上覆盖主题的值优先。
+    /// 合并结果保留基础主题的名称和模式；覆盖主题的映射为空时结果与基础主题一致。
+    ///
+    /// # Arguments
+    ///
+    /// * `overlay` - 覆盖主题
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::theme_types::Theme;
+    ///
+    /// let mut base = Theme::new("base");
+    /// base.add_color("primary", "#1890ff");
+    /// base.add_color("success", "#52c41a");
+    ///
+    /// let mut brand = Theme::new("brand");
+    /// brand.add_color("primary", "#ff4d4f");
+    ///
+    /// let merged = base.merge(&brand);
+    /// assert_eq!(merged.name, "base");
+    /// assert_eq!(merged.custom_variables["--color-primary"], "#ff4d4f");
+    /// assert_eq!(merged.custom_variables["--color-success"], "#52c41a");
+    /// ```
+    pub fn merge(&self, overlay: &Theme) -> Theme {
+        let mut merged = self.clone();
+
+        merged.token_system.variables.extend(
+            overlay
+                .token_system
+                .variables
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        merged.custom_variables.extend(
+            overlay
+                .custom_variables
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+
+        merged
+    }
+
+    /// 按内容比较两个主题
+    ///
+    /// 比较主题模式、令牌变体、令牌变量和自定义变量，忽略主题名称和插入顺序；
+    /// 自定义变量名按 `to_css_variables` 的规则补全 `--` 前缀后比较，
+    /// 因此 `primary` 与 `--primary` 视为同一变量。适用于主题比对和测试断言。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::theme_types::Theme;
+    ///
+    /// let a = Theme::new("a")
+    ///     .with_custom_variable("--radius", "4px")
+    ///     .with_custom_variable("--gap", "8px");
+    /// let b = Theme::new("b")
+    ///     .with_custom_variable("gap", "8px")
+    ///     .with_custom_variable("--radius", "4px");
+    ///
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &Theme) -> bool {
+        self.mode == other.mode
+            && self.token_system.variant == other.token_system.variant
+            && sorted_variables(&self.token_system.variables)
+                == sorted_variables(&other.token_system.variables)
+            && normalized_custom_variables(&self.custom_variables)
+                == normalized_custom_variables(&other.custom_variables)
+    }
+
     /// 生成CSS变量
     ///
     /// 将主题转换为CSS变量字符串
@@ -204,4 +335,188 @@ impl Theme {
 
         css
     }
+
+    /// 导出令牌为 JSON
+    ///
+    /// 只包含令牌系统中的变量，不包含主题模式和自定义变量，键按字母顺序排列，便于设计工具消费和比对。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use css_in_rust::theme::theme_types::Theme;
+    ///
+    /// let mut theme = Theme::new("brand");
+    /// theme.add_color("primary", "#3366ff");
+    ///
+    /// let json = theme.tokens_to_json();
+    /// let restored = Theme::from_tokens_json("brand-copy", &json).unwrap();
+    /// assert_eq!(restored.token_system.variables, theme.token_system.variables);
+    /// ```
+    pub fn tokens_to_json(&self) -> String {
+        let sorted: BTreeMap<&String, &String> = self.token_system.variables.iter().collect();
+        serde_json::to_string_pretty(&sorted).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// 从令牌 JSON 创建主题
+    ///
+    /// JSON 必须是字符串到字符串的对象，例如 `tokens_to_json` 的输出；
+    /// 新主题使用默认模式，且没有自定义变量。
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - 主题名称
+    /// * `json` - 令牌 JSON
+    pub fn from_tokens_json(name: impl Into<String>, json: &str) -> Result<Self, String> {
+        let variables: HashMap<String, String> =
+            serde_json::from_str(json).map_err(|e| format!("令牌 JSON 解析失败: {}", e))?;
+
+        let mut theme = Self::new(name);
+        theme.token_system.variables = variables;
+        Ok(theme)
+    }
+}
+
+/// 按变量名排序的变量视图
+fn sorted_variables(variables: &HashMap<String, String>) -> BTreeMap<&str, &str> {
+    variables
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.trim()))
+        .collect()
+}
+
+/// 补全 `--` 前缀后的自定义变量视图
+fn normalized_custom_variables(variables: &HashMap<String, String>) -> BTreeMap<String, &str> {
+    variables
+        .iter()
+        .map(|(name, value)| {
+            let name = if name.starts_with("--") {
+                name.clone()
+            } else {
+                format!("--{}", name)
+            };
+            (name, value.trim())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ant_design_full_emits_ant_variables() {
+        let mut theme = Theme::ant_design_full();
+        let css = theme.to_css_variables();
+
+        assert!(css.contains("--ant-color-primary: #1677ff;"));
+        assert!(css.contains("--ant-color-primary-bg: rgba(22, 119, 255, 0.1);"));
+        assert!(css.contains("--ant-padding: 16px;"));
+        assert!(css.contains("--ant-margin-lg: 24px;"));
+        assert!(css.contains("--ant-font-size: 14px;"));
+        assert!(css.contains("--ant-line-height: 1.5714285714285714;"));
+        assert!(css.contains("--ant-border-radius: 6px;"));
+    }
+
+    #[test]
+    fn test_merge_overrides_only_overlay_tokens() {
+        let mut base = Theme::new("base").with_custom_variable("--radius", "4px");
+        base.add_color("primary", "#1890ff");
+        base.add_color("success", "#52c41a");
+        base.token_system
+            .variables
+            .insert("spacing-md".to_string(), "16px".to_string());
+
+        let mut brand = Theme::new("brand");
+        brand.add_color("primary", "#ff4d4f");
+
+        let merged = base.merge(&brand);
+
+        assert_eq!(merged.name, "base");
+        assert_eq!(merged.mode, base.mode);
+        assert_eq!(
+            merged.token_system.variables.get("primary"),
+            Some(&"#ff4d4f".to_string())
+        );
+        assert_eq!(
+            merged.token_system.variables.get("success"),
+            Some(&"#52c41a".to_string())
+        );
+        assert_eq!(
+            merged.token_system.variables.get("spacing-md"),
+            Some(&"16px".to_string())
+        );
+        assert_eq!(merged.custom_variables["--color-primary"], "#ff4d4f");
+        assert_eq!(merged.custom_variables["--color-success"], "#52c41a");
+        assert_eq!(merged.custom_variables["--radius"], "4px");
+    }
+
+    #[test]
+    fn test_merge_with_empty_overlay_is_passthrough() {
+        let mut base = Theme::new("base").with_custom_variable("--radius", "4px");
+        base.add_color("primary", "#1890ff");
+
+        assert_eq!(base.merge(&Theme::new("empty")), base);
+    }
+
+    #[test]
+    fn test_tokens_json_round_trip() {
+        let mut theme = Theme::new("brand")
+            .with_mode(ThemeVariant::Dark)
+            .with_custom_variable("--radius", "4px");
+        theme.add_color("primary", "#1677ff");
+        theme.add_color("success", "#52c41a");
+
+        let json = theme.tokens_to_json();
+        assert!(!json.contains("--radius"));
+
+        let restored = Theme::from_tokens_json("restored", &json).unwrap();
+        assert_eq!(restored.name, "restored");
+        assert_eq!(restored.mode, ThemeVariant::default());
+        assert!(restored.custom_variables.is_empty());
+        assert_eq!(
+            restored.token_system.variables,
+            theme.token_system.variables
+        );
+        assert_eq!(restored.tokens_to_json(), json);
+
+        assert!(Theme::from_tokens_json("bad", "[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_semantically_eq_ignores_insertion_order() {
+        let variables = [
+            ("--radius", "4px"),
+            ("--gap", "8px"),
+            ("--shadow", "0 1px 2px rgba(0, 0, 0, 0.1)"),
+            ("--font", "14px"),
+        ];
+
+        let mut forward = Theme::new("forward");
+        for (name, value) in variables {
+            forward = forward.with_custom_variable(name, value);
+            forward.add_color(name.trim_start_matches("--"), value);
+        }
+
+        let mut backward = Theme::new("backward");
+        for (name, value) in variables.iter().rev() {
+            backward = backward.with_custom_variable(*name, *value);
+            backward.add_color(name.trim_start_matches("--"), *value);
+        }
+
+        assert!(forward.semantically_eq(&backward));
+        assert!(backward.semantically_eq(&forward));
+    }
+
+    #[test]
+    fn test_semantically_eq_detects_differences() {
+        let base = Theme::new("base").with_custom_variable("--radius", "4px");
+
+        assert!(base.semantically_eq(&Theme::new("other").with_custom_variable("radius", "4px")));
+        assert!(!base.semantically_eq(&Theme::new("base").with_custom_variable("--radius", "6px")));
+        assert!(!base.semantically_eq(&base.clone().with_mode(ThemeVariant::Dark)));
+
+        let mut with_token = base.clone();
+        with_token.add_color("primary", "#1677ff");
+        assert!(!base.semantically_eq(&with_token));
+    }
 }
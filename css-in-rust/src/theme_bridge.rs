@@ -4,10 +4,12 @@
 //! 提供主题变量注入、动态切换和类型安全的主题 API
 
 use super::theme::{
-    core::css::variables::{CssVariableInjector, InjectionStrategy},
+    core::css::variables::{
+        CssVariableInjector, CssVariableUtils, InjectionStrategy, RejectedVariable,
+    },
     Theme, ThemeVariant,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// 主题桥接器
 ///
@@ -25,6 +27,13 @@ pub struct ThemeBridge {
     auto_sync: bool,
 }
 
+/// 主题切换结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThemeSwitchResult {
+    /// 因变量名或值无效而未注入的变量
+    pub rejected_variables: Vec<RejectedVariable>,
+}
+
 /// 实现 PartialEq
 impl PartialEq for ThemeBridge {
     fn eq(&self, other: &Self) -> bool {
@@ -59,7 +68,28 @@ impl ThemeBridge {
         injection_strategy: InjectionStrategy,
         auto_sync: bool,
     ) -> Self {
-        let css_injector = CssVariableInjector::new(":root").with_strategy(injection_strategy);
+        Self::new_scoped(initial_theme, injection_strategy, auto_sync, ":root")
+    }
+
+    /// 创建作用于指定选择器的主题桥接器
+    ///
+    /// 主题变量注入到 `scope_selector`（如 `.my-widget`）而不是 `:root`，
+    /// 适用于嵌入到宿主页面的组件，避免影响宿主页面的样式。
+    ///
+    /// # 参数
+    ///
+    /// * `initial_theme` - 初始主题
+    /// * `injection_strategy` - CSS注入策略
+    /// * `auto_sync` - 是否自动同步变量
+    /// * `scope_selector` - 变量作用的选择器
+    pub fn new_scoped(
+        initial_theme: Theme,
+        injection_strategy: InjectionStrategy,
+        auto_sync: bool,
+        scope_selector: impl Into<String>,
+    ) -> Self {
+        let css_injector =
+            CssVariableInjector::new(scope_selector).with_strategy(injection_strategy);
 
         let mut bridge = Self {
             current_theme: initial_theme,
@@ -81,25 +111,35 @@ impl ThemeBridge {
         &self.current_theme
     }
 
+    /// 获取变量作用的选择器
+    pub fn scope_selector(&self) -> &str {
+        self.css_injector.get_target_selector()
+    }
+
+    /// 获取最近一次注入的变量 CSS 块
+    pub fn injected_css(&self) -> Option<&str> {
+        self.css_injector.get_current_css().map(String::as_str)
+    }
+
     /// 设置新主题
     ///
     /// # 参数
     ///
     /// * `theme` - 新主题
     ///
-    /// 如果启用了自动同步，会自动更新样式
-    pub fn set_theme(&mut self, theme: Theme) -> Result<(), ThemeBridgeError> {
+    /// 如果启用了自动同步，会自动更新样式，并在结果中返回被拒绝的变量
+    pub fn set_theme(&mut self, theme: Theme) -> Result<ThemeSwitchResult, ThemeBridgeError> {
         self.current_theme = theme;
 
         if self.auto_sync {
-            self.sync_theme_variables()?;
+            return self.sync_theme_variables();
         }
 
-        Ok(())
+        Ok(ThemeSwitchResult::default())
     }
 
     /// 切换明暗模式
-    pub fn toggle_mode(&mut self) -> Result<(), ThemeBridgeError> {
+    pub fn toggle_mode(&mut self) -> Result<ThemeSwitchResult, ThemeBridgeError> {
         let theme = self.current_theme.mode;
         let mut new_theme = self.current_theme.clone();
 
@@ -110,30 +150,49 @@ impl ThemeBridge {
 
     /// 同步主题变量到 CSS
     ///
-    /// 将当前主题的设计令牌转换为 CSS 变量并注入到文档中
-    pub fn sync_theme_variables(&mut self) -> Result<(), ThemeBridgeError> {
+    /// 将当前主题的设计令牌转换为 CSS 变量并注入到文档中。
+    /// 名称或值无效的变量不会注入，并在结果中返回。
+    pub fn sync_theme_variables(&mut self) -> Result<ThemeSwitchResult, ThemeBridgeError> {
         // 生成 CSS 变量
         let css_variables = self.current_theme.to_css_variables();
 
         // 解析为变量映射
-        let var_map = self.parse_css_variables(&css_variables);
+        let mut var_map = self.parse_css_variables(&css_variables);
 
         // 只有变量发生变化时才重新注入
-        if var_map != self.variable_cache {
-            self.css_injector
-                .inject_css_variables(&var_map)
-                .map_err(|e| ThemeBridgeError::InjectionFailed(e.to_string()))?;
+        if var_map == self.variable_cache {
+            return Ok(ThemeSwitchResult::default());
+        }
 
-            self.variable_cache = var_map;
+        let rejected_variables = self.inject_variables(&var_map)?;
+        for rejected in &rejected_variables {
+            var_map.remove(rejected.name.trim_start_matches("--"));
         }
+        self.variable_cache = var_map;
 
-        Ok(())
+        Ok(ThemeSwitchResult { rejected_variables })
+    }
+
+    /// 以 `--name` 形式注入变量，返回被拒绝的变量
+    fn inject_variables(
+        &mut self,
+        variables: &HashMap<String, String>,
+    ) -> Result<Vec<RejectedVariable>, ThemeBridgeError> {
+        let css_variables: HashMap<String, String> = variables
+            .iter()
+            .map(|(name, value)| (format!("--{}", name), value.clone()))
+            .collect();
+
+        self.css_injector
+            .inject_css_variables(&css_variables)
+            .map(|result| result.rejected)
+            .map_err(|e| ThemeBridgeError::InjectionFailed(e.to_string()))
     }
 
     /// 获取CSS变量
     pub fn get_css_variables(&mut self) -> String {
         // 简化实现，直接返回一个基本的CSS变量集
-        let mut css = String::from(":root {\n");
+        let mut css = format!("{} {{\n", self.scope_selector());
         css.push_str("  --color-primary: #1890ff;\n");
         css.push_str("  --color-success: #52c41a;\n");
         css.push_str("  --color-warning: #faad14;\n");
@@ -171,9 +230,12 @@ impl ThemeBridge {
         let mut css_variables = HashMap::new();
         css_variables.insert(variable_name.to_string(), value.to_string());
 
-        self.css_injector
-            .inject_css_variables(&css_variables)
-            .map_err(|e| ThemeBridgeError::InjectionFailed(e.to_string()))?;
+        if !self.inject_variables(&css_variables)?.is_empty() {
+            return Err(ThemeBridgeError::InvalidVariableValue(format!(
+                "--{}: {}",
+                variable_name, value
+            )));
+        }
 
         self.variable_cache
             .insert(variable_name.to_string(), value.to_string());
@@ -203,11 +265,14 @@ impl ThemeBridge {
             css_variables.insert(name.clone(), value.clone());
         }
 
-        self.css_injector
-            .inject_css_variables(&css_variables)
-            .map_err(|e| ThemeBridgeError::InjectionFailed(e.to_string()))?;
+        let rejected = self.inject_variables(&css_variables)?;
 
-        self.variable_cache.extend(variables);
+        self.variable_cache
+            .extend(variables.into_iter().filter(|(name, _)| {
+                !rejected
+                    .iter()
+                    .any(|r| r.name.trim_start_matches("--") == name)
+            }));
 
         Ok(())
     }
@@ -215,7 +280,7 @@ impl ThemeBridge {
     /// 清除所有自定义变量
     pub fn clear_custom_variables(&mut self) -> Result<(), ThemeBridgeError> {
         // 重新同步主题变量，这会覆盖所有自定义变量
-        self.sync_theme_variables()
+        self.sync_theme_variables().map(|_| ())
     }
 
     /// 检查是否为暗色模式
@@ -235,21 +300,10 @@ impl ThemeBridge {
 
     /// 解析 CSS 变量字符串为映射表
     fn parse_css_variables(&self, css: &str) -> HashMap<String, String> {
-        let mut variables = HashMap::new();
-
-        // 简单的 CSS 变量解析
-        for line in css.lines() {
-            let line = line.trim();
-            if line.starts_with("--") && line.contains(':') {
-                if let Some((name, value)) = line.split_once(':') {
-                    let name = name.trim().trim_start_matches("--");
-                    let value = value.trim().trim_end_matches(';');
-                    variables.insert(name.to_string(), value.to_string());
-                }
-            }
-        }
-
-        variables
+        CssVariableUtils::parse_css_variables(css)
+            .into_iter()
+            .map(|(name, value)| (name.trim_start_matches("--").to_string(), value))
+            .collect()
     }
 }
 
@@ -264,6 +318,8 @@ pub enum ThemeBridgeError {
     VariableNotFound(String),
     /// 无效的变量值
     InvalidVariableValue(String),
+    /// 主题未注册
+    ThemeNotFound(String),
 }
 
 impl std::fmt::Display for ThemeBridgeError {
@@ -281,6 +337,9 @@ impl std::fmt::Display for ThemeBridgeError {
             ThemeBridgeError::InvalidVariableValue(value) => {
                 write!(f, "Invalid variable value: {}", value)
             }
+            ThemeBridgeError::ThemeNotFound(name) => {
+                write!(f, "Theme not registered: {}", name)
+            }
         }
     }
 }
@@ -292,6 +351,8 @@ impl std::error::Error for ThemeBridgeError {}
 /// 提供全局访问主题桥接器的便捷方法
 pub struct GlobalThemeBridge {
     bridge: std::sync::Mutex<Option<ThemeBridge>>,
+    /// 按名称注册的主题
+    themes: std::sync::Mutex<BTreeMap<String, Theme>>,
 }
 
 impl GlobalThemeBridge {
@@ -299,6 +360,41 @@ impl GlobalThemeBridge {
     pub const fn new() -> Self {
         Self {
             bridge: std::sync::Mutex::new(None),
+            themes: std::sync::Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// 按主题名称注册主题，同名主题会被替换
+    pub fn register_theme(&self, theme: Theme) {
+        let mut themes = self.themes.lock().unwrap();
+        themes.insert(theme.name.clone(), theme);
+    }
+
+    /// 获取已注册的主题名称（按名称排序）
+    pub fn theme_names(&self) -> Vec<String> {
+        self.themes.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// 切换到已注册的主题
+    ///
+    /// 通过 [`ThemeBridge::set_theme`] 切换，只重新注入发生变化的变量。
+    /// 桥接器尚未初始化时，以该主题、`Replace` 策略和自动同步初始化。
+    pub fn switch_theme(&self, name: &str) -> Result<ThemeSwitchResult, ThemeBridgeError> {
+        let theme = self
+            .themes
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ThemeBridgeError::ThemeNotFound(name.to_string()))?;
+
+        let mut bridge_guard = self.bridge.lock().unwrap();
+        match bridge_guard.as_mut() {
+            Some(bridge) => bridge.set_theme(theme),
+            None => {
+                *bridge_guard = Some(ThemeBridge::new(theme, InjectionStrategy::Replace, true));
+                Ok(ThemeSwitchResult::default())
+            }
         }
     }
 
@@ -308,9 +404,25 @@ impl GlobalThemeBridge {
         theme: Theme,
         injection_strategy: InjectionStrategy,
         auto_sync: bool,
+    ) -> Result<(), ThemeBridgeError> {
+        self.initialize_scoped(theme, injection_strategy, auto_sync, ":root")
+    }
+
+    /// 初始化作用于指定选择器的全局主题桥接器
+    pub fn initialize_scoped(
+        &self,
+        theme: Theme,
+        injection_strategy: InjectionStrategy,
+        auto_sync: bool,
+        scope_selector: &str,
     ) -> Result<(), ThemeBridgeError> {
         let mut bridge_guard = self.bridge.lock().unwrap();
-        *bridge_guard = Some(ThemeBridge::new(theme, injection_strategy, auto_sync));
+        *bridge_guard = Some(ThemeBridge::new_scoped(
+            theme,
+            injection_strategy,
+            auto_sync,
+            scope_selector,
+        ));
         Ok(())
     }
 
@@ -359,6 +471,32 @@ pub fn init_global_theme_bridge(
     GLOBAL_THEME_BRIDGE.initialize(theme, injection_strategy, auto_sync)
 }
 
+/// 初始化作用于指定选择器的全局主题桥接器
+///
+/// 与 [`init_global_theme_bridge`] 相同，但主题变量注入到 `scope_selector` 而不是 `:root`。
+///
+/// # 示例
+///
+/// ```rust
+/// use css_in_rust::theme::core::css::variables::InjectionStrategy;
+/// use css_in_rust::theme::Theme;
+/// use css_in_rust::theme_bridge::{init_global_theme_bridge_scoped, with_global_theme_bridge};
+///
+/// init_global_theme_bridge_scoped(Theme::default(), InjectionStrategy::Replace, true, ".my-widget")
+///     .unwrap();
+///
+/// let scope = with_global_theme_bridge(|bridge| bridge.scope_selector().to_string());
+/// assert_eq!(scope.as_deref(), Some(".my-widget"));
+/// ```
+pub fn init_global_theme_bridge_scoped(
+    theme: Theme,
+    injection_strategy: InjectionStrategy,
+    auto_sync: bool,
+    scope_selector: &str,
+) -> Result<(), ThemeBridgeError> {
+    GLOBAL_THEME_BRIDGE.initialize_scoped(theme, injection_strategy, auto_sync, scope_selector)
+}
+
 /// 使用全局主题桥接器
 ///
 /// # 示例
@@ -407,10 +545,36 @@ pub fn get_current_css_variables() -> Option<String> {
 ///     }
 /// }
 /// ```
-pub fn toggle_global_theme_mode() -> Option<Result<(), ThemeBridgeError>> {
+pub fn toggle_global_theme_mode() -> Option<Result<ThemeSwitchResult, ThemeBridgeError>> {
     with_global_theme_bridge(|bridge| bridge.toggle_mode())
 }
 
+/// 向全局主题桥接器注册主题
+///
+/// 主题按名称注册，之后可通过 [`switch_global_theme`] 切换。
+///
+/// # 示例
+///
+/// ```rust
+/// use css_in_rust::theme::{Theme, ThemeVariant};
+/// use css_in_rust::theme_bridge::{register_theme, switch_global_theme};
+///
+/// register_theme(Theme::new("docs-light"));
+/// register_theme(Theme::new("docs-dark").with_mode(ThemeVariant::Dark));
+///
+/// switch_global_theme("docs-dark").unwrap();
+/// ```
+pub fn register_theme(theme: Theme) {
+    GLOBAL_THEME_BRIDGE.register_theme(theme)
+}
+
+/// 按名称切换全局主题
+///
+/// 主题需先通过 [`register_theme`] 注册，否则返回 [`ThemeBridgeError::ThemeNotFound`]。
+pub fn switch_global_theme(name: &str) -> Result<ThemeSwitchResult, ThemeBridgeError> {
+    GLOBAL_THEME_BRIDGE.switch_theme(name)
+}
+
 /// 设置全局自定义变量
 ///
 /// # 参数
@@ -436,3 +600,82 @@ pub fn set_global_custom_variable(
 ) -> Option<Result<(), ThemeBridgeError>> {
     with_global_theme_bridge(|bridge| bridge.set_custom_variable(variable_name, value))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::core::css::variables::VariableRejectReason;
+
+    #[test]
+    fn test_set_theme_reports_rejected_variables() {
+        let mut bridge = ThemeBridge::new(Theme::default(), InjectionStrategy::Replace, true);
+        let theme = Theme::new("brand")
+            .with_custom_variable("--brand-color", "#722ed1")
+            .with_custom_variable("--brand-shadow", "");
+
+        let result = bridge.set_theme(theme).unwrap();
+
+        assert_eq!(result.rejected_variables.len(), 1);
+        assert_eq!(result.rejected_variables[0].name, "--brand-shadow");
+        assert_eq!(
+            result.rejected_variables[0].reason,
+            VariableRejectReason::EmptyValue
+        );
+        assert_eq!(
+            bridge.get_variable("brand-color").as_deref(),
+            Some("#722ed1")
+        );
+        assert_eq!(bridge.get_variable("brand-shadow"), None);
+    }
+
+    #[test]
+    fn test_scoped_bridge_targets_selector() {
+        let theme = Theme::new("widget").with_custom_variable("--widget-accent", "#13c2c2");
+        let bridge = ThemeBridge::new_scoped(theme, InjectionStrategy::Replace, true, ".my-widget");
+
+        assert_eq!(bridge.scope_selector(), ".my-widget");
+        let css = bridge.injected_css().unwrap();
+        assert!(css.starts_with(".my-widget {\n"));
+        assert!(!css.contains(":root"));
+        assert!(css.contains("--widget-accent: #13c2c2;"));
+
+        let theme = Theme::new("page").with_custom_variable("--page-accent", "#13c2c2");
+        let root = ThemeBridge::new(theme, InjectionStrategy::Replace, true);
+        assert!(root.injected_css().unwrap().starts_with(":root {\n"));
+    }
+
+    #[test]
+    fn test_switch_registered_themes() {
+        let global = GlobalThemeBridge::new();
+        global.register_theme(
+            Theme::new("switch-light").with_custom_variable("--surface", "#ffffff"),
+        );
+        global.register_theme(
+            Theme::new("switch-dark")
+                .with_mode(ThemeVariant::Dark)
+                .with_custom_variable("--surface", "#141414"),
+        );
+        assert_eq!(global.theme_names(), vec!["switch-dark", "switch-light"]);
+
+        let surface = |global: &GlobalThemeBridge| {
+            global
+                .with_bridge(|bridge| bridge.get_variable("surface"))
+                .flatten()
+        };
+
+        global.switch_theme("switch-light").unwrap();
+        assert_eq!(surface(&global).as_deref(), Some("#ffffff"));
+
+        global.switch_theme("switch-dark").unwrap();
+        assert_eq!(surface(&global).as_deref(), Some("#141414"));
+        assert!(global.with_bridge(|bridge| bridge.is_dark_mode()).unwrap());
+
+        global.switch_theme("switch-light").unwrap();
+        assert_eq!(surface(&global).as_deref(), Some("#ffffff"));
+
+        assert_eq!(
+            global.switch_theme("missing"),
+            Err(ThemeBridgeError::ThemeNotFound("missing".to_string()))
+        );
+    }
+}
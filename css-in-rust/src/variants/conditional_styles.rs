@@ -229,6 +229,40 @@ impl ConditionType {
     }
 }
 
+impl ConditionValue {
+    /// 将 JSON 属性映射转换为条件值映射
+    pub fn from_props(
+        props: &HashMap<String, serde_json::Value>,
+    ) -> HashMap<String, ConditionValue> {
+        props
+            .iter()
+            .map(|(key, value)| (key.clone(), ConditionValue::from(value)))
+            .collect()
+    }
+}
+
+impl From<&serde_json::Value> for ConditionValue {
+    fn from(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => ConditionValue::Null,
+            serde_json::Value::Bool(b) => ConditionValue::Boolean(*b),
+            serde_json::Value::Number(n) => n
+                .as_f64()
+                .map(ConditionValue::Number)
+                .unwrap_or(ConditionValue::Null),
+            serde_json::Value::String(s) => ConditionValue::String(s.clone()),
+            serde_json::Value::Array(arr) => {
+                ConditionValue::Array(arr.iter().map(ConditionValue::from).collect())
+            }
+            serde_json::Value::Object(obj) => ConditionValue::Object(
+                obj.iter()
+                    .map(|(k, v)| (k.clone(), ConditionValue::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 impl ConditionRule {
     /// 评估条件规则
     pub fn evaluate(&self, props: &HashMap<String, ConditionValue>) -> bool {
@@ -246,6 +280,54 @@ impl ConditionRule {
 }
 
 impl ConditionCombination {
+    /// 创建相等条件，如 `props.variant == "ghost"`
+    pub fn eq(property: &str, value: ConditionValue) -> Self {
+        Self::rule(property, ConditionType::Equals, value)
+    }
+
+    /// 创建大于条件，如 `props.count > 3`
+    pub fn gt(property: &str, value: f64) -> Self {
+        Self::rule(
+            property,
+            ConditionType::GreaterThan,
+            ConditionValue::Number(value),
+        )
+    }
+
+    /// 创建小于条件
+    pub fn lt(property: &str, value: f64) -> Self {
+        Self::rule(
+            property,
+            ConditionType::LessThan,
+            ConditionValue::Number(value),
+        )
+    }
+
+    /// 创建 AND 组合
+    pub fn and(conditions: Vec<ConditionCombination>) -> Self {
+        ConditionCombination::And(conditions)
+    }
+
+    /// 创建 OR 组合
+    pub fn or(conditions: Vec<ConditionCombination>) -> Self {
+        ConditionCombination::Or(conditions)
+    }
+
+    /// 创建单条件规则
+    fn rule(property: &str, condition_type: ConditionType, value: ConditionValue) -> Self {
+        ConditionCombination::Single(ConditionRule {
+            property: property.to_string(),
+            condition_type,
+            expected_value: value,
+            negate: false,
+        })
+    }
+
+    /// 基于组件 JSON 属性评估条件组合
+    pub fn evaluate_props(&self, props: &HashMap<String, serde_json::Value>) -> bool {
+        self.evaluate(&ConditionValue::from_props(props))
+    }
+
     /// 评估条件组合
     pub fn evaluate(&self, props: &HashMap<String, ConditionValue>) -> bool {
         match self {
@@ -261,6 +343,13 @@ impl ConditionCombination {
     }
 }
 
+impl ConditionalStyle {
+    /// 检查条件样式是否在给定的组件属性下生效
+    pub fn matches_props(&self, props: &HashMap<String, serde_json::Value>) -> bool {
+        self.enabled && self.condition.evaluate_props(props)
+    }
+}
+
 impl ConditionalStyleManager {
     /// 创建新的条件样式管理器
     pub fn new() -> Self {
@@ -675,6 +764,49 @@ mod tests {
         assert!(combination.evaluate(&props));
     }
 
+    #[test]
+    fn test_json_props_string_condition() {
+        let condition =
+            ConditionCombination::eq("variant", ConditionValue::String("ghost".to_string()));
+
+        let mut props = HashMap::new();
+        props.insert("variant".to_string(), serde_json::json!("ghost"));
+        assert!(condition.evaluate_props(&props));
+
+        props.insert("variant".to_string(), serde_json::json!("solid"));
+        assert!(!condition.evaluate_props(&props));
+
+        props.remove("variant");
+        assert!(!condition.evaluate_props(&props));
+    }
+
+    #[test]
+    fn test_json_props_numeric_condition() {
+        let condition = ConditionCombination::or(vec![
+            ConditionCombination::gt("count", 3.0),
+            ConditionCombination::and(vec![
+                ConditionCombination::lt("count", 1.0),
+                ConditionCombination::eq("empty_hint", ConditionValue::Boolean(true)),
+            ]),
+        ]);
+
+        let mut props = HashMap::new();
+        props.insert("count".to_string(), serde_json::json!(5));
+        assert!(condition.evaluate_props(&props));
+
+        props.insert("count".to_string(), serde_json::json!(3));
+        assert!(!condition.evaluate_props(&props));
+
+        props.insert("count".to_string(), serde_json::json!(0.5));
+        props.insert("empty_hint".to_string(), serde_json::json!(true));
+        assert!(condition.evaluate_props(&props));
+
+        // 类型不匹配时数值比较不成立
+        props.insert("count".to_string(), serde_json::json!("5"));
+        props.insert("empty_hint".to_string(), serde_json::json!(false));
+        assert!(!condition.evaluate_props(&props));
+    }
+
     #[test]
     fn test_conditional_style_manager() {
         let mut manager = ConditionalStyleManager::new();
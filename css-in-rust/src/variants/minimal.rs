@@ -3,7 +3,7 @@
 //! 提供基础的变体功能，避免复杂的依赖关系
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// 简化的变体类型
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -90,6 +90,68 @@ impl SimpleVariantManager {
         Ok(applied_styles)
     }
 
+    /// 解析静态变体的类名
+    ///
+    /// 不生成 CSS，只返回变体对应的类名字符串，适用于 CSS 已经存在、只需要类名的场景。
+    /// 未显式指定的变体类型使用配置中的默认值，配置中不存在的变体会被忽略。
+    /// 类名按变体类型排序后以空格连接，相同输入总是得到相同结果。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::variants::{SimpleVariantManager, VariantConfig, VariantStyle};
+    /// use std::collections::HashMap;
+    ///
+    /// let style = VariantStyle {
+    ///     properties: HashMap::new(),
+    ///     pseudo_classes: HashMap::new(),
+    ///     priority: 10,
+    /// };
+    /// let config = VariantConfig {
+    ///     size: HashMap::from([("lg".to_string(), style.clone())]),
+    ///     color: HashMap::from([("primary".to_string(), style)]),
+    ///     state: HashMap::new(),
+    ///     responsive: HashMap::new(),
+    ///     defaults: HashMap::from([("size".to_string(), "lg".to_string())]),
+    ///     compound: Vec::new(),
+    ///     conditional: Vec::new(),
+    /// };
+    /// let variants = HashMap::from([("color".to_string(), "primary".to_string())]);
+    ///
+    /// let class_name = SimpleVariantManager::resolve_static(&config, &variants);
+    /// assert_eq!(class_name, "color-primary size-lg");
+    /// ```
+    pub fn resolve_static(
+        config: &super::VariantConfig,
+        variants: &HashMap<String, String>,
+    ) -> String {
+        let mut resolved: BTreeMap<&str, &str> = config
+            .defaults
+            .iter()
+            .map(|(variant_type, value)| (variant_type.as_str(), value.as_str()))
+            .collect();
+        resolved.extend(
+            variants
+                .iter()
+                .map(|(variant_type, value)| (variant_type.as_str(), value.as_str())),
+        );
+
+        resolved
+            .into_iter()
+            .filter(|(variant_type, value)| {
+                let style_map = match *variant_type {
+                    "size" => &config.size,
+                    "color" => &config.color,
+                    "state" => &config.state,
+                    _ => return false,
+                };
+                style_map.contains_key(*value)
+            })
+            .map(|(variant_type, value)| format!("{}-{}", variant_type, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// 获取当前样式
     pub fn get_current_styles(&self) -> HashMap<String, String> {
         let mut final_styles = HashMap::new();
@@ -445,4 +507,41 @@ mod tests {
         let css = manager.generate_css("button");
         assert!(css.contains("background-color: #007bff"));
     }
+
+    #[test]
+    fn test_resolve_static_class_names() {
+        let style = crate::variants::VariantStyle {
+            properties: HashMap::from([("color".to_string(), "red".to_string())]),
+            pseudo_classes: HashMap::new(),
+            priority: 10,
+        };
+        let config = crate::variants::VariantConfig {
+            size: HashMap::from([
+                ("sm".to_string(), style.clone()),
+                ("md".to_string(), style.clone()),
+            ]),
+            color: HashMap::from([("primary".to_string(), style.clone())]),
+            state: HashMap::from([("disabled".to_string(), style)]),
+            responsive: HashMap::new(),
+            defaults: HashMap::from([
+                ("size".to_string(), "md".to_string()),
+                ("color".to_string(), "primary".to_string()),
+            ]),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        let variants = HashMap::from([
+            ("state".to_string(), "disabled".to_string()),
+            ("size".to_string(), "sm".to_string()),
+            ("tone".to_string(), "muted".to_string()),
+        ]);
+
+        let class_name = SimpleVariantManager::resolve_static(&config, &variants);
+
+        assert_eq!(class_name, "color-primary size-sm state-disabled");
+        assert_eq!(
+            SimpleVariantManager::resolve_static(&config, &variants),
+            class_name
+        );
+    }
 }
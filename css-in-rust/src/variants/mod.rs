@@ -22,7 +22,12 @@ pub use variant_resolver::*;
 pub use variant_types::*;
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// 变体类型的应用顺序（与 `VariantConfig` 字段声明顺序一致）
+///
+/// 优先级相同的冲突属性由后应用的变体决定，未知变体类型按名称排在最后。
+const VARIANT_TYPE_ORDER: &[&str] = &["size", "color", "state", "responsive"];
 
 /// 变体配置
 ///
@@ -39,6 +44,12 @@ pub struct VariantConfig {
     pub responsive: HashMap<String, VariantStyle>,
     /// 默认变体
     pub defaults: HashMap<String, String>,
+    /// 复合变体
+    #[serde(default)]
+    pub compound: Vec<CompoundVariant>,
+    /// 基于组件属性的条件样式
+    #[serde(default)]
+    pub conditional: Vec<ConditionalStyle>,
 }
 
 /// 变体样式定义
@@ -52,8 +63,228 @@ pub struct VariantStyle {
     pub priority: u32,
 }
 
-/// 变体应用结果
+/// 复合变体定义
+///
+/// 仅当所有条件变体同时生效时才应用的样式，例如 `size=lg` 且 `color=danger`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompoundVariant {
+    /// 需要同时满足的变体条件（变体类型 -> 变体值）
+    pub conditions: HashMap<String, String>,
+    /// 条件满足时应用的样式
+    pub style: VariantStyle,
+}
+
+impl CompoundVariant {
+    /// 检查复合变体是否与当前生效的变体匹配
+    ///
+    /// # 参数
+    /// * `active_variants` - 当前生效的变体（变体类型 -> 变体值）
+    pub fn matches(&self, active_variants: &HashMap<String, String>) -> bool {
+        !self.conditions.is_empty()
+            && self
+                .conditions
+                .iter()
+                .all(|(variant_type, value)| active_variants.get(variant_type) == Some(value))
+    }
+
+    /// 获取复合变体的标识，条件按变体类型排序以保证稳定
+    fn identifier(&self) -> String {
+        let mut conditions: Vec<_> = self.conditions.iter().collect();
+        conditions.sort();
+        let parts: Vec<String> = conditions
+            .into_iter()
+            .map(|(variant_type, value)| format!("{}-{}", variant_type, value))
+            .collect();
+        format!("compound:{}", parts.join("-"))
+    }
+}
+
+/// 变体配置构建器
+///
+/// 以链式调用代替手动构造嵌套 `HashMap`，生成的 [`VariantConfig`] 与手写配置相同。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::variants::VariantConfigBuilder;
+///
+/// let config = VariantConfigBuilder::new()
+///     .size("lg", |s| s.prop("padding", "16px").priority(10))
+///     .color("primary", |s| s.prop("color", "#1677ff").pseudo(":hover", "color", "#4096ff"))
+///     .default("size", "md")
+///     .build();
+///
+/// assert_eq!(config.size["lg"].properties["padding"], "16px");
+/// assert_eq!(config.size["lg"].priority, 10);
+/// assert_eq!(config.defaults["size"], "md");
+/// ```
 #[derive(Debug, Clone)]
+pub struct VariantConfigBuilder {
+    config: VariantConfig,
+}
+
+impl VariantConfigBuilder {
+    /// 创建新的构建器
+    pub fn new() -> Self {
+        Self {
+            config: VariantConfig {
+                size: HashMap::new(),
+                color: HashMap::new(),
+                state: HashMap::new(),
+                responsive: HashMap::new(),
+                defaults: HashMap::new(),
+                compound: Vec::new(),
+                conditional: Vec::new(),
+            },
+        }
+    }
+
+    /// 添加尺寸变体
+    pub fn size(
+        mut self,
+        name: &str,
+        style: impl FnOnce(VariantStyleBuilder) -> VariantStyleBuilder,
+    ) -> Self {
+        self.config
+            .size
+            .insert(name.to_string(), style(VariantStyleBuilder::new()).build());
+        self
+    }
+
+    /// 添加颜色变体
+    pub fn color(
+        mut self,
+        name: &str,
+        style: impl FnOnce(VariantStyleBuilder) -> VariantStyleBuilder,
+    ) -> Self {
+        self.config
+            .color
+            .insert(name.to_string(), style(VariantStyleBuilder::new()).build());
+        self
+    }
+
+    /// 添加状态变体
+    pub fn state(
+        mut self,
+        name: &str,
+        style: impl FnOnce(VariantStyleBuilder) -> VariantStyleBuilder,
+    ) -> Self {
+        self.config
+            .state
+            .insert(name.to_string(), style(VariantStyleBuilder::new()).build());
+        self
+    }
+
+    /// 添加响应式变体
+    pub fn responsive(
+        mut self,
+        name: &str,
+        style: impl FnOnce(VariantStyleBuilder) -> VariantStyleBuilder,
+    ) -> Self {
+        self.config
+            .responsive
+            .insert(name.to_string(), style(VariantStyleBuilder::new()).build());
+        self
+    }
+
+    /// 设置变体类型的默认值，如 `default("size", "md")`
+    pub fn default(mut self, variant_type: &str, value: &str) -> Self {
+        self.config
+            .defaults
+            .insert(variant_type.to_string(), value.to_string());
+        self
+    }
+
+    /// 添加复合变体，`conditions` 为需要同时满足的（变体类型, 变体值）
+    pub fn compound(
+        mut self,
+        conditions: &[(&str, &str)],
+        style: impl FnOnce(VariantStyleBuilder) -> VariantStyleBuilder,
+    ) -> Self {
+        self.config.compound.push(CompoundVariant {
+            conditions: conditions
+                .iter()
+                .map(|(variant_type, value)| (variant_type.to_string(), value.to_string()))
+                .collect(),
+            style: style(VariantStyleBuilder::new()).build(),
+        });
+        self
+    }
+
+    /// 添加条件样式
+    pub fn conditional(mut self, style: ConditionalStyle) -> Self {
+        self.config.conditional.push(style);
+        self
+    }
+
+    /// 构建变体配置
+    pub fn build(self) -> VariantConfig {
+        self.config
+    }
+}
+
+impl Default for VariantConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 变体样式构建器
+#[derive(Debug, Clone)]
+pub struct VariantStyleBuilder {
+    style: VariantStyle,
+}
+
+impl VariantStyleBuilder {
+    /// 创建新的构建器，优先级默认为 0
+    pub fn new() -> Self {
+        Self {
+            style: VariantStyle {
+                properties: HashMap::new(),
+                pseudo_classes: HashMap::new(),
+                priority: 0,
+            },
+        }
+    }
+
+    /// 设置样式属性
+    pub fn prop(mut self, name: &str, value: &str) -> Self {
+        self.style
+            .properties
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// 设置伪类样式属性，如 `pseudo(":hover", "color", "red")`
+    pub fn pseudo(mut self, pseudo_class: &str, name: &str, value: &str) -> Self {
+        self.style
+            .pseudo_classes
+            .entry(pseudo_class.to_string())
+            .or_default()
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// 设置优先级
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.style.priority = priority;
+        self
+    }
+
+    /// 构建变体样式
+    pub fn build(self) -> VariantStyle {
+        self.style
+    }
+}
+
+impl Default for VariantStyleBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 变体应用结果
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VariantResult {
     /// 生成的 CSS 类名
     pub class_name: String,
@@ -65,6 +296,18 @@ pub struct VariantResult {
     pub priority_score: u32,
 }
 
+impl VariantResult {
+    /// 获取 CSS 规则中的声明部分（不含选择器和花括号）
+    ///
+    /// 样式注入器会自行用类名包裹声明，注入时应使用此方法而非 `css_rules`。
+    pub fn declarations(&self) -> &str {
+        match (self.css_rules.find('{'), self.css_rules.rfind('}')) {
+            (Some(start), Some(end)) if start < end => self.css_rules[start + 1..end].trim(),
+            _ => self.css_rules.trim(),
+        }
+    }
+}
+
 /// 变体管理器
 ///
 /// 负责变体的注册、解析和应用
@@ -72,8 +315,15 @@ pub struct VariantResult {
 pub struct VariantManager {
     /// 注册的变体配置
     configs: HashMap<String, VariantConfig>,
-    /// 简单变体管理器
-    simple_manager: SimpleVariantManager,
+}
+
+/// 变体管理器快照
+///
+/// 可序列化的已注册变体配置，按组件名排序，便于作为视觉回归基线保存
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct VariantManagerSnapshot {
+    /// 组件名到变体配置的映射
+    pub configs: BTreeMap<String, VariantConfig>,
 }
 
 impl VariantManager {
@@ -81,7 +331,6 @@ impl VariantManager {
     pub fn new() -> Self {
         Self {
             configs: HashMap::new(),
-            simple_manager: SimpleVariantManager::new(),
         }
     }
 
@@ -94,8 +343,38 @@ impl VariantManager {
         self.configs.insert(component_name.to_string(), config);
     }
 
+    /// 生成已注册变体配置的快照
+    pub fn snapshot(&self) -> VariantManagerSnapshot {
+        VariantManagerSnapshot {
+            configs: self
+                .configs
+                .iter()
+                .map(|(name, config)| (name.clone(), config.clone()))
+                .collect(),
+        }
+    }
+
+    /// 估算已注册变体配置占用的内存（字节）
+    ///
+    /// 以配置序列化为 JSON 后的长度作为估算值。
+    pub fn estimated_memory(&self) -> usize {
+        serde_json::to_string(&self.configs)
+            .map(|json| json.len())
+            .unwrap_or(0)
+    }
+
+    /// 从快照恢复变体管理器
+    pub fn from_snapshot(snapshot: VariantManagerSnapshot) -> Self {
+        Self {
+            configs: snapshot.configs.into_iter().collect(),
+        }
+    }
+
     /// 应用变体
     ///
+    /// 默认变体先于指定变体应用，同类变体按尺寸、颜色、状态的顺序应用；
+    /// 冲突属性优先级相同时后应用的变体获胜，类名中的变体顺序也与此一致。
+    ///
     /// # 参数
     /// * `component_name` - 组件名称
     /// * `variants` - 要应用的变体
@@ -104,46 +383,36 @@ impl VariantManager {
         &self,
         component_name: &str,
         variants: &HashMap<String, String>,
-        _props: &HashMap<String, serde_json::Value>,
+        props: &HashMap<String, serde_json::Value>,
     ) -> Result<VariantResult, String> {
         let config = self
             .configs
             .get(component_name)
             .ok_or_else(|| format!("Component '{}' not found", component_name))?;
 
-        let mut applied_styles = HashMap::new();
-        let mut applied_variants = Vec::new();
-        let mut priority_score = 0;
-
-        // 应用默认变体
-        for (variant_type, default_value) in &config.defaults {
-            if let Some(variant_style) = self.get_variant_style(config, variant_type, default_value)
-            {
-                self.merge_styles(&mut applied_styles, &variant_style.properties);
-                applied_variants.push(format!("{}:{}", variant_type, default_value));
-                priority_score += variant_style.priority;
-            }
-        }
+        let mut matched = self.matched_variant_styles(config, variants);
 
-        // 应用指定变体
-        for (variant_type, variant_value) in variants {
-            if let Some(variant_style) = self.get_variant_style(config, variant_type, variant_value)
-            {
-                self.merge_styles(&mut applied_styles, &variant_style.properties);
-                applied_variants.push(format!("{}:{}", variant_type, variant_value));
-                priority_score += variant_style.priority;
+        // 应用条件样式（仅在组件属性满足条件时生效）
+        for (index, conditional) in config.conditional.iter().enumerate() {
+            if conditional.matches_props(props) {
+                matched.push(MatchedStyle {
+                    identifier: format!("conditional:{}", index),
+                    style: &conditional.style,
+                    priority_type: PriorityType::Conditional,
+                    score: conditional.priority,
+                });
             }
         }
 
-        // 使用简单变体管理器处理
-        let simple_styles = self
-            .simple_manager
-            .apply_simple_variants(config, variants)?;
-        self.merge_styles(&mut applied_styles, &simple_styles);
+        let applied_variants: Vec<String> = matched
+            .iter()
+            .map(|entry| entry.identifier.clone())
+            .collect();
+        let priority_score = matched.iter().map(|entry| entry.score).sum();
 
         // 生成 CSS
         let class_name = self.generate_class_name(component_name, &applied_variants);
-        let css_rules = self.generate_css_rules(&class_name, &applied_styles);
+        let css_rules = self.generate_css_rules(&class_name, &resolve_matched_styles(&matched));
 
         Ok(VariantResult {
             class_name,
@@ -153,6 +422,209 @@ impl VariantManager {
         })
     }
 
+    /// 按应用顺序收集默认变体、指定变体和复合变体的样式（不含条件样式）
+    fn matched_variant_styles<'a>(
+        &self,
+        config: &'a VariantConfig,
+        variants: &HashMap<String, String>,
+    ) -> Vec<MatchedStyle<'a>> {
+        let mut matched = Vec::new();
+
+        // 应用默认变体（已显式指定的变体类型不再应用默认值）
+        for (variant_type, default_value) in ordered_variants(&config.defaults) {
+            if variants.contains_key(variant_type) {
+                continue;
+            }
+            if let Some(style) = self.get_variant_style(config, variant_type, default_value) {
+                matched.push(MatchedStyle::variant(variant_type, default_value, style));
+            }
+        }
+
+        // 应用指定变体
+        for (variant_type, variant_value) in ordered_variants(variants) {
+            if let Some(style) = self.get_variant_style(config, variant_type, variant_value) {
+                matched.push(MatchedStyle::variant(variant_type, variant_value, style));
+            }
+        }
+
+        // 应用复合变体（按条件样式计算优先级，高于单一变体）
+        let mut active_variants = config.defaults.clone();
+        active_variants.extend(variants.iter().map(|(k, v)| (k.clone(), v.clone())));
+        for compound in &config.compound {
+            if compound.matches(&active_variants) {
+                matched.push(MatchedStyle {
+                    identifier: compound.identifier(),
+                    style: &compound.style,
+                    priority_type: PriorityType::Conditional,
+                    score: compound.style.priority,
+                });
+            }
+        }
+
+        matched
+    }
+
+    /// 根据组件属性绑定解析变体
+    ///
+    /// `bindings` 为 (变体类型, 属性值) 列表，对应 `styled_component_with_props!` 中的
+    /// `variant(size = props.size)`。属性值同时作为组件属性参与条件样式匹配。
+    ///
+    /// # 参数
+    /// * `component_name` - 组件名称
+    /// * `bindings` - 属性到变体的绑定
+    pub fn resolve_prop_variants(
+        &self,
+        component_name: &str,
+        bindings: &[(&str, String)],
+    ) -> Result<VariantResult, String> {
+        let variants: HashMap<String, String> = bindings
+            .iter()
+            .map(|(variant_type, value)| (variant_type.to_string(), value.clone()))
+            .collect();
+        let props: HashMap<String, serde_json::Value> = bindings
+            .iter()
+            .map(|(variant_type, value)| {
+                (variant_type.to_string(), serde_json::json!(value.as_str()))
+            })
+            .collect();
+
+        self.apply_variants(component_name, &variants, &props)
+    }
+
+    /// 生成所有已注册组件的变体样式表
+    ///
+    /// 枚举每个组件尺寸、颜色、状态变体的所有组合（含未指定，此时使用默认值），
+    /// 按 [`VariantManager::apply_variants`] 的规则解析冲突属性与复合变体，
+    /// 以其返回的组合类名（如 `css-button-size-lg-color-danger`）为选择器输出基础规则和伪类规则。
+    /// 响应式变体不参与 `apply_variants`，按断点名输出独立的 `css-组件-responsive-断点` 类到对应的
+    /// `@media` 规则中。条件样式依赖运行时组件属性，不包含在输出中。组件和变体按名称排序，
+    /// 声明按属性名排序，重复的规则只保留一次。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use css_in_rust::variants::{VariantConfigBuilder, VariantManager};
+    ///
+    /// let mut manager = VariantManager::new();
+    /// manager.register_variant_config(
+    ///     "button",
+    ///     VariantConfigBuilder::new()
+    ///         .size("lg", |s| s.prop("padding", "16px"))
+    ///         .build(),
+    /// );
+    ///
+    /// let css = manager.generate_all_css();
+    /// assert!(css.contains(".css-button-size-lg {\n  padding: 16px;\n}"));
+    /// ```
+    pub fn generate_all_css(&self) -> String {
+        let breakpoints = ResponsiveManager::new();
+        let mut rules = Vec::new();
+
+        let mut component_names: Vec<&String> = self.configs.keys().collect();
+        component_names.sort();
+
+        for component_name in component_names {
+            let config = &self.configs[component_name];
+
+            for variants in variant_combinations(config) {
+                let matched = self.matched_variant_styles(config, &variants);
+                if matched.is_empty() {
+                    continue;
+                }
+
+                let identifiers: Vec<String> = matched
+                    .iter()
+                    .map(|entry| entry.identifier.clone())
+                    .collect();
+                let mut pseudo_classes: HashMap<String, HashMap<String, String>> = HashMap::new();
+                for entry in &matched {
+                    for (pseudo_class, properties) in &entry.style.pseudo_classes {
+                        pseudo_classes
+                            .entry(pseudo_class.clone())
+                            .or_default()
+                            .extend(properties.iter().map(|(k, v)| (k.clone(), v.clone())));
+                    }
+                }
+                let style = VariantStyle {
+                    properties: resolve_matched_styles(&matched),
+                    pseudo_classes,
+                    priority: 0,
+                };
+
+                let selector = format!(
+                    ".{}",
+                    self.generate_class_name(component_name, &identifiers)
+                );
+                rules.extend(self.generate_style_rules(&selector, &style));
+            }
+
+            for (breakpoint_name, style) in sorted_styles(&config.responsive) {
+                let Some(breakpoint) = breakpoints.get_breakpoint(breakpoint_name) else {
+                    continue;
+                };
+                let selector = format!(
+                    ".{}",
+                    self.generate_class_name(
+                        component_name,
+                        &[format!("responsive:{}", breakpoint_name)]
+                    )
+                );
+                for rule in self.generate_style_rules(&selector, style) {
+                    rules.push(format!(
+                        "@media {} {{\n{}}}\n",
+                        breakpoint.media_query, rule
+                    ));
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        rules.retain(|rule| seen.insert(rule.clone()));
+        rules.join("\n")
+    }
+
+    /// 生成变体样式的基础规则和伪类规则，声明按属性名排序
+    fn generate_style_rules(&self, selector: &str, style: &VariantStyle) -> Vec<String> {
+        let declarations = |properties: &HashMap<String, String>| {
+            let mut properties: Vec<_> = properties.iter().collect();
+            properties.sort();
+            properties
+                .into_iter()
+                .map(|(property, value)| format!("  {}: {};\n", property, value))
+                .collect::<String>()
+        };
+
+        let mut rules = Vec::new();
+        if !style.properties.is_empty() {
+            rules.push(format!(
+                "{} {{\n{}}}\n",
+                selector,
+                declarations(&style.properties)
+            ));
+        }
+
+        let mut pseudo_classes: Vec<_> = style.pseudo_classes.iter().collect();
+        pseudo_classes.sort_by(|a, b| a.0.cmp(b.0));
+        for (pseudo_class, properties) in pseudo_classes {
+            if properties.is_empty() {
+                continue;
+            }
+            let separator = if pseudo_class.starts_with(':') {
+                ""
+            } else {
+                ":"
+            };
+            rules.push(format!(
+                "{}{}{} {{\n{}}}\n",
+                selector,
+                separator,
+                pseudo_class,
+                declarations(properties)
+            ));
+        }
+        rules
+    }
+
     /// 获取变体样式
     fn get_variant_style<'a>(
         &self,
@@ -168,13 +640,6 @@ impl VariantManager {
         }
     }
 
-    /// 合并样式
-    fn merge_styles(&self, target: &mut HashMap<String, String>, source: &HashMap<String, String>) {
-        for (key, value) in source {
-            target.insert(key.clone(), value.clone());
-        }
-    }
-
     /// 生成类名
     fn generate_class_name(&self, component_name: &str, variants: &[String]) -> String {
         let mut class_parts = vec![component_name.to_string()];
@@ -195,6 +660,86 @@ impl VariantManager {
     }
 }
 
+/// 参与解析的变体样式
+struct MatchedStyle<'a> {
+    /// 变体标识，如 `size:lg` 或 `compound:...`
+    identifier: String,
+    /// 变体样式
+    style: &'a VariantStyle,
+    /// 优先级类型
+    priority_type: PriorityType,
+    /// 计入优先级分数的值
+    score: u32,
+}
+
+impl<'a> MatchedStyle<'a> {
+    /// 单一变体（尺寸、颜色、状态）的样式
+    fn variant(variant_type: &str, value: &str, style: &'a VariantStyle) -> Self {
+        Self {
+            identifier: format!("{}:{}", variant_type, value),
+            style,
+            priority_type: PriorityType::Variant,
+            score: style.priority,
+        }
+    }
+}
+
+/// 由优先级决定冲突属性的最终取值
+fn resolve_matched_styles(matched: &[MatchedStyle]) -> HashMap<String, String> {
+    let mut priority_manager = PriorityManager::with_config(PriorityConfig::deterministic());
+    for entry in matched {
+        let source = match entry.priority_type {
+            PriorityType::Variant => StyleSource::Variant(entry.identifier.clone()),
+            _ => StyleSource::Conditional(entry.identifier.clone()),
+        };
+        priority_manager.add_variant_style(entry.style, entry.priority_type.clone(), source);
+    }
+    priority_manager.resolve_styles().final_styles
+}
+
+/// 枚举尺寸、颜色、状态变体的所有取值组合，未指定的变体类型不出现在组合中
+fn variant_combinations(config: &VariantConfig) -> Vec<HashMap<String, String>> {
+    let mut combinations = vec![HashMap::new()];
+    for (variant_type, styles) in [
+        ("size", &config.size),
+        ("color", &config.color),
+        ("state", &config.state),
+    ] {
+        let values: Vec<&String> = sorted_styles(styles).into_iter().map(|(v, _)| v).collect();
+        let mut next = Vec::with_capacity(combinations.len() * (values.len() + 1));
+        for combination in &combinations {
+            next.push(combination.clone());
+            for value in &values {
+                let mut extended = combination.clone();
+                extended.insert(variant_type.to_string(), value.to_string());
+                next.push(extended);
+            }
+        }
+        combinations = next;
+    }
+    combinations
+}
+
+/// 按变体类型的应用顺序排列变体，保证优先级相同时结果稳定
+fn ordered_variants(variants: &HashMap<String, String>) -> Vec<(&String, &String)> {
+    let mut variants: Vec<_> = variants.iter().collect();
+    variants.sort_by_key(|(variant_type, _)| {
+        let position = VARIANT_TYPE_ORDER
+            .iter()
+            .position(|known| known == variant_type)
+            .unwrap_or(VARIANT_TYPE_ORDER.len());
+        (position, variant_type.as_str())
+    });
+    variants
+}
+
+/// 按变体值排序的变体样式
+fn sorted_styles(styles: &HashMap<String, VariantStyle>) -> Vec<(&String, &VariantStyle)> {
+    let mut styles: Vec<_> = styles.iter().collect();
+    styles.sort_by(|a, b| a.0.cmp(b.0));
+    styles
+}
+
 impl Default for VariantManager {
     fn default() -> Self {
         Self::new()
@@ -215,6 +760,49 @@ pub fn global_variant_manager() -> &'static mut VariantManager {
     }
 }
 
+/// 将属性绑定的变体注入并与基础类名合并
+///
+/// 在全局变体管理器中按组件名查找已注册的 `VariantConfig`，注入变体 CSS 后返回
+/// `"基础类名 变体类名"`。组件未注册变体配置时只返回基础类名。
+pub fn bind_prop_variants(
+    base_class: &str,
+    component_name: &str,
+    bindings: &[(&str, String)],
+) -> String {
+    bind_variants_with(
+        global_variant_manager(),
+        base_class,
+        component_name,
+        bindings,
+        crate::runtime::inject_style,
+    )
+}
+
+/// 使用指定的变体管理器和注入函数绑定属性变体
+///
+/// `inject` 接收（声明, 类名）并返回实际使用的类名。
+fn bind_variants_with(
+    manager: &VariantManager,
+    base_class: &str,
+    component_name: &str,
+    bindings: &[(&str, String)],
+    inject: impl FnOnce(&str, &str) -> String,
+) -> String {
+    match manager.resolve_prop_variants(component_name, bindings) {
+        Ok(result) => {
+            let variant_class = inject(result.declarations(), &result.class_name);
+            format!("{} {}", base_class, variant_class)
+        }
+        Err(e) => {
+            eprintln!(
+                "Failed to resolve variants for component '{}': {}",
+                component_name, e
+            );
+            base_class.to_string()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,9 +822,459 @@ mod tests {
             state: HashMap::new(),
             responsive: HashMap::new(),
             defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
         };
 
         manager.register_variant_config("button", config);
         assert!(manager.configs.contains_key("button"));
     }
+
+    #[test]
+    fn test_variant_result_json_round_trip() {
+        let mut manager = VariantManager::new();
+        let mut config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        config
+            .size
+            .insert("lg".to_string(), style_with(&[("padding", "12px")], 1));
+        manager.register_variant_config("button", config);
+
+        let variants = HashMap::from([("size".to_string(), "lg".to_string())]);
+        let result = manager
+            .apply_variants("button", &variants, &HashMap::new())
+            .unwrap();
+
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: VariantResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, result);
+        assert_eq!(restored.css_rules, result.css_rules);
+        assert_eq!(restored.applied_variants, vec!["size:lg".to_string()]);
+
+        // 管理器快照恢复后生成相同的结果
+        let snapshot_json = serde_json::to_string(&manager.snapshot()).unwrap();
+        let snapshot: VariantManagerSnapshot = serde_json::from_str(&snapshot_json).unwrap();
+        let restored_manager = VariantManager::from_snapshot(snapshot);
+        let restored_result = restored_manager
+            .apply_variants("button", &variants, &HashMap::new())
+            .unwrap();
+        assert_eq!(restored_result, result);
+    }
+
+    #[test]
+    fn test_variant_config_builder_matches_hand_built() {
+        let built = VariantConfigBuilder::new()
+            .size("lg", |s| s.prop("padding", "16px").priority(10))
+            .size("md", |s| s.prop("padding", "8px"))
+            .color("primary", |s| {
+                s.prop("color", "blue")
+                    .pseudo(":hover", "color", "navy")
+                    .priority(5)
+            })
+            .compound(&[("size", "lg"), ("color", "primary")], |s| {
+                s.prop("border", "2px solid blue").priority(20)
+            })
+            .default("size", "md")
+            .build();
+
+        let mut primary = style_with(&[("color", "blue")], 5);
+        primary.pseudo_classes.insert(
+            ":hover".to_string(),
+            HashMap::from([("color".to_string(), "navy".to_string())]),
+        );
+        let hand_built = VariantConfig {
+            size: HashMap::from([
+                ("lg".to_string(), style_with(&[("padding", "16px")], 10)),
+                ("md".to_string(), style_with(&[("padding", "8px")], 0)),
+            ]),
+            color: HashMap::from([("primary".to_string(), primary)]),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::from([("size".to_string(), "md".to_string())]),
+            compound: vec![CompoundVariant {
+                conditions: HashMap::from([
+                    ("size".to_string(), "lg".to_string()),
+                    ("color".to_string(), "primary".to_string()),
+                ]),
+                style: style_with(&[("border", "2px solid blue")], 20),
+            }],
+            conditional: Vec::new(),
+        };
+
+        assert_eq!(built, hand_built);
+    }
+
+    #[test]
+    fn test_generate_all_css_covers_registered_components() {
+        let mut manager = VariantManager::new();
+        manager.register_variant_config(
+            "button",
+            VariantConfigBuilder::new()
+                .size("lg", |s| {
+                    s.prop("padding", "16px").prop("font-size", "18px")
+                })
+                .color("danger", |s| {
+                    s.prop("color", "red").pseudo("hover", "color", "darkred")
+                })
+                .responsive("md", |s| s.prop("width", "100%"))
+                .compound(&[("size", "lg"), ("color", "danger")], |s| {
+                    s.prop("border", "2px solid red")
+                })
+                .build(),
+        );
+        manager.register_variant_config(
+            "input",
+            VariantConfigBuilder::new()
+                .size("sm", |s| s.prop("height", "24px"))
+                .state("disabled", |s| s.prop("opacity", "0.5"))
+                .build(),
+        );
+
+        let css = manager.generate_all_css();
+        assert!(css.contains(".css-button-size-lg {\n  font-size: 18px;\n  padding: 16px;\n}"));
+        assert!(css.contains(".css-button-color-danger:hover {\n  color: darkred;\n}"));
+        assert!(css.contains(
+            "@media (min-width: 768px) and (max-width: 991px) {\n.css-button-responsive-md {"
+        ));
+        assert!(css.contains(
+            ".css-button-size-lg-color-danger-compound-color-danger-size-lg {\n  border: 2px solid red;\n  color: red;\n  font-size: 18px;\n  padding: 16px;\n}"
+        ));
+        assert!(css.contains(".css-button-size-lg-color-danger-compound-color-danger-size-lg:hover {\n  color: darkred;\n}"));
+        assert!(!css.contains(".css-button-color-danger.css-button-size-lg"));
+        assert!(css.contains(".css-input-size-sm {\n  height: 24px;\n}"));
+        assert!(css.contains(".css-input-state-disabled {\n  opacity: 0.5;\n}"));
+        assert!(css.find("css-button").unwrap() < css.find("css-input").unwrap());
+
+        // 与运行时单独应用变体时的类名一致
+        let variants = HashMap::from([("size".to_string(), "sm".to_string())]);
+        let result = manager
+            .apply_variants("input", &variants, &HashMap::new())
+            .unwrap();
+        assert!(css.contains(&format!(".{} {{", result.class_name)));
+    }
+
+    #[test]
+    fn test_generate_all_css_matches_multi_variant_classes() {
+        let mut manager = VariantManager::new();
+        manager.register_variant_config(
+            "button",
+            VariantConfigBuilder::new()
+                .size("md", |s| s.prop("padding", "8px"))
+                .size("lg", |s| s.prop("padding", "16px"))
+                .color("danger", |s| s.prop("color", "red"))
+                .color("primary", |s| s.prop("color", "blue"))
+                .default("size", "md")
+                .compound(&[("size", "lg"), ("color", "danger")], |s| {
+                    s.prop("padding", "20px").priority(1)
+                })
+                .build(),
+        );
+        let css = manager.generate_all_css();
+
+        let cases: [&[(&str, &str)]; 4] = [
+            &[("size", "lg"), ("color", "danger")],
+            &[("color", "primary")],
+            &[("color", "danger"), ("size", "md")],
+            &[],
+        ];
+        for case in cases {
+            let variants: HashMap<String, String> = case
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            let result = manager
+                .apply_variants("button", &variants, &HashMap::new())
+                .unwrap();
+
+            // 运行时返回的类名在静态样式表中有对应规则，且声明一致
+            let rule_start = css
+                .find(&format!(".{} {{", result.class_name))
+                .unwrap_or_else(|| panic!("missing rule for {}", result.class_name));
+            let rule = &css[rule_start..rule_start + css[rule_start..].find('}').unwrap()];
+            for declaration in result.css_rules.lines().filter(|line| line.contains(':')) {
+                assert!(
+                    rule.contains(declaration.trim()),
+                    "{} in {}",
+                    declaration,
+                    rule
+                );
+            }
+        }
+
+        assert!(css.contains(
+            ".css-button-size-lg-color-danger-compound-color-danger-size-lg {\n  color: red;\n  padding: 20px;\n}"
+        ));
+        assert!(
+            css.contains(".css-button-size-md-color-primary {\n  color: blue;\n  padding: 8px;\n}")
+        );
+    }
+
+    fn style_with(properties: &[(&str, &str)], priority: u32) -> VariantStyle {
+        VariantStyle {
+            properties: properties
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            pseudo_classes: HashMap::new(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_compound_variant_applies_only_when_all_match() {
+        let mut manager = VariantManager::new();
+        let mut config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        config
+            .size
+            .insert("lg".to_string(), style_with(&[("padding", "12px")], 10));
+        config
+            .color
+            .insert("danger".to_string(), style_with(&[("color", "red")], 10));
+        config
+            .color
+            .insert("primary".to_string(), style_with(&[("color", "blue")], 10));
+        config.compound.push(CompoundVariant {
+            conditions: [("size", "lg"), ("color", "danger")]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            style: style_with(&[("border", "2px solid red")], 20),
+        });
+        manager.register_variant_config("button", config);
+
+        let props = HashMap::new();
+        let both: HashMap<String, String> = [("size", "lg"), ("color", "danger")]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let result = manager.apply_variants("button", &both, &props).unwrap();
+        assert!(result.css_rules.contains("border: 2px solid red"));
+        assert!(result
+            .applied_variants
+            .contains(&"compound:color-danger-size-lg".to_string()));
+
+        let only_size: HashMap<String, String> = [("size".to_string(), "lg".to_string())]
+            .into_iter()
+            .collect();
+        let result = manager
+            .apply_variants("button", &only_size, &props)
+            .unwrap();
+        assert!(!result.css_rules.contains("border"));
+
+        let other_color: HashMap<String, String> = [("size", "lg"), ("color", "primary")]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let result = manager
+            .apply_variants("button", &other_color, &props)
+            .unwrap();
+        assert!(!result.css_rules.contains("border"));
+    }
+
+    #[test]
+    fn test_conditional_styles_follow_props() {
+        let mut manager = VariantManager::new();
+        let config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: vec![
+                conditional_style(ConditionCombination::eq(
+                    "variant",
+                    ConditionValue::String("ghost".to_string()),
+                ))
+                .property("background", "transparent")
+                .build(),
+                conditional_style(ConditionCombination::gt("count", 3.0))
+                    .property("font-weight", "bold")
+                    .build(),
+            ],
+        };
+        manager.register_variant_config("badge", config);
+
+        let mut props = HashMap::new();
+        props.insert("variant".to_string(), serde_json::json!("ghost"));
+        props.insert("count".to_string(), serde_json::json!(2));
+        let result = manager
+            .apply_variants("badge", &HashMap::new(), &props)
+            .unwrap();
+        assert!(result.css_rules.contains("background: transparent"));
+        assert!(!result.css_rules.contains("font-weight"));
+
+        props.insert("variant".to_string(), serde_json::json!("solid"));
+        props.insert("count".to_string(), serde_json::json!(7));
+        let result = manager
+            .apply_variants("badge", &HashMap::new(), &props)
+            .unwrap();
+        assert!(!result.css_rules.contains("background"));
+        assert!(result.css_rules.contains("font-weight: bold"));
+    }
+
+    #[test]
+    fn test_higher_priority_variant_wins_regardless_of_order() {
+        let mut manager = VariantManager::new();
+        let mut config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        config
+            .color
+            .insert("danger".to_string(), style_with(&[("color", "red")], 50));
+        config.state.insert(
+            "muted".to_string(),
+            style_with(&[("color", "gray"), ("opacity", "0.6")], 5),
+        );
+        // 默认值先应用，指定变体后应用；低优先级的指定变体不应覆盖高优先级的默认变体
+        config
+            .defaults
+            .insert("color".to_string(), "danger".to_string());
+        manager.register_variant_config("button", config);
+
+        let variants: HashMap<String, String> = [("state".to_string(), "muted".to_string())]
+            .into_iter()
+            .collect();
+        let result = manager
+            .apply_variants("button", &variants, &HashMap::new())
+            .unwrap();
+
+        assert!(result.css_rules.contains("color: red"));
+        assert!(!result.css_rules.contains("color: gray"));
+        assert!(result.css_rules.contains("opacity: 0.6"));
+    }
+
+    #[test]
+    fn test_equal_priority_variants_resolve_stably() {
+        let mut manager = VariantManager::new();
+        manager.register_variant_config(
+            "button",
+            VariantConfigBuilder::new()
+                .size("lg", |s| s.prop("color", "red").prop("padding", "16px"))
+                .color("primary", |s| s.prop("color", "blue"))
+                .state("active", |s| s.prop("color", "green"))
+                .build(),
+        );
+
+        // 每次新建的 HashMap 迭代顺序不同，结果应始终一致
+        for _ in 0..32 {
+            let variants: HashMap<String, String> =
+                [("state", "active"), ("color", "primary"), ("size", "lg")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+            let result = manager
+                .apply_variants("button", &variants, &HashMap::new())
+                .unwrap();
+
+            assert_eq!(
+                result.class_name,
+                "css-button-size-lg-color-primary-state-active"
+            );
+            assert!(result.css_rules.contains("color: green"));
+            assert!(result.css_rules.contains("padding: 16px"));
+        }
+    }
+
+    /// 带 `size` 属性的测试组件
+    struct SizedButtonProps {
+        size: &'static str,
+    }
+
+    fn render_sized_button(manager: &VariantManager, props: &SizedButtonProps) -> String {
+        manager
+            .resolve_prop_variants("sized-button", &[("size", props.size.to_string())])
+            .unwrap()
+            .class_name
+    }
+
+    #[test]
+    fn test_size_prop_selects_size_variant() {
+        let mut manager = VariantManager::new();
+        let mut config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        config
+            .size
+            .insert("sm".to_string(), style_with(&[("padding", "4px")], 1));
+        config
+            .size
+            .insert("lg".to_string(), style_with(&[("padding", "12px")], 1));
+        manager.register_variant_config("sized-button", config);
+
+        let small = render_sized_button(&manager, &SizedButtonProps { size: "sm" });
+        let large = render_sized_button(&manager, &SizedButtonProps { size: "lg" });
+        assert_eq!(small, "css-sized-button-size-sm");
+        assert_eq!(large, "css-sized-button-size-lg");
+
+        let result = manager
+            .resolve_prop_variants("sized-button", &[("size", "lg".to_string())])
+            .unwrap();
+        assert!(result.css_rules.contains("padding: 12px"));
+    }
+
+    #[test]
+    fn test_bind_variants_injects_declarations_only() {
+        use crate::runtime::StyleInjector;
+
+        let mut manager = VariantManager::new();
+        manager.register_variant_config(
+            "bound-button",
+            VariantConfigBuilder::new()
+                .size("lg", |s| s.prop("padding", "12px"))
+                .build(),
+        );
+
+        let injector = StyleInjector::new_noop();
+        let class_name = bind_variants_with(
+            &manager,
+            "base",
+            "bound-button",
+            &[("size", "lg".to_string())],
+            |css, class_name| {
+                injector.inject_style(css, class_name).unwrap();
+                class_name.to_string()
+            },
+        );
+        assert_eq!(class_name, "base css-bound-button-size-lg");
+
+        let injected = injector.injected_styles();
+        assert_eq!(
+            injected.get("css-bound-button-size-lg").map(String::as_str),
+            Some("padding: 12px;")
+        );
+
+        // 未注册的组件只返回基础类名，不注入
+        let unbound = bind_variants_with(&manager, "base", "missing", &[], |_, _| {
+            panic!("should not inject")
+        });
+        assert_eq!(unbound, "base");
+    }
 }
@@ -648,6 +648,19 @@ impl PriorityManager {
     }
 }
 
+impl PriorityConfig {
+    /// 创建确定性配置
+    ///
+    /// 禁用特异性与时间戳排序，冲突仅由优先级决定；优先级相同时后添加的规则获胜
+    pub fn deterministic() -> Self {
+        Self {
+            enable_specificity: false,
+            enable_timestamp_ordering: false,
+            ..Self::default()
+        }
+    }
+}
+
 impl Default for PriorityConfig {
     fn default() -> Self {
         let mut priority_weights = HashMap::new();
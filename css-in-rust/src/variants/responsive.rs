@@ -5,7 +5,7 @@
 use super::{VariantConfig, VariantStyle};
 use crate::theme::core::token::values::Breakpoints;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// 响应式变体
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -333,6 +333,91 @@ impl Default for MediaQueryBuilder {
     }
 }
 
+/// 生成容器查询
+///
+/// 输出 `@container name (min-width: Xpx) { css }`；`name` 为 `None` 或空字符串时匹配最近的查询容器。
+///
+/// # 示例
+///
+/// ```
+/// use css_in_rust::variants::container_query;
+///
+/// let css = container_query(Some("card"), 400.0, ".title { font-size: 1.25rem; }");
+/// assert_eq!(
+///     css,
+///     "@container card (min-width: 400px) { .title { font-size: 1.25rem; } }"
+/// );
+/// ```
+pub fn container_query(name: Option<&str>, min_width_px: f64, css: &str) -> String {
+    let name = name.map(str::trim).filter(|name| !name.is_empty());
+    match name {
+        Some(name) => format!(
+            "@container {} (min-width: {}px) {{ {} }}",
+            name,
+            min_width_px,
+            css.trim()
+        ),
+        None => format!(
+            "@container (min-width: {}px) {{ {} }}",
+            min_width_px,
+            css.trim()
+        ),
+    }
+}
+
+/// 容器断点
+///
+/// 与视口断点类似，但用于容器查询，描述组件所在容器的宽度档位（单位 px）。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerBreakpoints {
+    /// 断点名称到最小宽度的映射
+    pub breakpoints: BTreeMap<String, f64>,
+}
+
+impl ContainerBreakpoints {
+    /// 创建空的容器断点集合
+    pub fn new() -> Self {
+        Self {
+            breakpoints: BTreeMap::new(),
+        }
+    }
+
+    /// 设置断点
+    pub fn set(&mut self, name: &str, min_width_px: f64) {
+        self.breakpoints.insert(name.to_string(), min_width_px);
+    }
+
+    /// 获取断点的最小宽度
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.breakpoints.get(name).copied()
+    }
+
+    /// 为指定断点生成容器查询，断点不存在时返回 `None`
+    pub fn query(
+        &self,
+        breakpoint: &str,
+        container_name: Option<&str>,
+        css: &str,
+    ) -> Option<String> {
+        self.get(breakpoint)
+            .map(|min_width| container_query(container_name, min_width, css))
+    }
+}
+
+impl Default for ContainerBreakpoints {
+    fn default() -> Self {
+        Self {
+            breakpoints: BTreeMap::from([
+                ("xs".to_string(), 240.0),
+                ("sm".to_string(), 320.0),
+                ("md".to_string(), 480.0),
+                ("lg".to_string(), 640.0),
+                ("xl".to_string(), 800.0),
+            ]),
+        }
+    }
+}
+
 /// 便捷的响应式变体创建函数
 pub fn responsive_variant() -> ResponsiveVariantBuilder {
     ResponsiveVariantBuilder::new()
@@ -442,4 +527,26 @@ mod tests {
         assert!(variant.breakpoints.contains_key("md"));
         assert!(variant.mobile_first);
     }
+
+    #[test]
+    fn test_container_query() {
+        let css = container_query(Some("sidebar"), 320.0, "  .nav { gap: 8px; }  ");
+        assert_eq!(
+            css,
+            "@container sidebar (min-width: 320px) { .nav { gap: 8px; } }"
+        );
+        assert_eq!(
+            container_query(None, 480.5, ".a { margin: 0; }"),
+            "@container (min-width: 480.5px) { .a { margin: 0; } }"
+        );
+
+        let mut breakpoints = ContainerBreakpoints::default();
+        breakpoints.set("card", 360.0);
+        assert_eq!(
+            breakpoints.query("md", Some("card"), ".a { padding: 4px; }"),
+            Some("@container card (min-width: 480px) { .a { padding: 4px; } }".to_string())
+        );
+        assert_eq!(breakpoints.get("card"), Some(360.0));
+        assert!(breakpoints.query("huge", None, ".a {}").is_none());
+    }
 }
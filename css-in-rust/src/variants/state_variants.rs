@@ -13,6 +13,8 @@ pub enum StateType {
     Hover,
     /// 焦点状态
     Focus,
+    /// 键盘焦点可见状态
+    FocusVisible,
     /// 激活状态
     Active,
     /// 禁用状态
@@ -110,6 +112,7 @@ impl StateType {
         match self {
             StateType::Hover => ":hover".to_string(),
             StateType::Focus => ":focus".to_string(),
+            StateType::FocusVisible => ":focus-visible".to_string(),
             StateType::Active => ":active".to_string(),
             StateType::Disabled => ":disabled".to_string(),
             StateType::Checked => ":checked".to_string(),
@@ -124,11 +127,46 @@ impl StateType {
         }
     }
 
+    /// 获取状态对应的全部选择器
+    ///
+    /// 禁用状态同时匹配原生 `:disabled` 与 `[aria-disabled="true"]`，
+    /// 以覆盖不支持 `disabled` 属性的元素
+    pub fn to_css_selectors(&self) -> Vec<String> {
+        match self {
+            StateType::Disabled => vec![
+                ":disabled".to_string(),
+                "[aria-disabled=\"true\"]".to_string(),
+            ],
+            _ => vec![self.to_css_selector()],
+        }
+    }
+
+    /// 从状态名称解析状态类型
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "hover" => StateType::Hover,
+            "focus" => StateType::Focus,
+            "focus-visible" => StateType::FocusVisible,
+            "active" => StateType::Active,
+            "disabled" => StateType::Disabled,
+            "checked" => StateType::Checked,
+            "visited" => StateType::Visited,
+            "selected" => StateType::Selected,
+            "loading" => StateType::Loading,
+            "first-child" => StateType::FirstChild,
+            "last-child" => StateType::LastChild,
+            "nth-child(odd)" | "nth-child-odd" => StateType::NthChildOdd,
+            "nth-child(even)" | "nth-child-even" => StateType::NthChildEven,
+            _ => StateType::Custom(name.to_string()),
+        }
+    }
+
     /// 获取状态名称
     pub fn name(&self) -> String {
         match self {
             StateType::Hover => "hover".to_string(),
             StateType::Focus => "focus".to_string(),
+            StateType::FocusVisible => "focus-visible".to_string(),
             StateType::Active => "active".to_string(),
             StateType::Disabled => "disabled".to_string(),
             StateType::Checked => "checked".to_string(),
@@ -376,9 +414,8 @@ impl StateVariantManager {
         for state_type in sorted_states {
             if let Some(styles) = state_style_map.get(state_type) {
                 css.push_str(&format!(
-                    ".{}{} {{\n",
-                    class_name,
-                    state_type.to_css_selector()
+                    "{} {{\n",
+                    build_state_selector(class_name, state_type)
                 ));
                 for (prop, value) in styles {
                     css.push_str(&format!("  {}: {};\n", prop, value));
@@ -398,21 +435,45 @@ impl StateVariantManager {
         }
     }
 
+    /// 根据变体配置中的状态变体自动生成伪类规则
+    ///
+    /// `config.state` 的键为状态名称（如 `hover`、`focus-visible`、`disabled`），
+    /// 每个状态生成对应的伪类选择器规则，按优先级从低到高输出
+    pub fn generate_config_state_css(&self, class_name: &str, config: &VariantConfig) -> String {
+        let mut states: Vec<_> = config.state.iter().collect();
+        states.sort_by(|(name_a, style_a), (name_b, style_b)| {
+            style_a
+                .priority
+                .cmp(&style_b.priority)
+                .then_with(|| name_a.cmp(name_b))
+        });
+
+        let mut css = String::new();
+        for (state_name, style) in states {
+            if style.properties.is_empty() {
+                continue;
+            }
+
+            let state_type = StateType::from_name(state_name);
+            let mut properties: Vec<_> = style.properties.iter().collect();
+            properties.sort();
+
+            css.push_str(&format!(
+                "{} {{\n",
+                build_state_selector(class_name, &state_type)
+            ));
+            for (prop, value) in properties {
+                css.push_str(&format!("  {}: {};\n", prop, value));
+            }
+            css.push_str("}\n\n");
+        }
+
+        css
+    }
+
     /// 从选择器解析状态类型
     fn parse_state_from_selector(&self, selector: &str) -> Option<StateType> {
-        match selector {
-            "hover" => Some(StateType::Hover),
-            "focus" => Some(StateType::Focus),
-            "active" => Some(StateType::Active),
-            "disabled" => Some(StateType::Disabled),
-            "checked" => Some(StateType::Checked),
-            "visited" => Some(StateType::Visited),
-            "first-child" => Some(StateType::FirstChild),
-            "last-child" => Some(StateType::LastChild),
-            "nth-child(odd)" => Some(StateType::NthChildOdd),
-            "nth-child(even)" => Some(StateType::NthChildEven),
-            _ => Some(StateType::Custom(selector.to_string())),
-        }
+        Some(StateType::from_name(selector))
     }
 
     /// 获取状态变体
@@ -461,6 +522,16 @@ impl StateSelector {
     }
 }
 
+/// 构建状态规则的完整选择器列表，如 `.btn:disabled, .btn[aria-disabled="true"]`
+fn build_state_selector(class_name: &str, state_type: &StateType) -> String {
+    state_type
+        .to_css_selectors()
+        .iter()
+        .map(|selector| format!(".{}{}", class_name, selector))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl Default for StateVariantManager {
     fn default() -> Self {
         Self::new()
@@ -574,6 +645,45 @@ mod tests {
         assert_eq!(variant.priority, 10);
     }
 
+    #[test]
+    fn test_disabled_state_emits_native_and_aria_selectors() {
+        let manager = StateVariantManager::new();
+        let mut config = VariantConfig {
+            size: HashMap::new(),
+            color: HashMap::new(),
+            state: HashMap::new(),
+            responsive: HashMap::new(),
+            defaults: HashMap::new(),
+            compound: Vec::new(),
+            conditional: Vec::new(),
+        };
+        config.state.insert(
+            "disabled".to_string(),
+            VariantStyle {
+                properties: HashMap::from([("opacity".to_string(), "0.5".to_string())]),
+                pseudo_classes: HashMap::new(),
+                priority: 30,
+            },
+        );
+        config.state.insert(
+            "focus-visible".to_string(),
+            VariantStyle {
+                properties: HashMap::from([(
+                    "outline".to_string(),
+                    "2px solid #0066cc".to_string(),
+                )]),
+                pseudo_classes: HashMap::new(),
+                priority: 15,
+            },
+        );
+
+        let css = manager.generate_config_state_css("btn", &config);
+
+        assert!(css.contains(".btn:disabled, .btn[aria-disabled=\"true\"] {"));
+        assert!(css.contains(".btn:focus-visible {"));
+        assert!(css.find(":focus-visible").unwrap() < css.find(":disabled").unwrap());
+    }
+
     #[test]
     fn test_state_selector() {
         let selector = StateSelector::new(".button")
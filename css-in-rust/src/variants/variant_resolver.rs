@@ -675,6 +675,7 @@ impl VariantResolver {
             let state_type = match *part {
                 "hover" => StateType::Hover,
                 "focus" => StateType::Focus,
+                "focus-visible" => StateType::FocusVisible,
                 "active" => StateType::Active,
                 "disabled" => StateType::Disabled,
                 "visited" => StateType::Visited,
@@ -701,6 +702,7 @@ impl VariantResolver {
         let selector = match state_type {
             StateType::Hover => ":hover",
             StateType::Focus => ":focus",
+            StateType::FocusVisible => ":focus-visible",
             StateType::Active => ":active",
             StateType::Disabled => ":disabled",
             StateType::Visited => ":visited",
@@ -8,7 +8,7 @@ use quote as _;
 use sha2 as _;
 use syn as _;
 
-use css_in_rust::css;
+use css_in_rust::{css, css_global, css_with_meta};
 
 // 使用所有依赖，避免未使用的依赖警告
 #[allow(unused_imports)]
@@ -22,10 +22,79 @@ use {
 #[allow(unused_imports)]
 use lightningcss as _;
 
+css!(name = "primary-btn", "color: red; padding: 4px;");
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// 测试具名 CSS 生成类名常量，配套的静态项在首次使用时注入样式
+    #[test]
+    fn test_named_css_injects_on_first_use() {
+        const CLASS: &str = PRIMARY_BTN;
+        let class_name: &str = &css!("color: red; padding: 4px;");
+        assert_eq!(CLASS, class_name);
+        assert!(matches!(class_name, PRIMARY_BTN));
+
+        assert_eq!(*PRIMARY_BTN_STYLE, PRIMARY_BTN);
+        assert!(css_in_rust::runtime::provider::is_style_injected(
+            PRIMARY_BTN
+        ));
+    }
+
+    /// 测试插值生成稳定的动态类名并注册动态样式
+    #[test]
+    fn test_interpolated_css_registers_dynamic_class() {
+        let width = 120;
+        let class_name = css!("color: red; width: {width}px;");
+        let classes: Vec<&str> = class_name.split(' ').collect();
+        assert_eq!(classes.len(), 2);
+        assert_eq!(classes[0], css!("color: red;"));
+        assert_eq!(
+            classes[1],
+            css_in_rust::runtime::generate_class_name("width: 120px;")
+                .replacen("css-", "css-dyn-", 1)
+        );
+        assert!(css_in_rust::runtime::provider::is_style_injected(
+            classes[1]
+        ));
+
+        let same = css!("color: red; width: {width}px;");
+        assert_eq!(class_name, same);
+
+        let width = 240;
+        let other = css!("color: red; width: {width}px;");
+        assert_ne!(class_name, other);
+
+        // 插值变量不会被宏展开中的局部变量遮蔽
+        let static_class = 8;
+        let shadowed = css!("color: red; margin: {static_class}px;");
+        assert!(shadowed.ends_with(
+            &css_in_rust::runtime::generate_class_name("margin: 8px;")
+                .replacen("css-", "css-dyn-", 1)
+        ));
+    }
+
+    /// 测试全局 CSS 不被类名作用域包裹，并原样出现在服务端渲染输出中
+    #[test]
+    fn test_global_css_is_not_scoped() {
+        let global = css_global!(
+            ":root { --gap: 4px; } @keyframes spin { from { opacity: 0 } to { opacity: 1 } }"
+        );
+        assert!(global.starts_with(":root{--gap:4px}"));
+        assert!(global.contains("@keyframes spin"));
+        assert!(!global.contains(".css-"));
+
+        let scoped = css!("color: red; margin: 0;");
+        assert!(scoped.starts_with("css-"));
+        assert!(!global.contains(scoped.as_str()));
+
+        let ssr_css = css_in_rust::runtime::StyleInjector::new_ssr()
+            .collect_ssr_css()
+            .unwrap();
+        assert!(ssr_css.lines().any(|line| line == global));
+    }
+
     #[test]
     fn test_css_macro_basic() {
         let class_name = css! {
@@ -230,6 +299,26 @@ mod tests {
         assert!(class_name.starts_with("css-"));
     }
 
+    #[test]
+    fn test_css_with_meta_lists_variable_references() {
+        let meta = css_with_meta!(
+            "color: var(--primary-color); border: 1px solid var(--border-color, #ddd); &:hover { color: var(--primary-color); }"
+        );
+
+        assert_eq!(meta.variables, ["--primary-color", "--border-color"]);
+        assert!(meta.references("--border-color"));
+        assert_eq!(meta.declaration_count, 3);
+        assert_eq!(meta.hash.len(), 64);
+        assert_eq!(
+            meta.class_name,
+            css!("color: var(--primary-color); border: 1px solid var(--border-color, #ddd); &:hover { color: var(--primary-color); }")
+        );
+
+        let plain = css_with_meta!("padding: 4px;");
+        assert!(plain.variables.is_empty());
+        assert_eq!(plain.declaration_count, 1);
+    }
+
     #[test]
     fn test_css_with_grid_layout() {
         let class_name = css! {
@@ -330,6 +419,41 @@ mod tests {
             assert!(class_name.starts_with("css-"));
         }
     }
+
+    /// Mirrors the calculator demo: the inputs flow through `NumCalculator` and
+    /// `CssCalculator`, so changing an input must change the generated class.
+    #[test]
+    fn test_calculator_inputs_drive_generated_class() {
+        use css_in_rust::theme::core::calc::{CssCalculator, NumCalculator};
+        use css_in_rust::theme::core::css::CssGenerator;
+
+        fn calculator_class(base: f64, scale: f64, gutter: &str) -> String {
+            let padding = NumCalculator::new(base)
+                .multiply(scale)
+                .to_string_with_unit("px");
+            let width = CssCalculator::new("100%").subtract(gutter).calc();
+
+            CssGenerator::new().generate_class(
+                "calculator",
+                &[
+                    ("padding".to_string(), padding),
+                    ("width".to_string(), width),
+                ],
+            )
+        }
+
+        let initial = calculator_class(8.0, 2.0, "16px");
+        assert!(initial.contains("padding: 16px;"));
+        assert!(initial.contains("width: calc(100% - 16px);"));
+
+        let scaled = calculator_class(8.0, 3.0, "16px");
+        assert_ne!(initial, scaled);
+        assert!(scaled.contains("padding: 24px;"));
+
+        let wider_gutter = calculator_class(8.0, 2.0, "2rem");
+        assert!(wider_gutter.contains("width: calc(100% - 2rem);"));
+        assert_eq!(initial, calculator_class(8.0, 2.0, "16px"));
+    }
 }
 
 // Note: Dioxus functionality has been removed in the simplified version
@@ -141,6 +141,8 @@ pub fn test_variant_system() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     variant_config
@@ -103,6 +103,7 @@ async fn test_hot_reload_config() {
         enable_css_injection: true,
         max_retries: 3,
         retry_interval_ms: 1000,
+        max_file_size: 5 * 1024 * 1024,
     };
 
     println!("\n自定义配置:");
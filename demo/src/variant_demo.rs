@@ -70,6 +70,8 @@ fn test_basic_variants() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 添加尺寸变体
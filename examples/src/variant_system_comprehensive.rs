@@ -102,6 +102,8 @@ fn demo_basic_variants() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 定义尺寸变体
@@ -253,6 +255,8 @@ fn demo_responsive_variants() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 定义响应式样式
@@ -589,6 +593,8 @@ fn demo_variant_resolver() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 添加基础变体
@@ -50,6 +50,8 @@ fn main() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 配置尺寸变体
@@ -152,6 +152,8 @@ fn demo_variant_config() {
         state: HashMap::new(),
         responsive: HashMap::new(),
         defaults: HashMap::new(),
+        compound: Vec::new(),
+        conditional: Vec::new(),
     };
 
     // 配置尺寸变体